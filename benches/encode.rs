@@ -0,0 +1,63 @@
+//! Benchmarks for hex encoding and decoding. Run with `--features simd` to measure
+//! the SIMD-accelerated backend.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use hex_buffer_serde::{codec, Hex as _, HexForm};
+use serde_derive::{Deserialize, Serialize};
+
+const SIZES: [usize; 3] = [1 << 10, 4 << 10, 64 << 10];
+
+#[derive(Serialize, Deserialize)]
+struct Buffer {
+    #[serde(with = "HexForm")]
+    bytes: Vec<u8>,
+}
+
+fn encoding(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("codec");
+    for len in SIZES {
+        let bytes: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+        let hex_str = codec::encode(&bytes);
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("encode", len), &bytes, |bencher, bytes| {
+            bencher.iter(|| codec::encode(black_box(bytes)));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("decode", len),
+            &hex_str,
+            |bencher, hex_str| {
+                bencher.iter(|| codec::decode(black_box(hex_str)).unwrap());
+            },
+        );
+    }
+    group.finish();
+}
+
+fn json_roundtrip(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("json");
+    for len in SIZES {
+        let buffer = Buffer {
+            bytes: (0..len).map(|i| (i % 251) as u8).collect(),
+        };
+        let json = serde_json::to_string(&buffer).unwrap();
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(
+            BenchmarkId::new("serialize", len),
+            &buffer,
+            |bencher, buffer| {
+                bencher.iter(|| serde_json::to_string(black_box(buffer)).unwrap());
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("deserialize", len),
+            &json,
+            |bencher, json| {
+                bencher.iter(|| serde_json::from_str::<Buffer>(black_box(json)).unwrap());
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, encoding, json_roundtrip);
+criterion_main!(benches);
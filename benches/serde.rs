@@ -0,0 +1,107 @@
+//! Benchmarks for (de)serialization with hex forms, compared to raw bytes via `serde_bytes`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use hex_buffer_serde::{ConstHex as _, ConstHexForm, Hex as _, HexForm};
+use serde::de::DeserializeOwned;
+use serde_derive::{Deserialize, Serialize};
+
+const VEC_SIZES: [usize; 3] = [16, 128, 1_024];
+
+#[derive(Serialize, Deserialize)]
+struct HexVec {
+    #[serde(with = "HexForm")]
+    bytes: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawVec {
+    #[serde(with = "serde_bytes")]
+    bytes: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HexArray32 {
+    #[serde(with = "ConstHexForm")]
+    bytes: [u8; 32],
+}
+
+#[derive(Serialize, Deserialize)]
+struct HexArray64 {
+    #[serde(with = "ConstHexForm")]
+    bytes: [u8; 64],
+}
+
+fn sample_bytes(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+/// Benchmarks JSON and `bincode` round-trips for `value` in the specified group.
+fn bench_formats<T: serde::Serialize + DeserializeOwned>(
+    criterion: &mut Criterion,
+    group_name: &str,
+    len: usize,
+    value: &T,
+) {
+    let json = serde_json::to_string(value).unwrap();
+    let bincode_bytes = bincode::serialize(value).unwrap();
+
+    let mut group = criterion.benchmark_group(group_name);
+    group.throughput(Throughput::Bytes(len as u64));
+    group.bench_with_input(
+        BenchmarkId::new("json_serialize", len),
+        value,
+        |bencher, value| {
+            bencher.iter(|| serde_json::to_string(black_box(value)).unwrap());
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("json_deserialize", len),
+        &json,
+        |bencher, json| {
+            bencher.iter(|| serde_json::from_str::<T>(black_box(json)).unwrap());
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("bincode_serialize", len),
+        value,
+        |bencher, value| {
+            bencher.iter(|| bincode::serialize(black_box(value)).unwrap());
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("bincode_deserialize", len),
+        &bincode_bytes,
+        |bencher, bytes| {
+            bencher.iter(|| bincode::deserialize::<T>(black_box(bytes)).unwrap());
+        },
+    );
+    group.finish();
+}
+
+fn hex_form(criterion: &mut Criterion) {
+    for len in VEC_SIZES {
+        let value = HexVec {
+            bytes: sample_bytes(len),
+        };
+        bench_formats(criterion, "hex_form", len, &value);
+    }
+}
+
+fn const_hex_form(criterion: &mut Criterion) {
+    let value = HexArray32 { bytes: [0xab; 32] };
+    bench_formats(criterion, "const_hex_form", 32, &value);
+    let value = HexArray64 { bytes: [0xab; 64] };
+    bench_formats(criterion, "const_hex_form", 64, &value);
+}
+
+fn raw_bytes(criterion: &mut Criterion) {
+    for len in VEC_SIZES {
+        let value = RawVec {
+            bytes: sample_bytes(len),
+        };
+        bench_formats(criterion, "serde_bytes", len, &value);
+    }
+}
+
+criterion_group!(benches, hex_form, const_hex_form, raw_bytes);
+criterion_main!(benches);
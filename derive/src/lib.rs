@@ -0,0 +1,499 @@
+//! Derive macros for [`hex-buffer-serde`].
+//!
+//! Usually, this crate should not be used directly; enable the `derive` feature
+//! of `hex-buffer-serde` instead.
+//!
+//! [`hex-buffer-serde`]: https://docs.rs/hex-buffer-serde/
+
+#![doc(html_root_url = "https://docs.rs/hex-buffer-serde-derive/0.4.0")]
+// Linter settings.
+#![warn(missing_docs, missing_debug_implementations)]
+#![warn(clippy::all, clippy::pedantic)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{
+    meta::ParseNestedMeta, parse::Parse, parse_macro_input, spanned::Spanned, DeriveInput, LitStr,
+    Path, Type,
+};
+
+/// Parsed contents of `#[hex_proxy(...)]` attributes.
+struct ProxyAttrs {
+    target: Type,
+    error: Type,
+    create_bytes: CreateBytes,
+    from_bytes: Option<Path>,
+}
+
+/// Way to convert the target type to bytes.
+enum CreateBytes {
+    /// `<Target>::as_bytes()`.
+    Default,
+    /// `AsRef<[u8]>` implementation for the target type.
+    AsRef(Span),
+    /// Custom function.
+    Custom(Path),
+}
+
+impl ProxyAttrs {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut target = None;
+        let mut error = None;
+        let mut create_bytes = CreateBytes::Default;
+        let mut from_bytes = None;
+
+        for attr in &input.attrs {
+            if !attr.path().is_ident("hex_proxy") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("target") {
+                    target = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("error") {
+                    error = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("as_ref") {
+                    Self::set_create_bytes(&mut create_bytes, &meta.path)?;
+                    create_bytes = CreateBytes::AsRef(meta.path.span());
+                } else if meta.path.is_ident("create_bytes") {
+                    Self::set_create_bytes(&mut create_bytes, &meta.path)?;
+                    create_bytes = CreateBytes::Custom(meta.value()?.parse()?);
+                } else if meta.path.is_ident("from_bytes") {
+                    from_bytes = Some(meta.value()?.parse()?);
+                } else {
+                    let message = "unsupported attribute; expected one of `target`, `error`, \
+                                   `as_ref`, `create_bytes` or `from_bytes`";
+                    return Err(meta.error(message));
+                }
+                Ok(())
+            })?;
+        }
+
+        let missing_attr = |name: &str| {
+            let message = format!("missing `{name}` in `#[hex_proxy(...)]` attribute");
+            syn::Error::new_spanned(&input.ident, message)
+        };
+        Ok(Self {
+            target: target.ok_or_else(|| missing_attr("target"))?,
+            error: error.ok_or_else(|| missing_attr("error"))?,
+            create_bytes,
+            from_bytes,
+        })
+    }
+
+    fn set_create_bytes(create_bytes: &mut CreateBytes, path: &Path) -> syn::Result<()> {
+        if matches!(create_bytes, CreateBytes::Default) {
+            Ok(())
+        } else {
+            let message =
+                "`as_ref` and `create_bytes` are mutually exclusive and can be specified once";
+            Err(syn::Error::new_spanned(path, message))
+        }
+    }
+
+    fn create_bytes_expr(&self) -> TokenStream2 {
+        let target = &self.target;
+        match &self.create_bytes {
+            CreateBytes::Default => quote_spanned!(target.span()=> <#target>::as_bytes),
+            CreateBytes::AsRef(span) => {
+                quote_spanned!(*span=> <#target as ::core::convert::AsRef<[u8]>>::as_ref)
+            }
+            CreateBytes::Custom(path) => quote!(#path),
+        }
+    }
+
+    fn parse_bytes_expr(&self) -> TokenStream2 {
+        let target = &self.target;
+        if let Some(path) = &self.from_bytes {
+            quote!(#path)
+        } else {
+            quote_spanned!(target.span()=> <#target>::from_bytes)
+        }
+    }
+}
+
+/// Parsed arguments of the `#[hex_proxy(...)]` attribute macro.
+struct ProxyMacroArgs {
+    target: Type,
+    error: Option<Type>,
+    bytes_fn: Option<Path>,
+    from_fn: Option<Path>,
+}
+
+impl ProxyMacroArgs {
+    fn parse(attr: TokenStream2) -> syn::Result<Self> {
+        let mut target = None;
+        let mut error = None;
+        let mut bytes_fn = None;
+        let mut from_fn = None;
+
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("target") {
+                target = Some(Self::parse_value(&meta)?);
+            } else if meta.path.is_ident("error") {
+                error = Some(Self::parse_value(&meta)?);
+            } else if meta.path.is_ident("bytes_fn") {
+                bytes_fn = Some(Self::parse_value(&meta)?);
+            } else if meta.path.is_ident("from_fn") {
+                from_fn = Some(Self::parse_value(&meta)?);
+            } else {
+                let message = "unsupported argument; expected one of `target`, `error`, \
+                               `bytes_fn` or `from_fn`";
+                return Err(meta.error(message));
+            }
+            Ok(())
+        });
+        syn::parse::Parser::parse2(parser, attr)?;
+
+        let target = target.ok_or_else(|| {
+            let message = "missing `target` in `#[hex_proxy(...)]`; it specifies \
+                           the type (de)serialized by the proxy";
+            syn::Error::new(Span::call_site(), message)
+        })?;
+        if from_fn.is_some() && error.is_none() {
+            let message = "missing `error` in `#[hex_proxy(...)]`; it is required if `from_fn` \
+                           is specified since the error type cannot be inferred from a function";
+            return Err(syn::Error::new(Span::call_site(), message));
+        }
+        Ok(Self {
+            target,
+            error,
+            bytes_fn,
+            from_fn,
+        })
+    }
+
+    /// Parses a value either from a string literal (`target = "Key"`) or directly (`target = Key`).
+    fn parse_value<T: Parse>(meta: &ParseNestedMeta<'_>) -> syn::Result<T> {
+        let value = meta.value()?;
+        if value.peek(LitStr) {
+            value.parse::<LitStr>()?.parse()
+        } else {
+            value.parse()
+        }
+    }
+
+    /// Resolves a function path. A single identifier (e.g., `as_bytes`) is treated as
+    /// an associated function of the target type.
+    fn resolve_fn(&self, path: &Path) -> TokenStream2 {
+        let target = &self.target;
+        if let Some(ident) = path.get_ident() {
+            quote_spanned!(ident.span()=> <#target>::#ident)
+        } else {
+            quote!(#path)
+        }
+    }
+
+    fn create_bytes_expr(&self) -> TokenStream2 {
+        let target = &self.target;
+        if let Some(path) = &self.bytes_fn {
+            self.resolve_fn(path)
+        } else {
+            quote_spanned!(target.span()=> <#target as ::core::convert::AsRef<[u8]>>::as_ref)
+        }
+    }
+
+    fn parse_bytes_expr(&self) -> TokenStream2 {
+        let target = &self.target;
+        if let Some(path) = &self.from_fn {
+            self.resolve_fn(path)
+        } else {
+            quote_spanned!(target.span()=> <#target as ::core::convert::TryFrom<&[u8]>>::try_from)
+        }
+    }
+
+    fn error_type(&self) -> TokenStream2 {
+        let target = &self.target;
+        if let Some(error) = &self.error {
+            quote!(#error)
+        } else {
+            quote_spanned! {target.span()=>
+                <#target as ::core::convert::TryFrom<&'static [u8]>>::Error
+            }
+        }
+    }
+}
+
+fn hex_impl(
+    input: &DeriveInput,
+    target: &Type,
+    error: &TokenStream2,
+    create_bytes: &TokenStream2,
+    from_bytes: &TokenStream2,
+) -> TokenStream2 {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::hex_buffer_serde::Hex<#target> for #name #ty_generics #where_clause {
+            type Error = #error;
+
+            fn create_bytes(value: &#target) -> ::hex_buffer_serde::__private::Cow<'_, [u8]> {
+                ::core::convert::From::from(#create_bytes(value))
+            }
+
+            fn from_bytes(bytes: &[u8]) -> ::core::result::Result<#target, Self::Error> {
+                #from_bytes(bytes)
+            }
+        }
+    }
+}
+
+fn derive_hex_proxy_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let attrs = ProxyAttrs::parse(input)?;
+    let error = &attrs.error;
+    Ok(hex_impl(
+        input,
+        &attrs.target,
+        &quote!(#error),
+        &attrs.create_bytes_expr(),
+        &attrs.parse_bytes_expr(),
+    ))
+}
+
+fn hex_proxy_impl(attr: TokenStream2, input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let args = ProxyMacroArgs::parse(attr)?;
+    let hex_impl = hex_impl(
+        input,
+        &args.target,
+        &args.error_type(),
+        &args.create_bytes_expr(),
+        &args.parse_bytes_expr(),
+    );
+    Ok(quote!(#input #hex_impl))
+}
+
+/// Derives a `Hex` implementation for a proxy type.
+///
+/// The proxy type is configured with the `#[hex_proxy(...)]` attribute, which supports
+/// the following params:
+///
+/// - `target = Type` (required). Type (de)serialized by the proxy.
+/// - `error = Type` (required). Error type for `Hex::Error`.
+/// - `create_bytes = path` (optional). Function converting `&Type` to bytes; its output
+///   must be convertible to `Cow<[u8]>` (e.g., `&[u8]` or `Vec<u8>`).
+///   By default, `Type::as_bytes` is used.
+/// - `as_ref` (optional; mutually exclusive with `create_bytes`). Uses the `AsRef<[u8]>`
+///   implementation for `Type` to convert it to bytes.
+/// - `from_bytes = path` (optional). Function with the `fn(&[u8]) -> Result<Type, Error>`
+///   signature restoring the type from bytes. By default, `Type::from_bytes` is used.
+///
+/// If the target type has neither the default methods nor an `AsRef<[u8]>` implementation,
+/// the generated code will fail to compile, with errors pointing to the `target` type;
+/// specify `create_bytes` and `from_bytes` explicitly in this case.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::Hex as _;
+/// use hex_buffer_serde_derive::HexProxy;
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// /// Foreign type that we want to serialize.
+/// pub struct Key([u8; 4]);
+///
+/// impl AsRef<[u8]> for Key {
+///     fn as_ref(&self) -> &[u8] {
+///         &self.0
+///     }
+/// }
+///
+/// impl Key {
+///     pub fn new(bytes: &[u8]) -> Result<Self, &'static str> {
+///         bytes.try_into().map(Self).map_err(|_| "invalid key length")
+///     }
+/// }
+///
+/// #[derive(HexProxy)]
+/// #[hex_proxy(target = Key, error = &'static str, as_ref, from_bytes = Key::new)]
+/// struct KeyHex(());
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "KeyHex")]
+///     key: Key,
+/// }
+///
+/// let json = r#"{"key":"01020304"}"#;
+/// let test: Test = serde_json::from_str(json)?;
+/// assert_eq!(test.key.0, [1, 2, 3, 4]);
+/// assert_eq!(serde_json::to_string(&test)?, json);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[proc_macro_derive(HexProxy, attributes(hex_proxy))]
+pub fn derive_hex_proxy(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match derive_hex_proxy_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Generates a `Hex` implementation for the annotated proxy type.
+///
+/// This is an alternative to the [`HexProxy`](macro@HexProxy) derive macro with defaults
+/// based on standard conversion traits. The attribute supports the following arguments;
+/// values can be specified either directly or as string literals (e.g., `target = "Key"`).
+///
+/// - `target` (required). Type (de)serialized by the proxy.
+/// - `bytes_fn` (optional). Function converting `&Type` to bytes; its output must be
+///   convertible to `Cow<[u8]>`. A single identifier (e.g., `as_bytes`) refers
+///   to an associated function of `Type`; free functions need to be specified with a path
+///   (e.g., `self::key_to_bytes`). By default, the `AsRef<[u8]>` implementation
+///   for `Type` is used.
+/// - `from_fn` (optional). Function with the `fn(&[u8]) -> Result<Type, Error>` signature
+///   restoring the type from bytes; a single identifier is resolved in the same way
+///   as for `bytes_fn`. By default, the `TryFrom<&[u8]>` implementation for `Type` is used.
+/// - `error` (required if `from_fn` is specified). Error type for `Hex::Error`. By default,
+///   the error type of the `TryFrom<&[u8]>` implementation for `Type` is used.
+///
+/// If the target type lacks the default trait implementations, the generated code will fail
+/// to compile, with errors pointing to the `target` type.
+///
+/// When importing the macro, note that it has the same name as the helper attribute
+/// of [`HexProxy`](macro@HexProxy); to avoid ambiguity, it can be referenced by its path.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::Hex as _;
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// /// Foreign type that we want to serialize.
+/// pub struct Key([u8; 4]);
+///
+/// impl Key {
+///     pub fn as_bytes(&self) -> &[u8] {
+///         &self.0
+///     }
+/// }
+///
+/// impl TryFrom<&[u8]> for Key {
+///     type Error = core::array::TryFromSliceError;
+///
+///     fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+///         bytes.try_into().map(Self)
+///     }
+/// }
+///
+/// #[hex_buffer_serde_derive::hex_proxy(target = "Key", bytes_fn = "as_bytes")]
+/// struct KeyHex;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "KeyHex")]
+///     key: Key,
+/// }
+///
+/// let json = r#"{"key":"01020304"}"#;
+/// let test: Test = serde_json::from_str(json)?;
+/// assert_eq!(test.key.0, [1, 2, 3, 4]);
+/// assert_eq!(serde_json::to_string(&test)?, json);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[proc_macro_attribute]
+pub fn hex_proxy(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match hex_proxy_impl(attr.into(), &input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    #[test]
+    fn parsing_attrs() {
+        let input: DeriveInput = parse_quote! {
+            #[hex_proxy(target = Key, error = &'static str, as_ref, from_bytes = Key::new)]
+            struct KeyHex(());
+        };
+        let attrs = ProxyAttrs::parse(&input).unwrap();
+        assert_eq!(attrs.target, parse_quote!(Key));
+        assert_eq!(attrs.error, parse_quote!(&'static str));
+        assert!(matches!(attrs.create_bytes, CreateBytes::AsRef(_)));
+        assert_eq!(attrs.from_bytes, Some(parse_quote!(Key::new)));
+    }
+
+    #[test]
+    fn parsing_attrs_errors() {
+        let input: DeriveInput = parse_quote! {
+            #[hex_proxy(error = &'static str)]
+            struct KeyHex(());
+        };
+        let err = ProxyAttrs::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "missing `target` in `#[hex_proxy(...)]` attribute"
+        );
+
+        let input: DeriveInput = parse_quote! {
+            #[hex_proxy(target = Key, error = (), as_ref, create_bytes = Key::to_vec)]
+            struct KeyHex(());
+        };
+        let err = ProxyAttrs::parse(&input).err().unwrap();
+        assert!(err.to_string().contains("mutually exclusive"), "{err}");
+
+        let input: DeriveInput = parse_quote! {
+            #[hex_proxy(target = Key, error = (), what = 1)]
+            struct KeyHex(());
+        };
+        let err = ProxyAttrs::parse(&input).err().unwrap();
+        assert!(
+            err.to_string().starts_with("unsupported attribute"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn parsing_macro_args() {
+        let attr = quote!(
+            target = "Key",
+            bytes_fn = "as_bytes",
+            from_fn = Key::new,
+            error = ()
+        );
+        let args = ProxyMacroArgs::parse(attr).unwrap();
+        assert_eq!(args.target, parse_quote!(Key));
+        assert_eq!(args.error, Some(parse_quote!(())));
+        assert_eq!(args.bytes_fn, Some(parse_quote!(as_bytes)));
+        assert_eq!(args.from_fn, Some(parse_quote!(Key::new)));
+        assert_eq!(
+            args.create_bytes_expr().to_string(),
+            quote!(<Key>::as_bytes).to_string()
+        );
+        assert_eq!(
+            args.parse_bytes_expr().to_string(),
+            quote!(Key::new).to_string()
+        );
+
+        let args = ProxyMacroArgs::parse(quote!(target = Key)).unwrap();
+        assert_eq!(
+            args.parse_bytes_expr().to_string(),
+            quote!(<Key as ::core::convert::TryFrom<&[u8]>>::try_from).to_string()
+        );
+    }
+
+    #[test]
+    fn parsing_macro_args_errors() {
+        let err = ProxyMacroArgs::parse(quote!(error = ())).err().unwrap();
+        assert!(err.to_string().starts_with("missing `target`"), "{err}");
+
+        let attr = quote!(target = Key, from_fn = Key::new);
+        let err = ProxyMacroArgs::parse(attr).err().unwrap();
+        assert!(
+            err.to_string()
+                .starts_with("missing `error` in `#[hex_proxy(...)]`; it is required if `from_fn`"),
+            "{err}"
+        );
+
+        let err = ProxyMacroArgs::parse(quote!(target = Key, as_ref))
+            .err()
+            .unwrap();
+        assert!(err.to_string().starts_with("unsupported argument"), "{err}");
+    }
+}
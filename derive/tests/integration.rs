@@ -0,0 +1,207 @@
+//! Integration tests for the `HexProxy` derive macro and the `hex_proxy` attribute macro.
+
+use hex_buffer_serde::{Hex as _, HexForm};
+use hex_buffer_serde_derive::HexProxy;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+
+use std::marker::PhantomData;
+
+/// Type with `as_bytes` / `from_bytes` methods picked up by default.
+#[derive(Debug, PartialEq)]
+struct Key([u8; 4]);
+
+impl Key {
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+        bytes.try_into().map(Self).map_err(|_| "invalid key length")
+    }
+}
+
+#[derive(HexProxy)]
+#[hex_proxy(target = Key, error = &'static str)]
+struct KeyHex(());
+
+/// Type implementing `AsRef<[u8]>` and having a named constructor.
+#[derive(Debug, PartialEq)]
+struct Signature(Vec<u8>);
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Signature {
+    fn new(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.is_empty() {
+            Err("empty signature".to_owned())
+        } else {
+            Ok(Self(bytes.to_vec()))
+        }
+    }
+}
+
+#[derive(HexProxy)]
+#[hex_proxy(target = Signature, error = String, as_ref, from_bytes = Signature::new)]
+struct SignatureHex(());
+
+/// Type with neither default methods nor `AsRef<[u8]>`.
+#[derive(Debug, PartialEq)]
+struct Counter(u32);
+
+fn counter_to_bytes(counter: &Counter) -> Vec<u8> {
+    counter.0.to_be_bytes().to_vec()
+}
+
+fn counter_from_bytes(bytes: &[u8]) -> Result<Counter, core::array::TryFromSliceError> {
+    bytes
+        .try_into()
+        .map(|bytes| Counter(u32::from_be_bytes(bytes)))
+}
+
+#[derive(HexProxy)]
+#[hex_proxy(
+    target = Counter,
+    error = core::array::TryFromSliceError,
+    create_bytes = counter_to_bytes,
+    from_bytes = counter_from_bytes,
+)]
+struct CounterHex<T>(PhantomData<T>);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Test {
+    #[serde(with = "KeyHex")]
+    key: Key,
+    #[serde(with = "SignatureHex")]
+    signature: Signature,
+    #[serde(with = "CounterHex::<()>")]
+    counter: Counter,
+    #[serde(with = "HexForm")]
+    bytes: Vec<u8>,
+}
+
+#[test]
+fn derived_proxies() {
+    let test = Test {
+        key: Key([1, 2, 3, 4]),
+        signature: Signature(vec![0xc0, 0xff, 0xee]),
+        counter: Counter(0x_dead_beef),
+        bytes: vec![0xab],
+    };
+    let json = serde_json::to_value(&test).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "key": "01020304",
+            "signature": "c0ffee",
+            "counter": "deadbeef",
+            "bytes": "ab",
+        })
+    );
+    let test_copy: Test = serde_json::from_value(json).unwrap();
+    assert_eq!(test_copy, test);
+}
+
+#[test]
+fn errors_from_derived_proxies() {
+    let json = json!({
+        "key": "010203",
+        "signature": "c0ffee",
+        "counter": "deadbeef",
+        "bytes": "ab",
+    });
+    let err = serde_json::from_value::<Test>(json).unwrap_err();
+    assert_eq!(err.to_string(), "invalid key length");
+
+    let json = json!({
+        "key": "01020304",
+        "signature": "",
+        "counter": "deadbeef",
+        "bytes": "ab",
+    });
+    let err = serde_json::from_value::<Test>(json).unwrap_err();
+    assert_eq!(err.to_string(), "empty signature");
+}
+
+/// Type implementing `AsRef<[u8]>` and `TryFrom<&[u8]>`, which are picked up by `hex_proxy`
+/// by default.
+#[derive(Debug, PartialEq)]
+struct Nonce([u8; 3]);
+
+impl AsRef<[u8]> for Nonce {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for Nonce {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self)
+    }
+}
+
+#[hex_buffer_serde_derive::hex_proxy(target = "Nonce")]
+struct NonceHex;
+
+#[hex_buffer_serde_derive::hex_proxy(
+    target = "Key",
+    bytes_fn = "as_bytes",
+    from_fn = "from_bytes",
+    error = "&'static str"
+)]
+struct KeyHexAttr;
+
+#[hex_buffer_serde_derive::hex_proxy(
+    target = Counter,
+    bytes_fn = self::counter_to_bytes,
+    from_fn = self::counter_from_bytes,
+    error = core::array::TryFromSliceError,
+)]
+struct CounterHexAttr;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AttrTest {
+    #[serde(with = "NonceHex")]
+    nonce: Nonce,
+    #[serde(with = "KeyHexAttr")]
+    key: Key,
+    #[serde(with = "CounterHexAttr")]
+    counter: Counter,
+}
+
+#[test]
+fn attribute_proxies() {
+    let test = AttrTest {
+        nonce: Nonce([0xc0, 0xff, 0xee]),
+        key: Key([1, 2, 3, 4]),
+        counter: Counter(0x_dead_beef),
+    };
+    let json = serde_json::to_value(&test).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "nonce": "c0ffee",
+            "key": "01020304",
+            "counter": "deadbeef",
+        })
+    );
+    let test_copy: AttrTest = serde_json::from_value(json).unwrap();
+    assert_eq!(test_copy, test);
+
+    let json = json!({
+        "nonce": "c0ff",
+        "key": "01020304",
+        "counter": "deadbeef",
+    });
+    let err = serde_json::from_value::<AttrTest>(json).unwrap_err();
+    assert!(
+        err.to_string().contains("could not convert slice to array"),
+        "{err}"
+    );
+}
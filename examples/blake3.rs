@@ -0,0 +1,57 @@
+//! Example demonstrating how to implement `ConstHex` for hash digests from the `blake3` crate.
+
+use serde_derive::*;
+
+use hex_buffer_serde::ConstHex;
+
+/// Serializes BLAKE3 hashes as 32-byte arrays.
+struct Blake3HashHex(());
+
+impl ConstHex<blake3::Hash, 32> for Blake3HashHex {
+    type Error = core::convert::Infallible;
+
+    fn create_bytes(value: &blake3::Hash) -> [u8; 32] {
+        *value.as_bytes()
+    }
+
+    fn from_bytes(bytes: [u8; 32]) -> Result<blake3::Hash, Self::Error> {
+        Ok(blake3::Hash::from(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Document {
+    name: String,
+    #[serde(with = "Blake3HashHex")]
+    digest: blake3::Hash,
+}
+
+/// BLAKE3 hash of the string `abc`, as published in the official test vectors.
+const ABC_HASH: &str = "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85";
+
+fn main() {
+    let digest = blake3::hash(b"abc");
+    assert_eq!(digest.to_hex().as_str(), ABC_HASH);
+
+    let document = Document {
+        name: "abc".to_owned(),
+        digest,
+    };
+    let json = serde_json::to_string(&document).unwrap();
+    println!("{json}");
+    assert_eq!(json, format!(r#"{{"name":"abc","digest":"{ABC_HASH}"}}"#));
+
+    let document_copy: Document = serde_json::from_str(&json).unwrap();
+    assert_eq!(document_copy, document);
+
+    // Binary formats store the digest as raw bytes.
+    let bin = bincode::serialize(&document).unwrap();
+    assert_eq!(bin[bin.len() - 32..], *digest.as_bytes());
+    let document_copy: Document = bincode::deserialize(&bin).unwrap();
+    assert_eq!(document_copy, document);
+
+    // Digests of incorrect length are rejected on deserialization.
+    let truncated_json = format!(r#"{{"name":"abc","digest":"{}"}}"#, &ABC_HASH[..62]);
+    let err = serde_json::from_str::<Document>(&truncated_json).unwrap_err();
+    println!("Error deserializing truncated digest: {err}");
+}
@@ -0,0 +1,116 @@
+//! Example demonstrating how to implement `Hex` and `ConstHex` for secp256k1 keys
+//! from the `k256` crate. Since these types are external both to this example
+//! and to `hex-buffer-serde`, they can only be (de)serialized via dedicated
+//! implementations.
+
+use k256::{elliptic_curve::sec1::ToEncodedPoint, PublicKey, SecretKey};
+use rand_core::OsRng;
+use serde_derive::*;
+
+use std::borrow::Cow;
+
+use hex_buffer_serde::{ConstHex, Hex};
+
+/// Variable-length implementation for secret keys. (Secret keys have a fixed length as well,
+/// so `ConstHex` could be used too.)
+struct SecretKeyHex(());
+
+impl Hex<SecretKey> for SecretKeyHex {
+    type Error = k256::elliptic_curve::Error;
+
+    fn create_bytes(value: &SecretKey) -> Cow<'_, [u8]> {
+        Cow::Owned(value.to_bytes().to_vec())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<SecretKey, Self::Error> {
+        SecretKey::from_slice(bytes)
+    }
+}
+
+/// Serializes public keys in the compressed SEC1 form (33 bytes).
+struct PublicKeyHex(());
+
+impl ConstHex<PublicKey, 33> for PublicKeyHex {
+    type Error = k256::elliptic_curve::Error;
+
+    fn create_bytes(value: &PublicKey) -> [u8; 33] {
+        let mut bytes = [0_u8; 33];
+        bytes.copy_from_slice(value.to_encoded_point(true).as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: [u8; 33]) -> Result<PublicKey, Self::Error> {
+        PublicKey::from_sec1_bytes(&bytes)
+    }
+}
+
+/// Serializes public keys in the uncompressed SEC1 form (65 bytes).
+struct UncompressedPublicKeyHex(());
+
+impl ConstHex<PublicKey, 65> for UncompressedPublicKeyHex {
+    type Error = k256::elliptic_curve::Error;
+
+    fn create_bytes(value: &PublicKey) -> [u8; 65] {
+        let mut bytes = [0_u8; 65];
+        bytes.copy_from_slice(value.to_encoded_point(false).as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: [u8; 65]) -> Result<PublicKey, Self::Error> {
+        PublicKey::from_sec1_bytes(&bytes)
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct KeyPair {
+    #[serde(with = "SecretKeyHex")]
+    secret_key: SecretKey,
+    #[serde(with = "PublicKeyHex")]
+    public_key: PublicKey,
+    #[serde(with = "UncompressedPublicKeyHex")]
+    uncompressed_public_key: PublicKey,
+}
+
+fn main() {
+    let secret_key = SecretKey::random(&mut OsRng);
+    let public_key = secret_key.public_key();
+    let pair = KeyPair {
+        secret_key: secret_key.clone(),
+        public_key,
+        uncompressed_public_key: public_key,
+    };
+
+    let json = serde_json::to_value(&pair).unwrap();
+    println!("{json:#}");
+    let secret_hex = json["secret_key"].as_str().unwrap();
+    assert_eq!(secret_hex, hex::encode(secret_key.to_bytes()));
+
+    let public_hex = json["public_key"].as_str().unwrap();
+    assert_eq!(public_hex.len(), 66);
+    assert!(public_hex.starts_with("02") || public_hex.starts_with("03"));
+    assert_eq!(
+        public_hex,
+        hex::encode(public_key.to_encoded_point(true).as_bytes())
+    );
+
+    let uncompressed_hex = json["uncompressed_public_key"].as_str().unwrap();
+    assert_eq!(uncompressed_hex.len(), 130);
+    assert!(uncompressed_hex.starts_with("04"));
+    // The uncompressed form contains the x coordinate of the compressed form.
+    assert_eq!(uncompressed_hex[2..66], public_hex[2..]);
+
+    let pair_copy: KeyPair = serde_json::from_value(json).unwrap();
+    assert_eq!(pair_copy, pair);
+
+    // Binary formats store keys as raw bytes.
+    let bin = bincode::serialize(&pair).unwrap();
+    assert_eq!(bin.len(), 3 * 8 + 32 + 33 + 65);
+    let pair_copy: KeyPair = bincode::deserialize(&bin).unwrap();
+    assert_eq!(pair_copy, pair);
+
+    // Invalid keys are rejected on deserialization.
+    let mut bogus_json = serde_json::to_value(&pair).unwrap();
+    bogus_json["public_key"] = format!("02{}", "ff".repeat(32)).into();
+    let err = serde_json::from_value::<KeyPair>(bogus_json).unwrap_err();
+    println!("Error deserializing invalid key: {err}");
+}
@@ -0,0 +1,284 @@
+//! Hex encoding with custom alphabets.
+
+use serde::{
+    de::{Error as DeError, Visitor},
+    Deserializer, Serializer,
+};
+
+use alloc::{borrow::Cow, vec::Vec};
+use core::{convert::TryFrom, fmt, marker::PhantomData};
+
+use crate::{var_len::from_validated_bytes, BytesVisitor, Hex, HexDecodeError, HexForm};
+
+/// Alphabet of 16 ASCII chars used by [`CustomAlphabetHex`] to encode nibbles.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{CustomAlphabetHex, Hex as _, HexAlphabet};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug)]
+/// enum Reversed {}
+///
+/// impl HexAlphabet for Reversed {
+///     const CHARS: &'static [u8; 16] = b"fedcba9876543210";
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "CustomAlphabetHex::<_, Reversed>")]
+///     bytes: Vec<u8>,
+/// }
+///
+/// let test: Test = serde_json::from_str(r#"{"bytes":"fe0f"}"#)?;
+/// assert_eq!(test.bytes, [0x01, 0xf0]);
+/// assert_eq!(serde_json::to_string(&test)?, r#"{"bytes":"fe0f"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub trait HexAlphabet {
+    /// Chars encoding nibbles `0..16`, in this order. All chars must be ASCII and distinct.
+    const CHARS: &'static [u8; 16];
+
+    /// Decodes a single char into a nibble. Returns `None` if the char is not a part
+    /// of the alphabet.
+    ///
+    /// The default implementation looks up the char in [`Self::CHARS`]. It can be overridden,
+    /// e.g., to make decoding case-insensitive.
+    fn decode_nibble(ch: u8) -> Option<u8> {
+        let position = Self::CHARS
+            .iter()
+            .position(|&alphabet_ch| alphabet_ch == ch)?;
+        u8::try_from(position).ok()
+    }
+}
+
+/// Standard lower-case [`HexAlphabet`] used by [`HexForm`]. Decoding is case-insensitive.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub enum LowerCaseAlphabet {}
+
+impl HexAlphabet for LowerCaseAlphabet {
+    const CHARS: &'static [u8; 16] = b"0123456789abcdef";
+
+    fn decode_nibble(ch: u8) -> Option<u8> {
+        decode_hex_digit(ch)
+    }
+}
+
+/// Standard upper-case [`HexAlphabet`] used by [`UpperHexForm`](crate::UpperHexForm).
+/// Decoding is case-insensitive.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub enum UpperCaseAlphabet {}
+
+impl HexAlphabet for UpperCaseAlphabet {
+    const CHARS: &'static [u8; 16] = b"0123456789ABCDEF";
+
+    fn decode_nibble(ch: u8) -> Option<u8> {
+        decode_hex_digit(ch)
+    }
+}
+
+fn decode_hex_digit(ch: u8) -> Option<u8> {
+    let digit = char::from(ch).to_digit(16)?;
+    u8::try_from(digit).ok()
+}
+
+/// Analogue of [`HexForm`] that encodes nibbles with a custom [`HexAlphabet`] for
+/// [human-readable][hr] serializers. Binary serialization is not affected.
+///
+/// `HexForm` and [`UpperHexForm`](crate::UpperHexForm) produce the same output as this form
+/// with [`LowerCaseAlphabet`] and [`UpperCaseAlphabet`] respectively, but are separate types
+/// using the faster encoding backend.
+///
+/// See [`HexAlphabet`] docs for an example of usage.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct CustomAlphabetHex<T, A: HexAlphabet>(PhantomData<(T, A)>);
+
+impl<T, E, A> Hex<T> for CustomAlphabetHex<T, A>
+where
+    T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
+    E: fmt::Display,
+    A: HexAlphabet,
+{
+    type Error = E;
+
+    fn create_bytes(buffer: &T) -> Cow<'_, [u8]> {
+        HexForm::create_bytes(buffer)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        HexForm::from_bytes(bytes)
+    }
+
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = Self::create_bytes(value);
+        if serializer.is_human_readable() {
+            serializer.collect_str(&AlphabetHex::<A>::new(&bytes))
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = if deserializer.is_human_readable() {
+            deserializer.deserialize_str(AlphabetVisitor::<A>(PhantomData))?
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor::new())?
+        };
+        from_validated_bytes::<T, Self>(&bytes).map_err(D::Error::custom)
+    }
+}
+
+/// Displays bytes using the specified alphabet.
+struct AlphabetHex<'a, A> {
+    bytes: &'a [u8],
+    _alphabet: PhantomData<A>,
+}
+
+impl<'a, A: HexAlphabet> AlphabetHex<'a, A> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            _alphabet: PhantomData,
+        }
+    }
+}
+
+impl<A: HexAlphabet> fmt::Display for AlphabetHex<'_, A> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &byte in self.bytes {
+            let chars = [
+                A::CHARS[usize::from(byte >> 4)],
+                A::CHARS[usize::from(byte & 0x0f)],
+            ];
+            let hex_str = core::str::from_utf8(&chars).map_err(|_| fmt::Error)?;
+            formatter.write_str(hex_str)?;
+        }
+        Ok(())
+    }
+}
+
+struct AlphabetVisitor<A>(PhantomData<A>);
+
+impl<A: HexAlphabet> Visitor<'_> for AlphabetVisitor<A> {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("hex string with custom alphabet")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        let digits = value.as_bytes();
+        if digits.len() % 2 != 0 {
+            return Err(E::custom(HexDecodeError::odd_length(digits.len())));
+        }
+
+        let decode = |position: usize| {
+            let ch = digits[position];
+            A::decode_nibble(ch).ok_or_else(|| E::custom(HexDecodeError::new(position, ch)))
+        };
+        (0..digits.len())
+            .step_by(2)
+            .map(|position| Ok((decode(position)? << 4) | decode(position + 1)?))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec;
+    use serde_derive::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::{
+        testing::{assert_binary_roundtrip, assert_decode_error, assert_hex_roundtrip},
+        UpperHexForm,
+    };
+
+    #[derive(Debug)]
+    enum Reversed {}
+
+    impl HexAlphabet for Reversed {
+        const CHARS: &'static [u8; 16] = b"fedcba9876543210";
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Test {
+        #[serde(with = "CustomAlphabetHex::<_, Reversed>")]
+        reversed: Vec<u8>,
+        #[serde(with = "CustomAlphabetHex::<_, UpperCaseAlphabet>")]
+        upper: [u8; 2],
+    }
+
+    #[test]
+    fn custom_alphabet() {
+        let value = Test {
+            reversed: vec![0x01, 0x23, 0xc0, 0xff, 0xee],
+            upper: [0xab, 0xcd],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "reversed": "fedc3f0011", "upper": "ABCD" }));
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[8..13], [0x01, 0x23, 0xc0, 0xff, 0xee]);
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        assert_hex_roundtrip::<_, CustomAlphabetHex<_, Reversed>>(&vec![0x7f, 0x80], "807f");
+        assert_binary_roundtrip::<_, CustomAlphabetHex<_, Reversed>>(&vec![0x7f, 0x80]);
+    }
+
+    #[test]
+    fn standard_alphabets_match_standard_forms() {
+        let bytes: Vec<u8> = (0_u8..=255).collect();
+        assert_eq!(
+            CustomAlphabetHex::<_, LowerCaseAlphabet>::serialize(
+                &bytes,
+                serde_json::value::Serializer
+            )
+            .unwrap(),
+            HexForm::serialize(&bytes, serde_json::value::Serializer).unwrap()
+        );
+        assert_eq!(
+            CustomAlphabetHex::<_, UpperCaseAlphabet>::serialize(
+                &bytes,
+                serde_json::value::Serializer
+            )
+            .unwrap(),
+            UpperHexForm::serialize(&bytes, serde_json::value::Serializer).unwrap()
+        );
+
+        for hex_str in [hex::encode(&bytes), hex::encode_upper(&bytes)] {
+            let restored: Vec<u8> =
+                CustomAlphabetHex::<_, LowerCaseAlphabet>::deserialize(json!(hex_str)).unwrap();
+            assert_eq!(restored, bytes);
+        }
+    }
+
+    #[test]
+    fn decoding_errors() {
+        type Form<T> = CustomAlphabetHex<T, Reversed>;
+
+        assert_decode_error::<Vec<u8>, Form<_>>("fed", "odd length");
+        // `A` is not a part of the alphabet since decoding is case-sensitive by default.
+        assert_decode_error::<Vec<u8>, Form<_>>("feA0", "invalid hex character 'A' at position 2");
+        assert_decode_error::<Vec<u8>, Form<_>>(
+            "f\u{fc}0",
+            "invalid hex character (byte 0xc3) at position 1",
+        );
+        assert_decode_error::<[u8; 2], Form<_>>("fe", "could not convert slice to array");
+    }
+}
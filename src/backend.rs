@@ -0,0 +1,129 @@
+//! Hex encoding backend.
+//!
+//! By default, the `hex` crate is used. If the `simd` feature is enabled, the SIMD-accelerated
+//! `const-hex` crate is used instead; it falls back to a scalar implementation on targets
+//! without SIMD support. Both backends produce identical outputs; `const-hex` errors
+//! are converted to `hex` ones.
+
+#[cfg(all(
+    any(feature = "const_len", feature = "heapless"),
+    not(feature = "simd")
+))]
+pub(crate) use hex::decode_to_slice;
+#[cfg(not(feature = "simd"))]
+pub(crate) use hex::encode_to_slice;
+#[cfg(all(feature = "alloc", not(feature = "simd")))]
+pub(crate) use hex::{decode, encode, encode_upper};
+
+#[cfg(feature = "simd")]
+pub(crate) use self::simd::*;
+
+#[cfg(feature = "simd")]
+mod simd {
+    use hex::FromHexError;
+
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "alloc")]
+    pub(crate) use const_hex::{encode, encode_upper};
+
+    fn convert_error(err: const_hex::FromHexError) -> FromHexError {
+        match err {
+            const_hex::FromHexError::InvalidHexCharacter { c, index } => {
+                FromHexError::InvalidHexCharacter { c, index }
+            }
+            const_hex::FromHexError::OddLength => FromHexError::OddLength,
+            const_hex::FromHexError::InvalidStringLength => FromHexError::InvalidStringLength,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn decode(input: impl AsRef<[u8]>) -> Result<Vec<u8>, FromHexError> {
+        const_hex::decode(input).map_err(convert_error)
+    }
+
+    #[cfg(any(feature = "const_len", feature = "heapless"))]
+    pub(crate) fn decode_to_slice(
+        input: impl AsRef<[u8]>,
+        output: &mut [u8],
+    ) -> Result<(), FromHexError> {
+        const_hex::decode_to_slice(input, output).map_err(convert_error)
+    }
+
+    pub(crate) fn encode_to_slice(
+        input: impl AsRef<[u8]>,
+        output: &mut [u8],
+    ) -> Result<(), FromHexError> {
+        const_hex::encode_to_slice(input, output).map_err(convert_error)
+    }
+}
+
+#[cfg(all(test, feature = "alloc", feature = "const_len"))]
+mod tests {
+    use super::*;
+
+    use alloc::{format, vec, vec::Vec};
+
+    fn sample_bytes(len: usize) -> Vec<u8> {
+        (0..len)
+            .map(|i| u8::try_from(i * 31 % 256).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn encoding_matches_scalar_implementation() {
+        for len in [0, 1, 2, 15, 16, 17, 31, 32, 33, 64, 255, 256, 1_024, 4_097] {
+            let bytes = sample_bytes(len);
+            assert_eq!(encode(&bytes), hex::encode(&bytes), "len={len}");
+            assert_eq!(encode_upper(&bytes), hex::encode_upper(&bytes), "len={len}");
+
+            let mut buffer = vec![0_u8; 2 * len];
+            encode_to_slice(&bytes, &mut buffer).unwrap();
+            assert_eq!(buffer, hex::encode(&bytes).as_bytes(), "len={len}");
+        }
+    }
+
+    #[test]
+    fn decoding_matches_scalar_implementation() {
+        for len in [0, 1, 16, 17, 32, 33, 256, 4_097] {
+            let bytes = sample_bytes(len);
+            for hex_str in [hex::encode(&bytes), hex::encode_upper(&bytes)] {
+                assert_eq!(decode(&hex_str).unwrap(), bytes, "len={len}");
+                let mut buffer = vec![0_u8; len];
+                decode_to_slice(&hex_str, &mut buffer).unwrap();
+                assert_eq!(buffer, bytes, "len={len}");
+            }
+        }
+    }
+
+    #[test]
+    fn decoding_errors_match_scalar_implementation() {
+        let long_prefix = hex::encode(sample_bytes(40));
+        let invalid_inputs = [
+            "0".into(),
+            "abc".into(),
+            "0g".into(),
+            "g0".into(),
+            "01 2".into(),
+            "01\u{fc}".into(),
+            format!("{long_prefix}0x"),
+            format!("{long_prefix}\u{fc}"),
+            format!("{long_prefix}{long_prefix}z1"),
+        ];
+        for input in &invalid_inputs {
+            assert_eq!(
+                decode(input).unwrap_err(),
+                hex::decode(input).unwrap_err(),
+                "{input}"
+            );
+        }
+
+        let mut buffer = [0_u8; 4];
+        let expected_err = hex::decode_to_slice("0102", &mut buffer).unwrap_err();
+        assert_eq!(
+            decode_to_slice("0102", &mut buffer).unwrap_err(),
+            expected_err
+        );
+    }
+}
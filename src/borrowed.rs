@@ -0,0 +1,152 @@
+//! Serialize-only wrapper for borrowed byte slices.
+
+use serde::{Serialize, Serializer};
+
+use core::{fmt, str};
+
+use crate::backend;
+
+/// Borrowed byte slice that is serialized as a hex string for [human-readable][hr] serializers,
+/// and as a byte array otherwise.
+///
+/// Unlike [`Hex`](crate::Hex) implementations, this wrapper is serialize-only and does not
+/// allocate: the hex string is written to the serializer piece by piece via
+/// [`Serializer::collect_str()`]. (Most serializers, such as `serde_json`, implement this method
+/// without allocations.) The wrapper implements [`Display`](fmt::Display) and [`Debug`]
+/// in the same way.
+///
+/// For byte arrays with length known in compile time, [`ConstHexBytes`](crate::ConstHexBytes)
+/// can be used instead.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::HexBytes;
+/// # use serde_derive::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Message<'a> {
+///     payload: HexBytes<'a>,
+/// }
+///
+/// let bytes = [0xc0, 0xff, 0xee];
+/// let message = Message { payload: HexBytes(&bytes) };
+/// assert_eq!(serde_json::to_string(&message)?, r#"{"payload":"c0ffee"}"#);
+/// assert_eq!(message.payload.to_string(), "c0ffee");
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexBytes<'a>(pub &'a [u8]);
+
+impl<'a> HexBytes<'a> {
+    /// Number of bytes encoded on the stack at a time.
+    const CHUNK_LEN: usize = 32;
+
+    /// Wraps the provided byte slice.
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<'a> From<&'a [u8]> for HexBytes<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for HexBytes<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl fmt::Display for HexBytes<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0_u8; 2 * Self::CHUNK_LEN];
+        for chunk in self.0.chunks(Self::CHUNK_LEN) {
+            let hex_slice = &mut buffer[..2 * chunk.len()];
+            backend::encode_to_slice(chunk, hex_slice).unwrap();
+            // ^ `unwrap` is safe: the length is correct by construction.
+            let hex_str = unsafe {
+                // SAFETY: hex output is always valid UTF-8.
+                str::from_utf8_unchecked(hex_slice)
+            };
+            formatter.write_str(hex_str)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for HexBytes<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "HexBytes({self})")
+    }
+}
+
+impl Serialize for HexBytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    /// Reference hex encoding that does not rely on `hex::encode()`, which requires `alloc`.
+    fn encode_reference(bytes: &[u8]) -> String {
+        let mut buffer = vec![0_u8; 2 * bytes.len()];
+        hex::encode_to_slice(bytes, &mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn formatting_hex_bytes() {
+        assert_eq!(HexBytes(&[]).to_string(), "");
+        assert_eq!(HexBytes(&[0xc0, 0xff, 0xee]).to_string(), "c0ffee");
+        assert_eq!(
+            format!("{:?}", HexBytes(&[0xc0, 0xff, 0xee])),
+            "HexBytes(c0ffee)"
+        );
+
+        // Check chunk boundaries.
+        for len in [31_u8, 32, 33, 64, 100] {
+            let bytes: Vec<u8> = (0..len).collect();
+            let expected = encode_reference(&bytes);
+            assert_eq!(HexBytes(&bytes).to_string(), expected, "{len}");
+        }
+    }
+
+    #[test]
+    fn serializing_hex_bytes() {
+        let bytes: Vec<u8> = (0_u8..50).collect();
+        let json = serde_json::to_value(HexBytes(&bytes)).unwrap();
+        assert_eq!(json, encode_reference(&bytes));
+
+        let encoded = bincode::serialize(&HexBytes(&bytes)).unwrap();
+        assert_eq!(encoded[..8], 50_u64.to_le_bytes());
+        assert_eq!(encoded[8..], bytes);
+    }
+
+    #[test]
+    fn creating_hex_bytes() {
+        const BYTES: HexBytes<'static> = HexBytes::new(&[0xc0, 0xff, 0xee]);
+
+        assert_eq!(BYTES.0, [0xc0, 0xff, 0xee]);
+        assert_eq!(HexBytes::from(&b"\x01\x02"[..]).to_string(), "0102");
+        assert_eq!(HexBytes::new(&[]).as_ref(), b"");
+    }
+}
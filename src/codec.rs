@@ -0,0 +1,192 @@
+//! Hex encoding and decoding without `serde`.
+//!
+//! The functions in this module use the same logic as the forms provided by the crate,
+//! and report decoding errors via [`HexDecodeError`].
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_buffer_serde::{codec, HexDecodeError};
+//!
+//! let bytes = [0xc0, 0xff, 0xee];
+//! assert_eq!(codec::encode(&bytes), "c0ffee");
+//! assert_eq!(codec::encode_upper(&bytes), "C0FFEE");
+//! assert_eq!(codec::decode("c0FFee")?, bytes);
+//!
+//! let err = codec::decode("c0ffeg").unwrap_err();
+//! assert_eq!(err, HexDecodeError::InvalidChar { position: 5, byte: b'g' });
+//! # Ok::<_, hex_buffer_serde::HexDecodeError>(())
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "const_len")]
+use core::{fmt, ops::Deref, str};
+
+#[cfg(feature = "const_fn_encode")]
+pub use crate::const_len::encode_to_array;
+#[cfg(feature = "hex_macro")]
+pub use crate::macros::decode_to_array;
+
+#[cfg(feature = "const_len")]
+use crate::const_len::{as_u8_slice_ref, encode_hex_str};
+#[cfg(feature = "alloc")]
+use crate::{backend, HexDecodeError};
+
+/// Encodes `bytes` as a lower-case hex string.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub fn encode(bytes: &[u8]) -> String {
+    backend::encode(bytes)
+}
+
+/// Encodes `bytes` as an upper-case hex string.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub fn encode_upper(bytes: &[u8]) -> String {
+    backend::encode_upper(bytes)
+}
+
+/// Decodes bytes from a hex string. Both lower-case and upper-case hex digits are accepted.
+///
+/// # Errors
+///
+/// Returns an error if `hex_str` has an odd length or contains a char that is not a hex digit.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub fn decode(hex_str: &str) -> Result<Vec<u8>, HexDecodeError> {
+    backend::decode(hex_str).map_err(|err| {
+        // The only other error `hex` can return when decoding into a `Vec` is `OddLength`.
+        HexDecodeError::from_hex_error(err, hex_str, 0)
+            .unwrap_or_else(|| HexDecodeError::odd_length(hex_str.len()))
+    })
+}
+
+/// Encodes a byte array as a lower-case hex string without allocations.
+///
+/// Since the length of the hex string cannot be expressed as `2 * N` on stable Rust,
+/// the string is returned as a [`ConstHexString`] wrapper, which dereferences to `str`.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::codec;
+///
+/// let hex_string = codec::encode_const(&[0xc0, 0xff, 0xee]);
+/// assert_eq!(hex_string.as_str(), "c0ffee");
+/// assert_eq!(hex_string.len(), 6);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[cfg(feature = "const_len")]
+pub fn encode_const<const N: usize>(bytes: &[u8; N]) -> ConstHexString<N> {
+    let mut buffer = [0_u16; N];
+    encode_hex_str(bytes, &mut buffer, false);
+    ConstHexString { buffer }
+}
+
+/// Hex string encoding `N` bytes stored on the stack. Returned by [`encode_const()`].
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[cfg(feature = "const_len")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstHexString<const N: usize> {
+    // Each `u16` stores 2 hex digits; using `u16`s allows to get a buffer of the necessary length
+    // without generic const expressions.
+    buffer: [u16; N],
+}
+
+#[cfg(feature = "const_len")]
+impl<const N: usize> ConstHexString<N> {
+    /// Returns the hex string.
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            // SAFETY: the buffer is filled with hex digits on creation.
+            str::from_utf8_unchecked(as_u8_slice_ref(&self.buffer))
+        }
+    }
+}
+
+#[cfg(feature = "const_len")]
+impl<const N: usize> Deref for ConstHexString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "const_len")]
+impl<const N: usize> AsRef<str> for ConstHexString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "const_len")]
+impl<const N: usize> fmt::Display for ConstHexString<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.pad(self.as_str())
+    }
+}
+
+#[cfg(feature = "const_len")]
+impl<const N: usize> fmt::Debug for ConstHexString<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encoding_and_decoding() {
+        let bytes: Vec<u8> = (0_u8..=255).collect();
+        let hex_str = encode(&bytes);
+        assert_eq!(hex_str, hex::encode(&bytes));
+        assert_eq!(decode(&hex_str).unwrap(), bytes);
+
+        let upper_hex_str = encode_upper(&bytes);
+        assert_eq!(upper_hex_str, hex_str.to_ascii_uppercase());
+        assert_eq!(decode(&upper_hex_str).unwrap(), bytes);
+
+        assert_eq!(encode(&[]), "");
+        assert!(decode("").unwrap().is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decoding_errors() {
+        let err = decode("c0ffeg").unwrap_err();
+        assert_eq!(err, HexDecodeError::new(5, b'g'));
+
+        let err = decode("c0\u{fc}fe").unwrap_err();
+        assert_eq!(err, HexDecodeError::new(2, 0xc3));
+
+        let err = decode("c0ffe").unwrap_err();
+        assert_eq!(err, HexDecodeError::odd_length(5));
+    }
+
+    #[cfg(feature = "const_len")]
+    #[test]
+    fn encoding_const() {
+        use alloc::{format, string::ToString};
+
+        let hex_string = encode_const(&[0xc0, 0xff, 0xee]);
+        assert_eq!(hex_string.as_str(), "c0ffee");
+        assert_eq!(hex_string.to_string(), "c0ffee");
+        assert_eq!(format!("{hex_string:?}"), "\"c0ffee\"");
+        assert_eq!(format!("{hex_string:>8}"), "  c0ffee");
+        assert!(hex_string.starts_with("c0"));
+
+        assert_eq!(encode_const(&[]).as_str(), "");
+        let mut bytes = [0_u8; 64];
+        for (byte, i) in bytes.iter_mut().zip(0_u8..) {
+            *byte = i;
+        }
+        let mut expected = [0_u8; 128];
+        hex::encode_to_slice(bytes, &mut expected).unwrap();
+        assert_eq!(encode_const(&bytes).as_str().as_bytes(), expected);
+    }
+}
@@ -0,0 +1,143 @@
+//! Colon-separated hex strings, such as `01:AB:CD`.
+
+use core::fmt;
+
+use crate::HexDecodeError;
+
+/// Displays bytes as upper-case hex pairs separated by colons.
+pub(crate) struct ColonHex<'a>(pub &'a [u8]);
+
+impl fmt::Display for ColonHex<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str(":")?;
+            }
+            write!(formatter, "{byte:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Error parsing a colon-separated hex string.
+#[derive(Debug)]
+pub(crate) enum ColonHexError {
+    /// String contains a char that is neither a hex digit nor a colon.
+    InvalidChar(HexDecodeError),
+    /// Colons are misplaced, or the number of hex digits is odd.
+    InvalidFormat,
+}
+
+/// Bytes parsed from a colon-separated (`01:02:03`) or a bare (`010203`) hex string.
+#[derive(Debug)]
+pub(crate) struct ColonHexBytes<'a> {
+    digits: &'a [u8],
+    /// Distance between the starts of consecutive hex pairs in `digits`.
+    stride: usize,
+}
+
+impl<'a> ColonHexBytes<'a> {
+    /// Parses and validates a hex string. Colons must either separate all hex pairs,
+    /// or be absent altogether.
+    pub fn new(value: &'a str) -> Result<Self, ColonHexError> {
+        let invalid_byte = value
+            .bytes()
+            .enumerate()
+            .find(|&(_, byte)| byte != b':' && !byte.is_ascii_hexdigit());
+        if let Some((position, byte)) = invalid_byte {
+            return Err(ColonHexError::InvalidChar(HexDecodeError::new(
+                position, byte,
+            )));
+        }
+
+        let digits = value.as_bytes();
+        if !digits.contains(&b':') {
+            return if digits.len() % 2 == 0 {
+                Ok(Self { digits, stride: 2 })
+            } else {
+                Err(ColonHexError::InvalidFormat)
+            };
+        }
+
+        if (digits.len() + 1) % 3 != 0 {
+            return Err(ColonHexError::InvalidFormat);
+        }
+        let is_valid = digits
+            .iter()
+            .enumerate()
+            .all(|(i, &ch)| (ch == b':') == (i % 3 == 2));
+        if is_valid {
+            Ok(Self { digits, stride: 3 })
+        } else {
+            Err(ColonHexError::InvalidFormat)
+        }
+    }
+
+    /// Returns the number of encoded bytes.
+    pub fn len(&self) -> usize {
+        (self.digits.len() + self.stride - 2) / self.stride
+    }
+
+    /// Iterates over the encoded bytes.
+    pub fn iter(&self) -> impl Iterator<Item = u8> + 'a {
+        self.digits
+            .chunks(self.stride)
+            .map(|chunk| (hex_digit(chunk[0]) << 4) | hex_digit(chunk[1]))
+    }
+}
+
+fn hex_digit(ch: u8) -> u8 {
+    match ch {
+        b'0'..=b'9' => ch - b'0',
+        b'a'..=b'f' => ch - b'a' + 10,
+        b'A'..=b'F' => ch - b'A' + 10,
+        _ => unreachable!("hex digits are validated on parsing"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{string::ToString, vec::Vec};
+
+    #[test]
+    fn displaying_colon_hex() {
+        assert_eq!(ColonHex(&[]).to_string(), "");
+        assert_eq!(ColonHex(&[0xab]).to_string(), "AB");
+        assert_eq!(ColonHex(&[1, 0xc0, 0xff]).to_string(), "01:C0:FF");
+    }
+
+    #[test]
+    fn parsing_colon_hex() {
+        let valid_strings = [
+            ("", &[] as &[u8]),
+            ("ab", &[0xab]),
+            ("01:C0:ff", &[1, 0xc0, 0xff]),
+            ("01c0ff", &[1, 0xc0, 0xff]),
+        ];
+        for (s, expected) in valid_strings {
+            let bytes = ColonHexBytes::new(s).unwrap();
+            assert_eq!(bytes.len(), expected.len(), "{s}");
+            assert_eq!(bytes.iter().collect::<Vec<_>>(), expected, "{s}");
+        }
+
+        let misplaced_colons = [":", "0", "01:", ":01", "0:1", "01:02:", "01::02", "01:0203"];
+        for s in misplaced_colons {
+            let err = ColonHexBytes::new(s).unwrap_err();
+            assert!(matches!(err, ColonHexError::InvalidFormat), "{s}");
+        }
+
+        let err = ColonHexBytes::new("01:0\u{fc}").unwrap_err();
+        let ColonHexError::InvalidChar(err) = err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(
+            err,
+            HexDecodeError::InvalidChar {
+                position: 4,
+                byte: 0xc3
+            }
+        );
+    }
+}
@@ -1,11 +1,29 @@
 //! Fixed-length hex (de)serialization.
 
 use serde::{
-    de::{Error as DeError, Unexpected, Visitor},
-    Deserializer, Serializer,
+    de::{value::StrDeserializer, Error as DeError, IgnoredAny, SeqAccess, Unexpected, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use core::{array::TryFromSliceError, convert::TryFrom, fmt, marker::PhantomData, mem, slice, str};
+use core::{
+    array::TryFromSliceError,
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+    ptr, slice,
+    str::{self, FromStr},
+};
+
+use crate::{
+    backend,
+    colon::{ColonHex, ColonHexBytes, ColonHexError},
+    error::{check_hex_len, check_lenient_hex_chars, check_strict_hex_chars, ExpectingFn},
+    flex, HexDecodeError, ParseHexError,
+};
 
 /// Analogue of [`Hex`](crate::Hex) for values that have constant-length byte presentation.
 /// This allows to avoid dependency on the `alloc` crate and expresses the byte length constraint
@@ -76,6 +94,31 @@ pub trait ConstHex<T, const N: usize> {
     /// Error returned on unsuccessful deserialization.
     type Error: fmt::Display;
 
+    /// Name of the deserialized type used in deserialization error messages, such as
+    /// `expected 32-byte hex-encoded PublicKey`. Defaults to `byte array`.
+    const TYPE_NAME: &'static str = DEFAULT_TYPE_NAME;
+
+    /// If set to `true`, values are always (de)serialized as hex strings, regardless of whether
+    /// the (de)serializer is [human-readable][hr]. This takes precedence over
+    /// [`Self::ALWAYS_BINARY`]. Defaults to `false`.
+    ///
+    /// [hr]: serde::Serializer::is_human_readable()
+    const ALWAYS_HEX: bool = false;
+
+    /// If set to `true`, values are always (de)serialized as byte arrays, regardless of whether
+    /// the (de)serializer is [human-readable][hr]. Defaults to `false`.
+    ///
+    /// [hr]: serde::Serializer::is_human_readable()
+    const ALWAYS_BINARY: bool = false;
+
+    /// If set to `true`, hex strings are serialized with upper-case digits. Deserialization
+    /// is case-insensitive regardless of this value. Defaults to `false`.
+    const UPPER_CASE: bool = false;
+
+    /// Prefix of hex strings (e.g., `0x`). If set, the prefix is prepended to hex strings
+    /// on serialization and is required on deserialization. Defaults to `None`.
+    const PREFIX: Option<&'static str> = None;
+
     /// Converts the value into bytes. This is used for serialization.
     fn create_bytes(value: &T) -> [u8; N];
 
@@ -87,211 +130,4536 @@ pub trait ConstHex<T, const N: usize> {
     /// to `serde` conventions (no upper-casing of the first letter, no punctuation at the end).
     fn from_bytes(bytes: [u8; N]) -> Result<T, Self::Error>;
 
-    /// Serializes the value for `serde`. This method is not meant to be overridden.
+    /// Validates bytes on deserialization before they are passed to [`Self::from_bytes()`].
+    /// This allows to enforce invariants (e.g., domain-specific byte patterns)
+    /// without duplicating them in `from_bytes()`.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Error conventions are the same as for [`Self::from_bytes()`].
+    fn validate_bytes(bytes: &[u8; N]) -> Result<(), Self::Error> {
+        let _ = bytes;
+        Ok(())
+    }
+
+    /// Describes the expected hex-encoded value in deserialization error messages,
+    /// e.g. `32-byte Ed25519 public key in hex encoding`.
+    ///
+    /// The default implementation writes `{N}-byte hex-encoded {TYPE_NAME}` if [`Self::TYPE_NAME`]
+    /// is overridden, and `hex-encoded byte array of length {N}` otherwise (with a mention
+    /// of [`Self::PREFIX`] if it is set).
+    ///
+    /// # Errors
+    ///
+    /// Propagates formatting errors.
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if Self::TYPE_NAME != DEFAULT_TYPE_NAME {
+            write!(formatter, "{N}-byte hex-encoded {}", Self::TYPE_NAME)
+        } else if let Some(prefix) = Self::PREFIX {
+            write!(
+                formatter,
+                "`{prefix}`-prefixed hex string encoding byte array of length {N}"
+            )
+        } else {
+            write!(formatter, "hex-encoded byte array of length {N}")
+        }
+    }
+
+    /// Serializes the value for `serde`. This method is not meant to be overridden;
+    /// use the associated constants to customize the output format.
     ///
     /// The serialization is a lower-case hex string
     /// for [human-readable][hr] serializers (e.g., JSON or TOML), and the original bytes
     /// returned by [`Self::create_bytes()`] for non-human-readable ones.
+    /// This can be changed with [`Self::ALWAYS_HEX`], [`Self::ALWAYS_BINARY`],
+    /// [`Self::UPPER_CASE`] and [`Self::PREFIX`].
     ///
     /// [hr]: serde::Serializer::is_human_readable()
     fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
-        // Transmutes a `u16` slice as a `u8` one. This is needed because it's currently
-        // impossible to declare a buffer as `[u8; N * 2]`.
-        fn as_u8_slice(slice: &mut [u16]) -> &mut [u8] {
-            if slice.is_empty() {
-                // Empty slices need special handling since `from_raw_parts_mut` doesn't accept
-                // an empty pointer.
-                &mut []
-            } else {
-                let byte_len = mem::size_of_val(slice);
-                let data = (slice as *mut [u16]).cast::<u8>();
-                unsafe {
-                    // SAFETY: length is trivially correct, and `[u8]` does not require
-                    // additional alignment compared to `[u16]`.
-                    slice::from_raw_parts_mut(data, byte_len)
-                }
-            }
-        }
-
-        let value = Self::create_bytes(value);
-        if serializer.is_human_readable() {
-            let mut hex_slice = [0_u16; N];
-            let hex_slice = as_u8_slice(&mut hex_slice);
-
-            hex::encode_to_slice(value, hex_slice).unwrap();
-            // ^ `unwrap` is safe: the length is statically correct.
-            serializer.serialize_str(unsafe {
-                // SAFETY: hex output is always valid UTF-8.
-                str::from_utf8_unchecked(hex_slice)
-            })
+        let bytes = Self::create_bytes(value);
+        let prefix = Self::PREFIX.unwrap_or("");
+        if Self::ALWAYS_HEX {
+            serialize_hex_str(bytes, serializer, Self::UPPER_CASE, prefix)
+        } else if Self::ALWAYS_BINARY {
+            serializer.serialize_bytes(&bytes)
         } else {
-            serializer.serialize_bytes(value.as_ref())
+            serialize_array(bytes, serializer, Self::UPPER_CASE, prefix)
         }
     }
 
-    /// Deserializes a value using `serde`. This method is not meant to be overridden.
+    /// Deserializes a value using `serde`. This method is not meant to be overridden;
+    /// use the associated constants to customize the input format.
     ///
     /// If the deserializer is [human-readable][hr] (e.g., JSON or TOML), this method
     /// expects a hex-encoded string. Otherwise, the method expects a byte array.
+    /// This can be changed with [`Self::ALWAYS_HEX`], [`Self::ALWAYS_BINARY`]
+    /// and [`Self::PREFIX`].
     ///
     /// [hr]: serde::Serializer::is_human_readable()
     fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct HexVisitor<const M: usize>;
+        let visitor = ConstHexVisitor::with_prefix(Self::PREFIX.unwrap_or(""))
+            .with_expecting(Self::expecting);
+        let bytes = if Self::ALWAYS_HEX {
+            deserializer.deserialize_str(visitor)?
+        } else if Self::ALWAYS_BINARY {
+            deserializer.deserialize_bytes(ConstBytesVisitor::new())?
+        } else {
+            deserialize_array(deserializer, visitor)?
+        };
+        from_validated_array::<_, Self, N>(bytes).map_err(D::Error::custom)
+    }
+}
 
-        impl<const M: usize> Visitor<'_> for HexVisitor<M> {
-            type Value = [u8; M];
+/// Default value of [`ConstHex::TYPE_NAME`].
+const DEFAULT_TYPE_NAME: &str = "byte array";
 
-            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(formatter, "hex-encoded byte array of length {M}")
-            }
+/// Transmutes a `u16` slice as a `u8` one. This is needed because it's currently
+/// impossible to declare a buffer as `[u8; N * 2]`.
+///
+/// # Why `[u16; N]`?
+///
+/// Hex-encoding `N` bytes requires a buffer of `2 * N` bytes, and expressing its type
+/// as `[u8; 2 * N]` requires generic const expressions, which are unstable
+/// (see the [tracking issue]). Instead, the buffer is declared as `[u16; N]`, which has
+/// the necessary size, and is viewed as a `u8` slice.
+///
+/// If the `alloc` feature is enabled, [`ConstHex`] serialization uses a heap buffer instead
+/// (see [`encode_hex_string()`]). The stack buffer is still used in no-alloc builds, and in types
+/// that must not allocate regardless of enabled features, such as [`ConstHexBytes`],
+/// [`ConstHexWriter`] and [`codec::encode_const()`](crate::codec::encode_const()).
+/// There is no nightly-only `[u8; 2 * N]` implementation: generic const expressions are
+/// an incomplete feature, and using them would require `[(); 2 * N]:` bounds on all generic
+/// `ConstHex` code. Once the feature is stabilized, buffers should be declared
+/// as `[u8; 2 * N]` directly.
+///
+/// [tracking issue]: https://github.com/rust-lang/rust/issues/76560
+fn as_u8_slice(slice: &mut [u16]) -> &mut [u8] {
+    if slice.is_empty() {
+        // Empty slices need special handling since `from_raw_parts_mut` doesn't accept
+        // an empty pointer.
+        &mut []
+    } else {
+        let byte_len = mem::size_of_val(slice);
+        let data = ptr::from_mut(slice).cast::<u8>();
+        unsafe {
+            // SAFETY: length is trivially correct, and `[u8]` does not require
+            // additional alignment compared to `[u16]`.
+            slice::from_raw_parts_mut(data, byte_len)
+        }
+    }
+}
 
-            fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
-                let mut decoded = [0_u8; M];
-                hex::decode_to_slice(value, &mut decoded)
-                    .map_err(|_| E::invalid_type(Unexpected::Str(value), &self))?;
-                Ok(decoded)
-            }
+/// Lower-case hex digits.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
 
-            fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
-                <[u8; M]>::try_from(value).map_err(|_| E::invalid_length(value.len(), &self))
-            }
+/// Hex-encodes `bytes` into a lower-case hex string in compile time. The hex string
+/// is returned as a byte array.
+///
+/// The output length `M` is a separate const parameter since `[u8; 2 * N]` cannot be expressed
+/// on stable Rust; it can usually be inferred from the context.
+///
+/// # Panics
+///
+/// Panics if `M` is not equal to `2 * bytes.len()`. If the function is evaluated
+/// in compile time, this results in a compilation error.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::codec::encode_to_array;
+///
+/// const FINGERPRINT: [u8; 8] = encode_to_array(&[0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(&FINGERPRINT, b"deadbeef");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_fn_encode")))]
+#[cfg_attr(not(feature = "const_fn_encode"), allow(dead_code))]
+pub const fn encode_to_array<const M: usize>(bytes: &[u8]) -> [u8; M] {
+    assert!(
+        M == 2 * bytes.len(),
+        "output length must be twice the input length"
+    );
+
+    let mut output = [0_u8; M];
+    let mut i = 0;
+    while i < bytes.len() {
+        output[2 * i] = HEX_DIGITS[(bytes[i] >> 4) as usize];
+        output[2 * i + 1] = HEX_DIGITS[(bytes[i] & 0xf) as usize];
+        i += 1;
+    }
+    output
+}
+
+/// Hex-encodes a byte array in compile time, producing a `&'static str`.
+///
+/// The macro accepts an expression evaluating to a byte array (e.g., an array literal
+/// or a constant).
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::const_hex;
+///
+/// const KEY: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+/// const KEY_HEX: &str = const_hex!(KEY);
+/// assert_eq!(KEY_HEX, "deadbeef");
+/// assert_eq!(const_hex!([0x01, 0x02]), "0102");
+/// ```
+#[cfg(feature = "const_fn_encode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "const_fn_encode")))]
+#[macro_export]
+macro_rules! const_hex {
+    ($bytes:expr) => {{
+        const BYTES: &[u8] = &$bytes;
+        const HEX: [u8; 2 * BYTES.len()] = $crate::codec::encode_to_array(BYTES);
+        const HEX_STR: &str = match ::core::str::from_utf8(&HEX) {
+            Ok(hex_str) => hex_str,
+            Err(_) => panic!("hex digits are valid UTF-8"),
+        };
+        HEX_STR
+    }};
+}
+
+/// Immutable version of [`as_u8_slice()`].
+pub(crate) fn as_u8_slice_ref(slice: &[u16]) -> &[u8] {
+    let byte_len = mem::size_of_val(slice);
+    unsafe {
+        // SAFETY: length is trivially correct, `[u8]` does not require additional alignment
+        // compared to `[u16]`, and the pointer is non-null even for empty slices.
+        slice::from_raw_parts(slice.as_ptr().cast::<u8>(), byte_len)
+    }
+}
+
+/// Validates `bytes` with [`ConstHex::validate_bytes()`] and converts them into a value.
+fn from_validated_array<T, H: ConstHex<T, N> + ?Sized, const N: usize>(
+    bytes: [u8; N],
+) -> Result<T, H::Error> {
+    H::validate_bytes(&bytes)?;
+    H::from_bytes(bytes)
+}
+
+/// Serializes `bytes` as a hex string or a byte array depending on whether the serializer
+/// is human-readable. `prefix` is prepended to the hex string.
+fn serialize_array<S: Serializer, const N: usize>(
+    bytes: [u8; N],
+    serializer: S,
+    upper_case: bool,
+    prefix: &str,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serialize_hex_str(bytes, serializer, upper_case, prefix)
+    } else {
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+/// Serializes `bytes` as a hex string regardless of whether the serializer is human-readable.
+///
+/// If the `alloc` feature is enabled, the string is encoded into a heap buffer with
+/// [`encode_hex_string()`]. Otherwise, [`serialize_hex_str_on_stack()`] is used.
+fn serialize_hex_str<S: Serializer, const N: usize>(
+    bytes: [u8; N],
+    serializer: S,
+    upper_case: bool,
+    prefix: &str,
+) -> Result<S::Ok, S::Error> {
+    #[cfg(feature = "alloc")]
+    {
+        serializer.serialize_str(&encode_hex_string(&bytes, upper_case, prefix))
+    }
+    #[cfg(not(feature = "alloc"))]
+    {
+        serialize_hex_str_on_stack(bytes, serializer, upper_case, prefix)
+    }
+}
+
+/// Serializes `bytes` as a hex string using a stack buffer. Does not allocate by itself.
+fn serialize_hex_str_on_stack<S: Serializer, const N: usize>(
+    bytes: [u8; N],
+    serializer: S,
+    upper_case: bool,
+    prefix: &str,
+) -> Result<S::Ok, S::Error> {
+    let mut buffer = [0_u16; N];
+    let hex_str = encode_hex_str(&bytes, &mut buffer, upper_case);
+    if prefix.is_empty() {
+        serializer.serialize_str(hex_str)
+    } else {
+        // We cannot extend the stack buffer to fit the prefix, so we rely
+        // on `collect_str()`, which most serializers implement without allocations.
+        serializer.collect_str(&format_args!("{prefix}{hex_str}"))
+    }
+}
+
+/// Encodes `bytes` as a hex string prefixed with `prefix` into a heap buffer of length
+/// `prefix.len() + 2 * N`. Unlike [`encode_hex_str()`], this does not need to view
+/// a `[u16; N]` buffer as bytes.
+#[cfg(feature = "alloc")]
+fn encode_hex_string<const N: usize>(
+    bytes: &[u8; N],
+    upper_case: bool,
+    prefix: &str,
+) -> alloc::string::String {
+    let mut hex = alloc::vec![0_u8; prefix.len() + 2 * N];
+    hex[..prefix.len()].copy_from_slice(prefix.as_bytes());
+    let digits = &mut hex[prefix.len()..];
+    backend::encode_to_slice(bytes, digits).unwrap();
+    // ^ `unwrap` is safe: the length is correct by construction.
+    if upper_case {
+        digits.make_ascii_uppercase();
+    }
+    alloc::string::String::from_utf8(hex).unwrap()
+    // ^ `unwrap` is safe: the prefix is valid UTF-8, and hex digits are ASCII.
+}
+
+/// Encodes `bytes` as a hex string using `buffer` as the backing storage.
+pub(crate) fn encode_hex_str<'a, const N: usize>(
+    bytes: &[u8; N],
+    buffer: &'a mut [u16; N],
+    upper_case: bool,
+) -> &'a str {
+    let hex_slice = as_u8_slice(buffer);
+    backend::encode_to_slice(bytes, hex_slice).unwrap();
+    // ^ `unwrap` is safe: the length is statically correct.
+    if upper_case {
+        hex_slice.make_ascii_uppercase();
+    }
+    unsafe {
+        // SAFETY: hex output is always valid UTF-8.
+        str::from_utf8_unchecked(hex_slice)
+    }
+}
+
+/// Deserializes an array from a hex string (using `visitor`) or a byte array
+/// depending on whether the deserializer is human-readable.
+fn deserialize_array<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+    visitor: ConstHexVisitor<N>,
+) -> Result<[u8; N], D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_bytes(ConstBytesVisitor::new())
+    }
+}
+
+/// [`Visitor`] producing a byte array of length `N` from a hex string.
+///
+/// This is the visitor used by [`ConstHexForm`] and other [`ConstHex`] implementations
+/// when deserializing from human-readable formats. It can be embedded into custom
+/// [`Deserialize`](serde::Deserialize) implementations.
+///
+/// # Implemented methods
+///
+/// - `visit_str` decodes a hex string, which must start with the prefix
+///   specified on creation (if any) and encode exactly `N` bytes. ASCII whitespace is rejected
+///   unless [`Self::strip_whitespace()`] is called.
+/// - `visit_bytes` copies raw bytes, checking that there are exactly `N` of them.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::ConstHexVisitor;
+/// use serde::Deserializer as _;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(r#""c0ffee""#);
+/// let bytes: [u8; 3] = deserializer.deserialize_str(ConstHexVisitor::new())?;
+/// assert_eq!(bytes, [0xc0, 0xff, 0xee]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug, Clone, Copy)]
+pub struct ConstHexVisitor<const N: usize> {
+    prefix: &'static str,
+    strip_whitespace: bool,
+    type_name: &'static str,
+    expecting: Option<ExpectingFn>,
+}
+
+impl<const N: usize> Default for ConstHexVisitor<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> ConstHexVisitor<N> {
+    /// Creates a visitor for non-prefixed hex strings.
+    pub const fn new() -> Self {
+        Self::with_prefix("")
+    }
+
+    /// Creates a visitor for hex strings starting with the specified `prefix` (e.g., `0x`).
+    pub const fn with_prefix(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            strip_whitespace: false,
+            type_name: DEFAULT_TYPE_NAME,
+            expecting: None,
         }
+    }
 
-        struct BytesVisitor<const M: usize>;
+    /// Makes the visitor ignore ASCII whitespace in hex strings (e.g., `01 02 03`).
+    /// The prefix (if any) must still immediately start the string.
+    #[must_use]
+    pub const fn strip_whitespace(mut self) -> Self {
+        self.strip_whitespace = true;
+        self
+    }
 
-        impl<const M: usize> Visitor<'_> for BytesVisitor<M> {
-            type Value = [u8; M];
+    /// Sets the type name used in error messages (see [`ConstHex::TYPE_NAME`]).
+    #[must_use]
+    pub const fn with_type_name(mut self, type_name: &'static str) -> Self {
+        self.type_name = type_name;
+        self
+    }
 
-            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(formatter, "byte array of length {M}")
-            }
+    /// Sets the function describing the expected value in error messages
+    /// (see [`ConstHex::expecting()`]). This takes precedence over the type name and prefix.
+    pub(crate) const fn with_expecting(mut self, expecting: ExpectingFn) -> Self {
+        self.expecting = Some(expecting);
+        self
+    }
+
+    /// Checks that the hex string has the expected number of digits (`2 * N`).
+    fn check_digit_count<E: DeError>(digit_count: usize) -> Result<(), E> {
+        if digit_count == 2 * N {
+            Ok(())
+        } else {
+            Err(E::custom(HexDecodeError::WrongLength {
+                expected: 2 * N,
+                got: digit_count,
+            }))
+        }
+    }
 
-            fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
-                <[u8; M]>::try_from(value).map_err(|_| E::invalid_length(value.len(), &self))
+    /// Decodes a hex string with whitespace without allocations.
+    fn decode_stripped<E: DeError>(self, hex_str: &str) -> Result<[u8; N], E> {
+        check_lenient_hex_chars(hex_str, self.prefix.len())?;
+
+        let mut digits = [0_u16; N];
+        let digits = as_u8_slice(&mut digits);
+        let mut len = 0;
+        for digit in hex_str.bytes().filter(|ch| !ch.is_ascii_whitespace()) {
+            if let Some(dest) = digits.get_mut(len) {
+                *dest = digit;
             }
+            len += 1;
         }
+        check_hex_len(len)?;
+        Self::check_digit_count(len)?;
+
+        let mut decoded = [0_u8; N];
+        backend::decode_to_slice(digits, &mut decoded).map_err(E::custom)?;
+        Ok(decoded)
+    }
+}
 
-        let maybe_bytes = if deserializer.is_human_readable() {
-            deserializer.deserialize_str(HexVisitor)
+impl<const N: usize> Visitor<'_> for ConstHexVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(expecting) = self.expecting {
+            expecting(formatter)
+        } else if self.type_name != DEFAULT_TYPE_NAME {
+            write!(formatter, "{N}-byte hex-encoded {}", self.type_name)
+        } else if self.prefix.is_empty() {
+            write!(formatter, "hex-encoded byte array of length {N}")
         } else {
-            deserializer.deserialize_bytes(BytesVisitor)
+            write!(
+                formatter,
+                "`{}`-prefixed hex string encoding byte array of length {N}",
+                self.prefix
+            )
+        }
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        let Some(hex_str) = value.strip_prefix(self.prefix) else {
+            return Err(E::invalid_value(Unexpected::Str(value), &self));
         };
-        maybe_bytes.and_then(|bytes| Self::from_bytes(bytes).map_err(D::Error::custom))
+        if self.strip_whitespace {
+            return self.decode_stripped(hex_str);
+        }
+        check_hex_len(hex_str.len())?;
+        Self::check_digit_count(hex_str.len())?;
+        let mut decoded = [0_u8; N];
+        backend::decode_to_slice(hex_str, &mut decoded).map_err(|err| {
+            match HexDecodeError::from_hex_error(err, hex_str, self.prefix.len()) {
+                Some(err) => E::custom(err),
+                None => E::invalid_type(Unexpected::Str(value), &self),
+            }
+        })?;
+        Ok(decoded)
+    }
+
+    fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
+        <[u8; N]>::try_from(value).map_err(|_| E::invalid_length(value.len(), &self))
     }
 }
 
-/// A dummy container for use inside `#[serde(with)]` attribute if the underlying type
-/// implements [`ConstHex`].
+/// [`Visitor`] producing a byte array of length `N` from raw bytes.
+///
+/// This is the visitor used by [`ConstHexForm`] and other [`ConstHex`] implementations
+/// when deserializing from binary formats.
+///
+/// # Implemented methods
+///
+/// - `visit_bytes` accepts a native byte array, checking that it has exactly `N` bytes.
+/// - `visit_seq` reads a sequence of exactly `N` bytes; formats without native byte array
+///   support (e.g., JSON) represent bytes this way.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::ConstBytesVisitor;
+/// use serde::Deserializer as _;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3]");
+/// let bytes: [u8; 3] = deserializer.deserialize_seq(ConstBytesVisitor::new())?;
+/// assert_eq!(bytes, [1, 2, 3]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
 #[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
-#[derive(Debug)]
-pub struct ConstHexForm<T>(PhantomData<T>);
-
-impl<const N: usize> ConstHex<[u8; N], N> for ConstHexForm<[u8; N]> {
-    type Error = TryFromSliceError;
+#[derive(Debug, Clone, Copy)]
+pub struct ConstBytesVisitor<const N: usize>(());
 
-    fn create_bytes(buffer: &[u8; N]) -> [u8; N] {
-        *buffer
+impl<const N: usize> Default for ConstBytesVisitor<N> {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    fn from_bytes(bytes: [u8; N]) -> Result<[u8; N], Self::Error> {
-        Ok(bytes)
+impl<const N: usize> ConstBytesVisitor<N> {
+    /// Creates a new visitor.
+    pub const fn new() -> Self {
+        Self(())
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl<'de, const N: usize> Visitor<'de> for ConstBytesVisitor<N> {
+    type Value = [u8; N];
 
-    use alloc::string::ToString;
-    use serde_derive::{Deserialize, Serialize};
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "byte array of length {N}")
+    }
 
-    #[derive(Debug, PartialEq, Serialize, Deserialize)]
-    struct Arrays {
-        #[serde(with = "ConstHexForm")]
-        array: [u8; 16],
-        #[serde(with = "ConstHexForm")]
-        longer_array: [u8; 32],
+    fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
+        <[u8; N]>::try_from(value).map_err(|_| E::invalid_length(value.len(), &self))
     }
 
-    #[test]
-    fn serializing_arrays() {
-        let arrays = Arrays {
-            array: [11; 16],
-            longer_array: [240; 32],
-        };
-        let json = serde_json::to_string(&arrays).unwrap();
-        assert!(json.contains(&"0b".repeat(16)));
+    // Formats without native byte array support (e.g., JSON) represent bytes as sequences.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0_u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+        }
 
-        let arrays_copy: Arrays = serde_json::from_str(&json).unwrap();
-        assert_eq!(arrays_copy, arrays);
+        let mut len = N;
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            len += 1;
+        }
+        if len == N {
+            Ok(bytes)
+        } else {
+            Err(A::Error::invalid_length(len, &self))
+        }
     }
+}
 
-    #[test]
-    fn deserializing_array_with_incorrect_length() {
-        let json = serde_json::json!({
-            "array": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
-            "longer_array": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
-        });
-        let err = serde_json::from_value::<Arrays>(json)
-            .unwrap_err()
-            .to_string();
+/// A dummy container for use inside `#[serde(with)]` attribute if the underlying type
+/// implements [`ConstHex`].
+///
+/// # Supported types
+///
+/// - `[u8; N]`
+/// - Any `Copy` type convertible to and from `[u8; N]` via [`Into`] and [`From`], such as
+///   a newtype around a byte array
+/// - `GenericArray<u8, N>` from the [`generic-array`](https://crates.io/crates/generic-array)
+///   crate, for lengths from 1 to 64 bytes (requires the `generic-array` feature). This is
+///   a special case of the previous item.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, ConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// pub struct Key([u8; 4]);
+///
+/// impl From<[u8; 4]> for Key {
+///     fn from(bytes: [u8; 4]) -> Self {
+///         Self(bytes)
+///     }
+/// }
+///
+/// impl From<Key> for [u8; 4] {
+///     fn from(key: Key) -> Self {
+///         key.0
+///     }
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ConstHexForm")]
+///     key: Key,
+/// }
+///
+/// let test = Test { key: Key([1, 2, 3, 4]) };
+/// assert_eq!(serde_json::to_string(&test)?, r#"{"key":"01020304"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct ConstHexForm<T>(PhantomData<T>);
 
-        assert!(err.contains("invalid type"), "{}", err);
-        assert!(err.contains("expected hex-encoded byte array"), "{}", err);
+// Traits are implemented manually since derives would require bounds on `T`.
+impl<T> Clone for ConstHexForm<T> {
+    fn clone(&self) -> Self {
+        *self
     }
+}
 
-    #[test]
-    fn deserializing_array_with_incorrect_length_from_binary_format() {
-        #[derive(Debug, Serialize, Deserialize)]
-        struct ArrayHolder<const N: usize>(#[serde(with = "ConstHexForm")] [u8; N]);
+impl<T> Copy for ConstHexForm<T> {}
 
-        let buffer = bincode::serialize(&ArrayHolder([5; 6])).unwrap();
-        let err = bincode::deserialize::<ArrayHolder<4>>(&buffer).unwrap_err();
+impl<T> Default for ConstHexForm<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
 
-        assert_eq!(
-            err.to_string(),
-            "invalid length 6, expected byte array of length 4"
-        );
+impl<T> PartialEq for ConstHexForm<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
     }
+}
 
-    #[test]
-    fn custom_type() {
-        use ed25519_compact::PublicKey;
+impl<T> Eq for ConstHexForm<T> {}
 
-        struct PublicKeyHex(());
-        impl ConstHex<PublicKey, 32> for PublicKeyHex {
-            type Error = ed25519_compact::Error;
+impl<T> Hash for ConstHexForm<T> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {
+        // No state to hash
+    }
+}
 
-            fn create_bytes(pk: &PublicKey) -> [u8; 32] {
-                **pk
-            }
+impl<T, const N: usize> ConstHex<T, N> for ConstHexForm<T>
+where
+    T: Copy + Into<[u8; N]> + From<[u8; N]>,
+{
+    // Retained from the times when only `[u8; N]` was supported; conversions never fail.
+    type Error = TryFromSliceError;
 
-            fn from_bytes(bytes: [u8; 32]) -> Result<PublicKey, Self::Error> {
-                PublicKey::from_slice(&bytes)
-            }
-        }
+    fn create_bytes(value: &T) -> [u8; N] {
+        (*value).into()
+    }
 
-        #[derive(Debug, Serialize, Deserialize)]
-        struct Holder {
-            #[serde(with = "PublicKeyHex")]
-            public_key: PublicKey,
-        }
+    fn from_bytes(bytes: [u8; N]) -> Result<T, Self::Error> {
+        Ok(T::from(bytes))
+    }
+}
 
+/// Analogue of [`ConstHexForm`] for types exposing their bytes via [`AsRef`]`<[u8; N]>`
+/// rather than [`Into`]`<[u8; N]>`, and constructible via [`From`]`<[u8; N]>`. The types
+/// are not required to be `Copy`. This covers, e.g., fixed-size cryptographic keys.
+///
+/// (De)serialization uses the same format as `ConstHexForm`.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{AsRefConstHexForm, ConstHex as _};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct SecretKey([u8; 4]);
+///
+/// impl AsRef<[u8; 4]> for SecretKey {
+///     fn as_ref(&self) -> &[u8; 4] {
+///         &self.0
+///     }
+/// }
+///
+/// impl From<[u8; 4]> for SecretKey {
+///     fn from(bytes: [u8; 4]) -> Self {
+///         Self(bytes)
+///     }
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "AsRefConstHexForm")]
+///     key: SecretKey,
+/// }
+///
+/// let test: Test = serde_json::from_str(r#"{"key":"01020304"}"#)?;
+/// assert_eq!(test.key, SecretKey([1, 2, 3, 4]));
+/// assert_eq!(serde_json::to_string(&test)?, r#"{"key":"01020304"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct AsRefConstHexForm<T>(PhantomData<T>);
+
+impl<T, const N: usize> ConstHex<T, N> for AsRefConstHexForm<T>
+where
+    T: AsRef<[u8; N]> + From<[u8; N]>,
+{
+    type Error = core::convert::Infallible;
+
+    fn create_bytes(value: &T) -> [u8; N] {
+        *value.as_ref()
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<T, Self::Error> {
+        Ok(T::from(bytes))
+    }
+}
+
+/// Analogue of [`ConstHexForm`] that uses upper-case hex strings for
+/// [human-readable][hr] serializers.
+///
+/// Deserialization is case-insensitive, so values serialized with `ConstHexForm` can be read
+/// back with `UpperConstHexForm` and vice versa.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, UpperConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Fingerprint {
+///     #[serde(with = "UpperConstHexForm")]
+///     bytes: [u8; 4],
+/// }
+///
+/// let fingerprint = Fingerprint { bytes: [0xde, 0xad, 0xbe, 0xef] };
+/// let json = serde_json::to_string(&fingerprint)?;
+/// assert_eq!(json, r#"{"bytes":"DEADBEEF"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct UpperConstHexForm<T>(PhantomData<T>);
+
+impl<const N: usize> ConstHex<[u8; N], N> for UpperConstHexForm<[u8; N]> {
+    type Error = TryFromSliceError;
+    const UPPER_CASE: bool = true;
+
+    fn create_bytes(buffer: &[u8; N]) -> [u8; N] {
+        *buffer
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<[u8; N], Self::Error> {
+        Ok(bytes)
+    }
+}
+
+/// Analogue of [`ConstHexForm`] that prepends the `0x` prefix to hex strings
+/// for [human-readable][hr] serializers.
+///
+/// On deserialization, the prefix is required; hex strings without it are rejected.
+/// Binary serialization is not affected.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, PrefixedConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Account {
+///     #[serde(with = "PrefixedConstHexForm")]
+///     address: [u8; 4],
+/// }
+///
+/// let account = Account { address: [0xde, 0xad, 0xbe, 0xef] };
+/// let json = serde_json::to_string(&account)?;
+/// assert_eq!(json, r#"{"address":"0xdeadbeef"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct PrefixedConstHexForm<T>(PhantomData<T>);
+
+impl<const N: usize> ConstHex<[u8; N], N> for PrefixedConstHexForm<[u8; N]> {
+    type Error = TryFromSliceError;
+    const PREFIX: Option<&'static str> = Some("0x");
+
+    fn create_bytes(buffer: &[u8; N]) -> [u8; N] {
+        *buffer
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<[u8; N], Self::Error> {
+        Ok(bytes)
+    }
+}
+
+/// Analogue of [`ConstHexForm`] that ignores ASCII whitespace in hex strings
+/// on deserialization (e.g., `01 02 03 04` or `0102 0304`).
+///
+/// Serialization and binary deserialization are the same as for `ConstHexForm`;
+/// in particular, serialized hex strings contain no whitespace. Deserialization does not allocate.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, LenientConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Key {
+///     #[serde(with = "LenientConstHexForm")]
+///     bytes: [u8; 4],
+/// }
+///
+/// let key: Key = serde_json::from_str(r#"{"bytes":" de ad\tbe ef "}"#)?;
+/// assert_eq!(key.bytes, [0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(serde_json::to_string(&key)?, r#"{"bytes":"deadbeef"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct LenientConstHexForm<T>(PhantomData<T>);
+
+impl<const N: usize> ConstHex<[u8; N], N> for LenientConstHexForm<[u8; N]> {
+    type Error = TryFromSliceError;
+
+    fn create_bytes(buffer: &[u8; N]) -> [u8; N] {
+        *buffer
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<[u8; N], Self::Error> {
+        Ok(bytes)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<[u8; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = ConstHexVisitor::new().strip_whitespace();
+        let bytes = deserialize_array(deserializer, visitor)?;
+        from_validated_array::<_, Self, N>(bytes).map_err(D::Error::custom)
+    }
+}
+
+/// Analogue of [`ConstHexForm`] that serializes arrays as upper-case hex pairs separated
+/// by colons (e.g., `01:AB:CD`) for [human-readable][hr] serializers.
+///
+/// See [`ColonHexForm`](crate::ColonHexForm) for more details on the format.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ColonConstHexForm, ConstHex as _};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Device {
+///     #[serde(with = "ColonConstHexForm")]
+///     mac: [u8; 6],
+/// }
+///
+/// let device = Device { mac: [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e] };
+/// let json = serde_json::to_string(&device)?;
+/// assert_eq!(json, r#"{"mac":"00:1A:2B:3C:4D:5E"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct ColonConstHexForm<T>(PhantomData<T>);
+
+impl<const N: usize> ConstHex<[u8; N], N> for ColonConstHexForm<[u8; N]> {
+    type Error = TryFromSliceError;
+
+    fn create_bytes(buffer: &[u8; N]) -> [u8; N] {
+        *buffer
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<[u8; N], Self::Error> {
+        Ok(bytes)
+    }
+
+    fn serialize<S: Serializer>(value: &[u8; N], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&ColonHex(value))
+        } else {
+            serializer.serialize_bytes(value)
+        }
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<[u8; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ColonHexVisitor)
+        } else {
+            deserializer.deserialize_bytes(ConstBytesVisitor::new())
+        }
+    }
+}
+
+struct ColonHexVisitor<const N: usize>;
+
+impl<const N: usize> Visitor<'_> for ColonHexVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "colon-separated hex string encoding byte array of length {N}"
+        )
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        let bytes = match ColonHexBytes::new(value) {
+            Ok(bytes) => bytes,
+            Err(ColonHexError::InvalidChar(err)) => return Err(E::custom(err)),
+            Err(ColonHexError::InvalidFormat) => {
+                return Err(E::invalid_value(Unexpected::Str(value), &self));
+            }
+        };
+        if bytes.len() != N {
+            return Err(E::invalid_length(bytes.len(), &self));
+        }
+
+        let mut decoded = [0_u8; N];
+        for (dest, byte) in decoded.iter_mut().zip(bytes.iter()) {
+            *dest = byte;
+        }
+        Ok(decoded)
+    }
+}
+
+/// Analogue of [`ConstHexForm`] that zeroizes the intermediate decoded array on deserialization.
+///
+/// See [`ZeroizeHexForm`](crate::ZeroizeHexForm) for more details.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, ZeroizeConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Keypair {
+///     #[serde(with = "ZeroizeConstHexForm")]
+///     secret: [u8; 4],
+/// }
+///
+/// let keypair: Keypair = serde_json::from_str(r#"{"secret":"c0ffee00"}"#)?;
+/// assert_eq!(keypair.secret, [0xc0, 0xff, 0xee, 0]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "zeroize", feature = "const_len"))))]
+#[derive(Debug)]
+pub struct ZeroizeConstHexForm<T>(PhantomData<T>);
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> ConstHex<[u8; N], N> for ZeroizeConstHexForm<[u8; N]> {
+    type Error = TryFromSliceError;
+
+    fn create_bytes(buffer: &[u8; N]) -> [u8; N] {
+        *buffer
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<[u8; N], Self::Error> {
+        Ok(bytes)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<[u8; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut bytes = deserialize_array(deserializer, ConstHexVisitor::new())?;
+        from_array_zeroizing::<_, Self, N>(&mut bytes).map_err(D::Error::custom)
+    }
+}
+
+/// Converts `bytes` into a value and zeroizes them, regardless of the conversion outcome.
+#[cfg(feature = "zeroize")]
+fn from_array_zeroizing<T, H: ConstHex<T, N>, const N: usize>(
+    bytes: &mut [u8; N],
+) -> Result<T, H::Error> {
+    let result = from_validated_array::<T, H, N>(*bytes);
+    zeroize::Zeroize::zeroize(bytes);
+    result
+}
+
+/// [`ConstHex`] implementation for [`Pod`](bytemuck::Pod) types that (de)serializes values
+/// via their in-memory byte representation.
+///
+/// The byte length `N` must be specified explicitly and be equal to the size of `T`,
+/// since it cannot be derived from `T` on stable Rust. A mismatch is detected in compile time.
+/// Note that the byte representation of multi-byte primitives (e.g., `f32`) depends
+/// on the target endianness.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, PodHex};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Vertex {
+///     #[serde(with = "PodHex::<[f32; 2], 8>")]
+///     position: [f32; 2],
+/// }
+///
+/// let vertex = Vertex { position: [1.0, -0.5] };
+/// let json = serde_json::to_string(&vertex)?;
+/// let vertex_copy: Vertex = serde_json::from_str(&json)?;
+/// assert_eq!(vertex_copy, vertex);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+///
+/// Specifying an incorrect byte length leads to a compilation error:
+///
+/// ```compile_fail
+/// use hex_buffer_serde::{ConstHex as _, PodHex};
+///
+/// let bytes = PodHex::<[f32; 2], 4>::create_bytes(&[1.0, -0.5]);
+/// ```
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+#[derive(Debug)]
+pub struct PodHex<T, const N: usize>(PhantomData<T>);
+
+#[cfg(feature = "bytemuck")]
+impl<T, const N: usize> PodHex<T, N> {
+    /// Evaluating this constant fails compilation if `N` is not equal to the size of `T`.
+    const SIZE_CHECK: () = assert!(
+        mem::size_of::<T>() == N,
+        "byte length must be equal to the size of the type"
+    );
+}
+
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::Pod, const N: usize> ConstHex<T, N> for PodHex<T, N> {
+    type Error = core::convert::Infallible;
+
+    fn create_bytes(value: &T) -> [u8; N] {
+        let () = Self::SIZE_CHECK;
+        let mut bytes = [0_u8; N];
+        bytes.copy_from_slice(bytemuck::bytes_of(value));
+        bytes
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<T, Self::Error> {
+        let () = Self::SIZE_CHECK;
+        Ok(bytemuck::pod_read_unaligned(&bytes))
+    }
+}
+
+/// [`ConstHex`] implementation for [`Uuid`](uuid::Uuid)s that (de)serializes them
+/// as compact 32-char hex strings without hyphens.
+///
+/// This differs from the default `serde` implementation in the `uuid` crate, which uses
+/// the hyphenated format for human-readable formats. See [`HyphenatedUuidHex`] for a form
+/// using the hyphenated format.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, UuidHex};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use uuid::Uuid;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Request {
+///     #[serde(with = "UuidHex")]
+///     id: Uuid,
+/// }
+///
+/// let id = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+/// let json = serde_json::to_string(&Request { id })?;
+/// assert_eq!(json, r#"{"id":"67e5504410b1426f9247bb680e5fe0c8"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+#[derive(Debug)]
+pub struct UuidHex(());
+
+#[cfg(feature = "uuid")]
+impl ConstHex<uuid::Uuid, 16> for UuidHex {
+    type Error = core::convert::Infallible;
+
+    fn create_bytes(value: &uuid::Uuid) -> [u8; 16] {
+        *value.as_bytes()
+    }
+
+    fn from_bytes(bytes: [u8; 16]) -> Result<uuid::Uuid, Self::Error> {
+        Ok(uuid::Uuid::from_bytes(bytes))
+    }
+}
+
+/// [`ConstHex`] implementation for [`Uuid`](uuid::Uuid)s that (de)serializes them
+/// in the hyphenated format (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`) for human-readable formats,
+/// and as 16-byte arrays otherwise.
+///
+/// Hyphens are treated as a part of the format: on deserialization, they are required
+/// at the standard positions.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, HyphenatedUuidHex};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use uuid::Uuid;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Request {
+///     #[serde(with = "HyphenatedUuidHex")]
+///     id: Uuid,
+/// }
+///
+/// let id = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+/// let json = serde_json::to_string(&Request { id })?;
+/// assert_eq!(json, r#"{"id":"67e55044-10b1-426f-9247-bb680e5fe0c8"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+#[derive(Debug)]
+pub struct HyphenatedUuidHex(());
+
+#[cfg(feature = "uuid")]
+impl ConstHex<uuid::Uuid, 16> for HyphenatedUuidHex {
+    type Error = core::convert::Infallible;
+
+    fn create_bytes(value: &uuid::Uuid) -> [u8; 16] {
+        *value.as_bytes()
+    }
+
+    fn from_bytes(bytes: [u8; 16]) -> Result<uuid::Uuid, Self::Error> {
+        Ok(uuid::Uuid::from_bytes(bytes))
+    }
+
+    fn serialize<S: Serializer>(value: &uuid::Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&value.hyphenated())
+        } else {
+            serializer.serialize_bytes(value.as_bytes())
+        }
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<uuid::Uuid, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HyphenatedUuidVisitor)
+        } else {
+            let bytes = deserializer.deserialize_bytes(ConstBytesVisitor::new())?;
+            Ok(uuid::Uuid::from_bytes(bytes))
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+struct HyphenatedUuidVisitor;
+
+#[cfg(feature = "uuid")]
+impl HyphenatedUuidVisitor {
+    /// Positions of hyphens in a hyphenated UUID string.
+    const HYPHEN_POSITIONS: [usize; 4] = [8, 13, 18, 23];
+}
+
+#[cfg(feature = "uuid")]
+impl Visitor<'_> for HyphenatedUuidVisitor {
+    type Value = uuid::Uuid;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("hyphenated UUID string")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        let bytes = value.as_bytes();
+        let is_hyphenated =
+            bytes.len() == 36 && Self::HYPHEN_POSITIONS.iter().all(|&pos| bytes[pos] == b'-');
+        if !is_hyphenated {
+            return Err(E::invalid_value(Unexpected::Str(value), &self));
+        }
+
+        let mut hex_digits = [0_u8; 32];
+        let digits = bytes.iter().copied().filter(|&byte| byte != b'-');
+        for (dest, digit) in hex_digits.iter_mut().zip(digits) {
+            *dest = digit;
+        }
+        let mut uuid_bytes = [0_u8; 16];
+        backend::decode_to_slice(hex_digits, &mut uuid_bytes)
+            .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))?;
+        Ok(uuid::Uuid::from_bytes(uuid_bytes))
+    }
+}
+
+/// Wrapper around a [`ConstHex`] implementation that always (de)serializes values
+/// as hex strings, regardless of whether the (de)serializer is [human-readable][hr].
+///
+/// This is an analogue of [`ForceHexStr`](crate::ForceHexStr) for `ConstHex`.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, ConstHexForm, ForceConstHexStr};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ForceConstHexStr::<ConstHexForm<_>>")]
+///     buffer: [u8; 4],
+/// }
+///
+/// let test = Test { buffer: [0xde, 0xad, 0xbe, 0xef] };
+/// let bytes = bincode::serialize(&test)?;
+/// assert_eq!(bytes, bincode::serialize("deadbeef")?);
+/// let test_copy: Test = bincode::deserialize(&bytes)?;
+/// assert_eq!(test_copy, test);
+/// # Ok::<_, bincode::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct ForceConstHexStr<H>(PhantomData<H>);
+
+impl<T, H: ConstHex<T, N>, const N: usize> ConstHex<T, N> for ForceConstHexStr<H> {
+    type Error = H::Error;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+    const ALWAYS_HEX: bool = true;
+    const UPPER_CASE: bool = H::UPPER_CASE;
+    const PREFIX: Option<&'static str> = H::PREFIX;
+
+    fn create_bytes(value: &T) -> [u8; N] {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<T, Self::Error> {
+        H::from_bytes(bytes)
+    }
+
+    fn validate_bytes(bytes: &[u8; N]) -> Result<(), Self::Error> {
+        H::validate_bytes(bytes)
+    }
+}
+
+/// Wrapper around a [`ConstHex`] implementation that always (de)serializes values
+/// as byte arrays, regardless of whether the (de)serializer is [human-readable][hr].
+///
+/// This is an analogue of [`ForceBinary`](crate::ForceBinary) for `ConstHex`.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, ConstHexForm, ForceConstBinary};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ForceConstBinary::<ConstHexForm<_>>")]
+///     buffer: [u8; 4],
+/// }
+///
+/// let test = Test { buffer: [0xde, 0xad, 0xbe, 0xef] };
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"buffer":[222,173,190,239]}"#);
+/// let test_copy: Test = serde_json::from_str(&json)?;
+/// assert_eq!(test_copy, test);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct ForceConstBinary<H>(PhantomData<H>);
+
+impl<T, H: ConstHex<T, N>, const N: usize> ConstHex<T, N> for ForceConstBinary<H> {
+    type Error = H::Error;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+    const ALWAYS_BINARY: bool = true;
+
+    fn create_bytes(value: &T) -> [u8; N] {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<T, Self::Error> {
+        H::from_bytes(bytes)
+    }
+
+    fn validate_bytes(bytes: &[u8; N]) -> Result<(), Self::Error> {
+        H::validate_bytes(bytes)
+    }
+}
+
+/// Wrapper around a [`ConstHex`] implementation that accepts both hex strings and byte sequences
+/// (e.g., `"01020304"` and `[1, 2, 3, 4]` in JSON) when deserializing from
+/// [human-readable][hr] formats.
+///
+/// This is useful when migrating from a raw-bytes presentation to hex strings, so that
+/// previously serialized data remains readable. Values are always serialized as hex strings
+/// in human-readable formats. The wrapped implementation is only used to convert values
+/// to / from bytes; the hex string is always lower-case and has no prefix. Invalid hex strings
+/// are still rejected. Non-human-readable formats are unaffected.
+///
+/// [`HexForm`](crate::HexForm) and other [`Hex`](crate::Hex) implementations accept byte sequences
+/// by default, so this wrapper has no variable-length analogue.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, ConstHexForm, ConstHexOrBytes};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ConstHexOrBytes::<ConstHexForm<_>>")]
+///     buffer: [u8; 4],
+/// }
+///
+/// let old: Test = serde_json::from_str(r#"{ "buffer": [222, 173, 190, 239] }"#)?;
+/// let new: Test = serde_json::from_str(r#"{ "buffer": "deadbeef" }"#)?;
+/// assert_eq!(old, new);
+/// assert_eq!(serde_json::to_string(&old)?, r#"{"buffer":"deadbeef"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct ConstHexOrBytes<H>(PhantomData<H>);
+
+impl<T, H: ConstHex<T, N>, const N: usize> ConstHex<T, N> for ConstHexOrBytes<H> {
+    type Error = H::Error;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+
+    fn create_bytes(value: &T) -> [u8; N] {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<T, Self::Error> {
+        H::from_bytes(bytes)
+    }
+
+    fn validate_bytes(bytes: &[u8; N]) -> Result<(), Self::Error> {
+        H::validate_bytes(bytes)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = if deserializer.is_human_readable() {
+            let visitor = ConstHexVisitor::new().with_expecting(H::expecting);
+            deserializer.deserialize_any(HexOrBytesVisitor(visitor))?
+        } else {
+            deserializer.deserialize_bytes(ConstBytesVisitor::new())?
+        };
+        from_validated_array::<_, Self, N>(bytes).map_err(D::Error::custom)
+    }
+}
+
+/// [`Visitor`] used by [`ConstHexOrBytes`] that accepts hex strings and byte sequences.
+struct HexOrBytesVisitor<const N: usize>(ConstHexVisitor<N>);
+
+impl<'de, const N: usize> Visitor<'de> for HexOrBytesVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.expecting(formatter)?;
+        write!(formatter, " or sequence of {N} bytes")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        self.0.visit_str(value)
+    }
+
+    fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
+        self.0.visit_bytes(value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        ConstBytesVisitor::new().visit_seq(seq)
+    }
+}
+
+/// Analogue of [`FlexHex`](crate::FlexHex) for [`ConstHex`] implementations.
+///
+/// When deserializing from [human-readable][hr] formats, the formats are tried in this order:
+///
+/// 1. Strings accepted by `H` (e.g., hex strings for [`ConstHexForm`]).
+/// 2. Base64-encoded strings with the standard alphabet and padding, if the `base64`
+///    crate feature is enabled. Base64 is only tried if `H` fails to deserialize the string.
+/// 3. Byte arrays.
+/// 4. Sequences of bytes (e.g., `[1, 2, 3]` in JSON).
+///
+/// Serialization and deserialization from binary formats are delegated to `H`.
+/// Unlike [`ConstHexOrBytes`], this wrapper delegates string deserialization to `H`,
+/// so it can be used with forms having a custom string presentation.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstFlexHex, ConstHex as _, PrefixedConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ConstFlexHex::<PrefixedConstHexForm<_>>")]
+///     buffer: [u8; 3],
+/// }
+///
+/// let from_hex: Test = serde_json::from_str(r#"{ "buffer": "0xc0ffee" }"#)?;
+/// let from_seq: Test = serde_json::from_str(r#"{ "buffer": [192, 255, 238] }"#)?;
+/// assert_eq!(from_hex, from_seq);
+/// assert_eq!(serde_json::to_string(&from_seq)?, r#"{"buffer":"0xc0ffee"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct ConstFlexHex<H>(PhantomData<H>);
+
+impl<T, H: ConstHex<T, N>, const N: usize> ConstHex<T, N> for ConstFlexHex<H> {
+    type Error = H::Error;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+
+    fn create_bytes(value: &T) -> [u8; N] {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<T, Self::Error> {
+        H::from_bytes(bytes)
+    }
+
+    fn validate_bytes(bytes: &[u8; N]) -> Result<(), Self::Error> {
+        H::validate_bytes(bytes)
+    }
+
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(value, serializer)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ConstFlexHexVisitor::<T, H, N>(PhantomData))
+        } else {
+            H::deserialize(deserializer)
+        }
+    }
+}
+
+/// [`Visitor`] used by [`ConstFlexHex`].
+struct ConstFlexHexVisitor<T, H, const N: usize>(PhantomData<fn() -> (T, H)>);
+
+impl<'de, T, H: ConstHex<T, N>, const N: usize> Visitor<'de> for ConstFlexHexVisitor<T, H, N> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)?;
+        formatter.write_str(flex::EXPECTING_SUFFIX)
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        match H::deserialize(StrDeserializer::<E>::new(value)) {
+            Ok(value) => Ok(value),
+            #[cfg(feature = "base64")]
+            Err(hex_err) => {
+                let bytes = flex::decode_base64(value, &hex_err)?;
+                let bytes = <[u8; N]>::try_from(bytes.as_slice())
+                    .map_err(|_| E::invalid_length(bytes.len(), &self))?;
+                from_validated_array::<T, H, N>(bytes).map_err(E::custom)
+            }
+            #[cfg(not(feature = "base64"))]
+            Err(hex_err) => Err(hex_err),
+        }
+    }
+
+    fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
+        let bytes = ConstBytesVisitor::<N>::new().visit_bytes(value)?;
+        from_validated_array::<T, H, N>(bytes).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let bytes = ConstBytesVisitor::<N>::new().visit_seq(seq)?;
+        from_validated_array::<T, H, N>(bytes).map_err(A::Error::custom)
+    }
+}
+
+/// Analogue of [`StrictHex`](crate::StrictHex) for [`ConstHex`] implementations.
+///
+/// Besides only accepting lower-case hex digits without a prefix when deserializing from
+/// [human-readable][hr] formats, the wrapper checks that the hex string consists
+/// of exactly `2 * N` digits before passing it to `H`.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, ConstHexForm, StrictConstHex};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "StrictConstHex::<ConstHexForm<_>>")]
+///     buffer: [u8; 2],
+/// }
+///
+/// let test: Test = serde_json::from_str(r#"{ "buffer": "aabb" }"#)?;
+/// assert_eq!(test.buffer, [0xaa, 0xbb]);
+/// let err = serde_json::from_str::<Test>(r#"{ "buffer": "AABB" }"#).unwrap_err();
+/// assert!(err.to_string().starts_with("strict hex requires lowercase characters only"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct StrictConstHex<H>(PhantomData<H>);
+
+impl<T, H: ConstHex<T, N>, const N: usize> ConstHex<T, N> for StrictConstHex<H> {
+    type Error = H::Error;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+
+    fn create_bytes(value: &T) -> [u8; N] {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn from_bytes(bytes: [u8; N]) -> Result<T, Self::Error> {
+        H::from_bytes(bytes)
+    }
+
+    fn validate_bytes(bytes: &[u8; N]) -> Result<(), Self::Error> {
+        H::validate_bytes(bytes)
+    }
+
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(value, serializer)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrictConstHexVisitor::<T, H, N>(PhantomData))
+        } else {
+            H::deserialize(deserializer)
+        }
+    }
+}
+
+/// [`Visitor`] used by [`StrictConstHex`].
+struct StrictConstHexVisitor<T, H, const N: usize>(PhantomData<fn() -> (T, H)>);
+
+impl<T, H: ConstHex<T, N>, const N: usize> Visitor<'_> for StrictConstHexVisitor<T, H, N> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)?;
+        formatter.write_str(" in lower case")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        check_strict_hex_chars(value)?;
+        ConstHexVisitor::<N>::check_digit_count::<E>(value.len())?;
+        H::deserialize(StrDeserializer::<E>::new(value))
+    }
+}
+
+/// Adapter allowing to serialize a value via a [`ConstHex`] implementation.
+struct ConstHexSerialize<'a, T, H, const N: usize> {
+    value: &'a T,
+    _hex: PhantomData<H>,
+}
+
+impl<'a, T, H: ConstHex<T, N>, const N: usize> ConstHexSerialize<'a, T, H, N> {
+    fn new(value: &'a T) -> Self {
+        Self {
+            value,
+            _hex: PhantomData,
+        }
+    }
+}
+
+impl<T, H: ConstHex<T, N>, const N: usize> Serialize for ConstHexSerialize<'_, T, H, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(self.value, serializer)
+    }
+}
+
+/// Analogue of [`HexOption`](crate::HexOption) for [`ConstHex`] implementations.
+/// Does not require the `alloc` crate.
+///
+/// `None` is serialized as a missing value (e.g., `null` in JSON), and `Some(_)` is serialized
+/// in the same way as with `H`; binary formats see the value via [`Serializer::serialize_none()`] /
+/// [`Serializer::serialize_some()`]. The byte length `N` is inferred from the `ConstHex`
+/// implementation.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHexForm, ConstHexOption};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ConstHexOption::<ConstHexForm<_>>")]
+///     buffer: Option<[u8; 4]>,
+/// }
+///
+/// let test = Test { buffer: Some([0xde, 0xad, 0xbe, 0xef]) };
+/// assert_eq!(serde_json::to_string(&test)?, r#"{"buffer":"deadbeef"}"#);
+/// let test_copy: Test = serde_json::from_str(r#"{"buffer":null}"#)?;
+/// assert_eq!(test_copy.buffer, None);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct ConstHexOption<H>(PhantomData<H>);
+
+impl<H> ConstHexOption<H> {
+    /// Serializes an optional value.
+    pub fn serialize<T, S, const N: usize>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        H: ConstHex<T, N>,
+        S: Serializer,
+    {
+        match value {
+            Some(value) => serializer.serialize_some(&ConstHexSerialize::<T, H, N>::new(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes an optional value.
+    pub fn deserialize<'de, T, D, const N: usize>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        H: ConstHex<T, N>,
+        D: Deserializer<'de>,
+    {
+        struct OptionVisitor<T, H, const N: usize>(PhantomData<fn() -> (T, H)>);
+
+        impl<'de, T, H: ConstHex<T, N>, const N: usize> Visitor<'de> for OptionVisitor<T, H, N> {
+            type Value = Option<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "optional hex-encoded byte array of length {N}")
+            }
+
+            fn visit_none<E: DeError>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_unit<E: DeError>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                H::deserialize(deserializer).map(Some)
+            }
+        }
+
+        deserializer.deserialize_option(OptionVisitor::<T, H, N>(PhantomData))
+    }
+}
+
+/// Seed allowing to deserialize a sequence element via a [`ConstHex`] implementation.
+/// Errors are annotated with the element index.
+struct ConstHexElementSeed<T, H, const N: usize> {
+    index: usize,
+    _hex: PhantomData<fn() -> (T, H)>,
+}
+
+impl<T, H: ConstHex<T, N>, const N: usize> ConstHexElementSeed<T, H, N> {
+    fn new(index: usize) -> Self {
+        Self {
+            index,
+            _hex: PhantomData,
+        }
+    }
+}
+
+impl<'de, T, H: ConstHex<T, N>, const N: usize> serde::de::DeserializeSeed<'de>
+    for ConstHexElementSeed<T, H, N>
+{
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        H::deserialize(deserializer)
+            .map_err(|err| D::Error::custom(format_args!("element {}: {err}", self.index)))
+    }
+}
+
+/// Analogue of [`HexVec`](crate::HexVec) for [`ConstHex`] implementations.
+///
+/// The vector is serialized as a sequence, each element of which is serialized in the same way
+/// as with `H`; i.e., as a hex string of fixed length for human-readable formats, and as
+/// a byte array for binary ones. Unlike with `HexVec`, elements are encoded on the stack.
+/// On deserialization, errors are annotated with the index of the failing element.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHexForm, ConstHexVec};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ConstHexVec::<ConstHexForm<_>>")]
+///     arrays: Vec<[u8; 4]>,
+/// }
+///
+/// let test = Test { arrays: vec![[1, 2, 3, 4], [5, 6, 7, 8]] };
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"arrays":["01020304","05060708"]}"#);
+///
+/// let err = serde_json::from_str::<Test>(r#"{"arrays":["01020304","0506"]}"#).unwrap_err();
+/// assert!(err.to_string().starts_with("element 1: expected hex string of 8 characters"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "const_len", feature = "alloc"))))]
+#[derive(Debug)]
+pub struct ConstHexVec<H>(PhantomData<H>);
+
+#[cfg(feature = "alloc")]
+impl<H> ConstHexVec<H> {
+    /// Serializes a slice of values.
+    pub fn serialize<T, S, const N: usize>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        H: ConstHex<T, N>,
+        S: Serializer,
+    {
+        serializer.collect_seq(values.iter().map(ConstHexSerialize::<T, H, N>::new))
+    }
+
+    /// Deserializes a vector of values.
+    pub fn deserialize<'de, T, D, const N: usize>(
+        deserializer: D,
+    ) -> Result<alloc::vec::Vec<T>, D::Error>
+    where
+        H: ConstHex<T, N>,
+        D: Deserializer<'de>,
+    {
+        struct VecVisitor<T, H, const N: usize>(PhantomData<fn() -> (T, H)>);
+
+        impl<'de, T, H: ConstHex<T, N>, const N: usize> Visitor<'de> for VecVisitor<T, H, N> {
+            type Value = alloc::vec::Vec<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "sequence of hex-encoded byte arrays of length {N}"
+                )
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let capacity = crate::var_len::cautious_capacity::<T>(seq.size_hint());
+                let mut values = alloc::vec::Vec::with_capacity(capacity);
+                while let Some(value) =
+                    seq.next_element_seed(ConstHexElementSeed::<T, H, N>::new(values.len()))?
+                {
+                    values.push(value);
+                }
+                Ok(values)
+            }
+        }
+
+        deserializer.deserialize_seq(VecVisitor::<T, H, N>(PhantomData))
+    }
+}
+
+/// Analogue of [`HexPair`](crate::HexPair) for [`ConstHex`] implementations.
+///
+/// The pair is serialized as a 2-element tuple, each element of which is serialized in the same
+/// way as with the corresponding `ConstHex` implementation. Unlike `HexPair`, this container
+/// does not require the `alloc` crate.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHexForm, ConstHexPair};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ConstHexPair::<ConstHexForm<_>, ConstHexForm<_>>")]
+///     pair: ([u8; 2], [u8; 4]),
+/// }
+///
+/// let test = Test { pair: ([1, 2], [3, 4, 5, 6]) };
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"pair":["0102","03040506"]}"#);
+///
+/// let err = serde_json::from_str::<Test>(r#"{"pair":["0102"]}"#).unwrap_err();
+/// assert!(err.to_string().starts_with("invalid length 1, expected pair"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct ConstHexPair<H1, H2>(PhantomData<(H1, H2)>);
+
+impl<H1, H2> ConstHexPair<H1, H2> {
+    /// Serializes a pair of values.
+    pub fn serialize<T1, T2, S, const N1: usize, const N2: usize>(
+        value: &(T1, T2),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        H1: ConstHex<T1, N1>,
+        H2: ConstHex<T2, N2>,
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&ConstHexSerialize::<T1, H1, N1>::new(&value.0))?;
+        tuple.serialize_element(&ConstHexSerialize::<T2, H2, N2>::new(&value.1))?;
+        tuple.end()
+    }
+
+    /// Deserializes a pair of values.
+    pub fn deserialize<'de, T1, T2, D, const N1: usize, const N2: usize>(
+        deserializer: D,
+    ) -> Result<(T1, T2), D::Error>
+    where
+        H1: ConstHex<T1, N1>,
+        H2: ConstHex<T2, N2>,
+        D: Deserializer<'de>,
+    {
+        struct PairVisitor<T1, T2, H1, H2, const N1: usize, const N2: usize> {
+            _values: PhantomData<fn() -> (T1, T2)>,
+            _hex: PhantomData<fn() -> (H1, H2)>,
+        }
+
+        impl<'de, T1, T2, H1, H2, const N1: usize, const N2: usize> Visitor<'de>
+            for PairVisitor<T1, T2, H1, H2, N1, N2>
+        where
+            H1: ConstHex<T1, N1>,
+            H2: ConstHex<T2, N2>,
+        {
+            type Value = (T1, T2);
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "pair of hex-encoded byte arrays of lengths {N1} and {N2}"
+                )
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let first = seq
+                    .next_element_seed(ConstHexElementSeed::<T1, H1, N1>::new(0))?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let second = seq
+                    .next_element_seed(ConstHexElementSeed::<T2, H2, N2>::new(1))?
+                    .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+                Ok((first, second))
+            }
+        }
+
+        let visitor = PairVisitor::<T1, T2, H1, H2, N1, N2> {
+            _values: PhantomData,
+            _hex: PhantomData,
+        };
+        deserializer.deserialize_tuple(2, visitor)
+    }
+}
+
+/// Container for use inside `#[serde(with)]` attribute for vectors of values that serializes
+/// them as a single concatenated hex string (e.g., two 32-byte keys as a 128-char string),
+/// using the [`ConstHex`] implementation `H` for each element.
+///
+/// For binary formats, the element bytes are concatenated into a single byte array.
+/// The hex string is always lower-case and has no prefix. On deserialization, the byte length
+/// must be divisible by `N`; zero-length elements are not supported.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHexConcat, ConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ConstHexConcat::<ConstHexForm<_>>")]
+///     keys: Vec<[u8; 2]>,
+/// }
+///
+/// let test = Test { keys: vec![[1, 2], [3, 4]] };
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"keys":"01020304"}"#);
+/// let test_copy: Test = serde_json::from_str(&json)?;
+/// assert_eq!(test_copy, test);
+///
+/// let err = serde_json::from_str::<Test>(r#"{"keys":"010203"}"#).unwrap_err();
+/// assert!(err.to_string().starts_with("expected hex string with length divisible by 4"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "const_len", feature = "alloc"))))]
+#[derive(Debug)]
+pub struct ConstHexConcat<H>(PhantomData<H>);
+
+#[cfg(feature = "alloc")]
+impl<H> ConstHexConcat<H> {
+    /// Serializes a slice of values.
+    pub fn serialize<T, S, const N: usize>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        H: ConstHex<T, N>,
+        S: Serializer,
+    {
+        let mut bytes = alloc::vec::Vec::with_capacity(values.len() * N);
+        for value in values {
+            bytes.extend_from_slice(&H::create_bytes(value));
+        }
+        crate::var_len::serialize_bytes(&bytes, serializer, false, "")
+    }
+
+    /// Deserializes a vector of values.
+    pub fn deserialize<'de, T, D, const N: usize>(
+        deserializer: D,
+    ) -> Result<alloc::vec::Vec<T>, D::Error>
+    where
+        H: ConstHex<T, N>,
+        D: Deserializer<'de>,
+    {
+        let is_human_readable = deserializer.is_human_readable();
+        let bytes = crate::var_len::deserialize_bytes(deserializer, crate::HexVisitor::new())?;
+        if N == 0 {
+            return Err(D::Error::custom("zero-length elements are not supported"));
+        }
+        if bytes.len() % N != 0 {
+            return Err(if is_human_readable {
+                D::Error::custom(format_args!(
+                    "expected hex string with length divisible by {}, got {} characters",
+                    2 * N,
+                    2 * bytes.len()
+                ))
+            } else {
+                D::Error::custom(format_args!(
+                    "expected byte array with length divisible by {N}, got {} bytes",
+                    bytes.len()
+                ))
+            });
+        }
+
+        bytes
+            .chunks_exact(N)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let mut array = [0_u8; N];
+                array.copy_from_slice(chunk);
+                from_validated_array::<T, H, N>(array)
+                    .map_err(|err| D::Error::custom(format_args!("element {i}: {err}")))
+            })
+            .collect()
+    }
+}
+
+/// Container for use inside `#[serde(with)]` attribute for pairs of values that serializes
+/// them as a single concatenated hex string (e.g., a 16-byte nonce followed by a 32-byte key
+/// as a 96-char string), using the [`ConstHex`] implementations `H1` and `H2` for the elements.
+///
+/// For binary formats, the element bytes are concatenated into a single byte array.
+/// The hex string is always lower-case and has no prefix. On deserialization, the total
+/// byte length is checked before splitting the bytes between the elements.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHexConcatPair, ConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Sealed {
+///     #[serde(with = "ConstHexConcatPair::<ConstHexForm<_>, ConstHexForm<_>>")]
+///     nonce_and_key: ([u8; 2], [u8; 3]),
+/// }
+///
+/// let sealed = Sealed { nonce_and_key: ([1, 2], [3, 4, 5]) };
+/// let json = serde_json::to_string(&sealed)?;
+/// assert_eq!(json, r#"{"nonce_and_key":"0102030405"}"#);
+/// let sealed_copy: Sealed = serde_json::from_str(&json)?;
+/// assert_eq!(sealed_copy, sealed);
+///
+/// let err = serde_json::from_str::<Sealed>(r#"{"nonce_and_key":"01020304"}"#).unwrap_err();
+/// assert!(err.to_string().starts_with("expected hex string of 10 characters"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "const_len", feature = "alloc"))))]
+#[derive(Debug)]
+pub struct ConstHexConcatPair<H1, H2>(PhantomData<(H1, H2)>);
+
+#[cfg(feature = "alloc")]
+impl<H1, H2> ConstHexConcatPair<H1, H2> {
+    /// Serializes a pair of values.
+    pub fn serialize<T1, T2, S, const N1: usize, const N2: usize>(
+        value: &(T1, T2),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        H1: ConstHex<T1, N1>,
+        H2: ConstHex<T2, N2>,
+        S: Serializer,
+    {
+        let mut bytes = alloc::vec::Vec::with_capacity(N1 + N2);
+        bytes.extend_from_slice(&H1::create_bytes(&value.0));
+        bytes.extend_from_slice(&H2::create_bytes(&value.1));
+        crate::var_len::serialize_bytes(&bytes, serializer, false, "")
+    }
+
+    /// Deserializes a pair of values.
+    pub fn deserialize<'de, T1, T2, D, const N1: usize, const N2: usize>(
+        deserializer: D,
+    ) -> Result<(T1, T2), D::Error>
+    where
+        H1: ConstHex<T1, N1>,
+        H2: ConstHex<T2, N2>,
+        D: Deserializer<'de>,
+    {
+        let is_human_readable = deserializer.is_human_readable();
+        let bytes = crate::var_len::deserialize_bytes(deserializer, crate::HexVisitor::new())?;
+        if bytes.len() != N1 + N2 {
+            return Err(if is_human_readable {
+                D::Error::custom(HexDecodeError::WrongLength {
+                    expected: 2 * (N1 + N2),
+                    got: 2 * bytes.len(),
+                })
+            } else {
+                D::Error::custom(format_args!(
+                    "expected byte array of {} bytes, got {} bytes",
+                    N1 + N2,
+                    bytes.len()
+                ))
+            });
+        }
+
+        let (first_bytes, second_bytes) = bytes.split_at(N1);
+        let mut first_array = [0_u8; N1];
+        first_array.copy_from_slice(first_bytes);
+        let mut second_array = [0_u8; N2];
+        second_array.copy_from_slice(second_bytes);
+
+        let first = from_validated_array::<T1, H1, N1>(first_array)
+            .map_err(|err| D::Error::custom(format_args!("element 0: {err}")))?;
+        let second = from_validated_array::<T2, H2, N2>(second_array)
+            .map_err(|err| D::Error::custom(format_args!("element 1: {err}")))?;
+        Ok((first, second))
+    }
+}
+
+/// Wrapper around a value that (de)serializes it using a [`ConstHex`] implementation.
+///
+/// This is an analogue of [`HexBuf`](crate::HexBuf) for `ConstHex`. Unlike `HexBuf`,
+/// serialization does not allocate, and the byte length is checked on deserialization before
+/// calling [`ConstHex::from_bytes()`].
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHexBuf, ConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// type HexArray = ConstHexBuf<[u8; 4], ConstHexForm<[u8; 4]>, 4>;
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     arrays: Vec<HexArray>,
+/// }
+///
+/// let test = Test {
+///     arrays: vec![[0xde, 0xad, 0xbe, 0xef].into()],
+/// };
+/// assert_eq!(test.arrays[0][0], 0xde); // uses `Deref`
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"arrays":["deadbeef"]}"#);
+/// let test_copy: Test = serde_json::from_str(&json)?;
+/// assert_eq!(test_copy, test);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+pub struct ConstHexBuf<T, H, const N: usize> {
+    value: T,
+    _hex: PhantomData<fn() -> H>,
+}
+
+impl<T, H, const N: usize> ConstHexBuf<T, H, N> {
+    /// Wraps the provided value.
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            _hex: PhantomData,
+        }
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: fmt::Debug, H, const N: usize> fmt::Debug for ConstHexBuf<T, H, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("ConstHexBuf")
+            .field(&self.value)
+            .finish()
+    }
+}
+
+/// Formats the value as a lower-case hex string. Does not allocate.
+impl<T, H: ConstHex<T, N>, const N: usize> fmt::Display for ConstHexBuf<T, H, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, formatter)
+    }
+}
+
+impl<T, H: ConstHex<T, N>, const N: usize> fmt::LowerHex for ConstHexBuf<T, H, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0_u16; N];
+        formatter.pad(encode_hex_str(
+            &H::create_bytes(&self.value),
+            &mut buffer,
+            false,
+        ))
+    }
+}
+
+impl<T, H: ConstHex<T, N>, const N: usize> fmt::UpperHex for ConstHexBuf<T, H, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0_u16; N];
+        formatter.pad(encode_hex_str(
+            &H::create_bytes(&self.value),
+            &mut buffer,
+            true,
+        ))
+    }
+}
+
+/// Parses the value from a hex string. Both lower-case and upper-case hex digits are accepted;
+/// prefixes and whitespace are not, regardless of `H`.
+impl<T, H: ConstHex<T, N>, const N: usize> FromStr for ConstHexBuf<T, H, N> {
+    type Err = ParseHexError<H::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let wrong_length = HexDecodeError::WrongLength {
+            expected: 2 * N,
+            got: s.len(),
+        };
+        if s.len() % 2 != 0 {
+            return Err(ParseHexError::Hex(HexDecodeError::odd_length(s.len())));
+        } else if s.len() != 2 * N {
+            return Err(ParseHexError::Hex(wrong_length));
+        }
+
+        let mut bytes = [0_u8; N];
+        backend::decode_to_slice(s, &mut bytes).map_err(|err| {
+            // Since the length is checked above, the only possible error is an invalid char.
+            ParseHexError::Hex(HexDecodeError::from_hex_error(err, s, 0).unwrap_or(wrong_length))
+        })?;
+        from_validated_array::<T, H, N>(bytes)
+            .map(Self::new)
+            .map_err(ParseHexError::Conversion)
+    }
+}
+
+impl<T: Clone, H, const N: usize> Clone for ConstHexBuf<T, H, N> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: PartialEq, H, const N: usize> PartialEq for ConstHexBuf<T, H, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, H, const N: usize> Eq for ConstHexBuf<T, H, N> {}
+
+impl<T: Copy, H, const N: usize> Copy for ConstHexBuf<T, H, N> {}
+
+impl<T: Default, H, const N: usize> Default for ConstHexBuf<T, H, N> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Hash, H, const N: usize> Hash for ConstHexBuf<T, H, N> {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        self.value.hash(state);
+    }
+}
+
+impl<T, H, const N: usize> From<T> for ConstHexBuf<T, H, N> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, H, const N: usize> Deref for ConstHexBuf<T, H, N> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, H, const N: usize> DerefMut for ConstHexBuf<T, H, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T, H: ConstHex<T, N>, const N: usize> Serialize for ConstHexBuf<T, H, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(&self.value, serializer)
+    }
+}
+
+impl<'de, T, H: ConstHex<T, N>, const N: usize> Deserialize<'de> for ConstHexBuf<T, H, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        H::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Borrowed byte array that is serialized as a hex string for [human-readable][hr] serializers,
+/// and as a byte array otherwise.
+///
+/// This is a serialize-only analogue of [`HexBytes`](crate::HexBytes) for arrays
+/// with length known in compile time. The hex string is encoded on the stack, so serialization
+/// does not allocate. The wrapper implements [`Display`](fmt::Display) and [`Debug`]
+/// in the same way.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::ConstHexBytes;
+/// # use serde_derive::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Message<'a> {
+///     hash: ConstHexBytes<'a, 4>,
+/// }
+///
+/// let hash = [0xde, 0xad, 0xbe, 0xef];
+/// let message = Message { hash: ConstHexBytes(&hash) };
+/// assert_eq!(serde_json::to_string(&message)?, r#"{"hash":"deadbeef"}"#);
+/// assert_eq!(format!("{:>10}", message.hash), "  deadbeef");
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstHexBytes<'a, const N: usize>(pub &'a [u8; N]);
+
+impl<'a, const N: usize> ConstHexBytes<'a, N> {
+    /// Wraps the provided byte array.
+    pub const fn new(bytes: &'a [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [u8; N]> for ConstHexBytes<'a, N> {
+    fn from(bytes: &'a [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for ConstHexBytes<'_, N> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl<const N: usize> fmt::Display for ConstHexBytes<'_, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0_u16; N];
+        formatter.pad(encode_hex_str(self.0, &mut buffer, false))
+    }
+}
+
+impl<const N: usize> fmt::Debug for ConstHexBytes<'_, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "ConstHexBytes({self})")
+    }
+}
+
+impl<const N: usize> Serialize for ConstHexBytes<'_, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serialize_hex_str_on_stack(*self.0, serializer, false, "")
+        } else {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+}
+
+/// Writer of hex-encoded bytes into a stack buffer with capacity for `N` bytes.
+///
+/// This is a no-alloc analogue of [`HexEncoder`](crate::HexEncoder). Writes exceeding
+/// the capacity fail without modifying the buffer. The writer also implements
+/// [`fmt::Write`], hex-encoding the UTF-8 bytes of the written strings.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::ConstHexWriter;
+///
+/// let mut writer = ConstHexWriter::<4>::new();
+/// writer.write_byte(0xde)?;
+/// writer.write_bytes(&[0xad, 0xbe])?;
+/// assert_eq!(writer.as_str(), "deadbe");
+/// assert_eq!(writer.len(), 3);
+/// // The buffer has space for a single byte only.
+/// assert!(writer.write_bytes(&[0xef, 0xff]).is_err());
+/// assert_eq!(writer.as_str(), "deadbe");
+/// # Ok::<_, core::fmt::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Clone)]
+pub struct ConstHexWriter<const N: usize> {
+    // Each `u16` stores 2 hex digits.
+    buffer: [u16; N],
+    len: usize,
+}
+
+impl<const N: usize> Default for ConstHexWriter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> ConstHexWriter<N> {
+    /// Creates an empty writer.
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether no bytes were written so far.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Writes a single byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is full.
+    pub fn write_byte(&mut self, byte: u8) -> fmt::Result {
+        self.write_bytes(&[byte])
+    }
+
+    /// Writes bytes. Either all bytes are written, or none of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer does not have enough space for `bytes`.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> fmt::Result {
+        let new_len = self.len + bytes.len();
+        if new_len > N {
+            return Err(fmt::Error);
+        }
+        let hex_slice = &mut as_u8_slice(&mut self.buffer)[2 * self.len..2 * new_len];
+        // The length is correct by construction, so this never fails.
+        backend::encode_to_slice(bytes, hex_slice).map_err(|_| fmt::Error)?;
+        self.len = new_len;
+        Ok(())
+    }
+
+    /// Returns the hex string written so far.
+    pub fn as_str(&self) -> &str {
+        let hex_slice = &as_u8_slice_ref(&self.buffer)[..2 * self.len];
+        unsafe {
+            // SAFETY: the written part of the buffer consists of hex digits.
+            str::from_utf8_unchecked(hex_slice)
+        }
+    }
+}
+
+impl<const N: usize> fmt::Write for ConstHexWriter<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_bytes(s.as_bytes())
+    }
+}
+
+impl<const N: usize> fmt::Display for ConstHexWriter<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for ConstHexWriter<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ConstHexWriter")
+            .field("hex", &self.as_str())
+            .finish()
+    }
+}
+
+/// Adapter implementing [`Hex`](crate::Hex) for a [`ConstHex`] implementation.
+///
+/// This allows to use `ConstHex` implementations in contexts requiring `Hex`,
+/// e.g., in [`HexVec`](crate::HexVec) or [`ForceHexStr`](crate::ForceHexStr).
+/// (A blanket `Hex` implementation for all `ConstHex` implementations is impossible
+/// since the byte length `N` would not be constrained by it.)
+///
+/// Serialization and deserialization delegate to `ConstHex`, so they produce
+/// the same results and do not allocate.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex, ConstHexBridge, HexVec};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// pub struct Key([u8; 4]);
+///
+/// struct KeyHex(());
+///
+/// impl ConstHex<Key, 4> for KeyHex {
+///     // snip
+/// #   type Error = core::convert::Infallible;
+/// #   fn create_bytes(key: &Key) -> [u8; 4] {
+/// #       key.0
+/// #   }
+/// #   fn from_bytes(bytes: [u8; 4]) -> Result<Key, Self::Error> {
+/// #       Ok(Key(bytes))
+/// #   }
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "HexVec::<ConstHexBridge<KeyHex, 4>>")]
+///     keys: Vec<Key>,
+/// }
+///
+/// let test = Test { keys: vec![Key([1, 2, 3, 4])] };
+/// assert_eq!(serde_json::to_string(&test)?, r#"{"keys":["01020304"]}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "const_len", feature = "alloc"))))]
+#[derive(Debug)]
+pub struct ConstHexBridge<H, const N: usize>(PhantomData<H>);
+
+#[cfg(feature = "alloc")]
+impl<T, H: ConstHex<T, N>, const N: usize> crate::Hex<T> for ConstHexBridge<H, N> {
+    type Error = ConstHexBridgeError<H::Error>;
+
+    fn create_bytes(value: &T) -> alloc::borrow::Cow<'_, [u8]> {
+        alloc::borrow::Cow::Owned(H::create_bytes(value).to_vec())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        let bytes = ConstHexBridgeError::check_len(bytes)?;
+        H::from_bytes(bytes).map_err(ConstHexBridgeError::Conversion)
+    }
+
+    fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+        let bytes = ConstHexBridgeError::check_len(bytes)?;
+        H::validate_bytes(&bytes).map_err(ConstHexBridgeError::Conversion)
+    }
+
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(value, serializer)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        H::deserialize(deserializer)
+    }
+}
+
+/// Error converting bytes to a value in [`ConstHexBridge`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "const_len", feature = "alloc"))))]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConstHexBridgeError<E> {
+    /// Byte slice has an unexpected length.
+    InvalidLength {
+        /// Expected byte length.
+        expected: usize,
+        /// Actual byte length.
+        actual: usize,
+    },
+    /// Error converting the byte array into a value.
+    Conversion(E),
+}
+
+#[cfg(feature = "alloc")]
+impl<E> ConstHexBridgeError<E> {
+    fn check_len<const N: usize>(bytes: &[u8]) -> Result<[u8; N], Self> {
+        <[u8; N]>::try_from(bytes).map_err(|_| Self::InvalidLength {
+            expected: N,
+            actual: bytes.len(),
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: fmt::Display> fmt::Display for ConstHexBridgeError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, actual } => write!(
+                formatter,
+                "invalid length {actual}, expected byte array of length {expected}"
+            ),
+            Self::Conversion(err) => fmt::Display::fmt(err, formatter),
+        }
+    }
+}
+
+/// Free functions (de)serializing byte arrays as lower-case hex strings for use
+/// with `#[serde(with)]`, analogous to [`hex_bytes`](mod@crate::hex_bytes).
+///
+/// The functions delegate to [`ConstHexForm`], but do not require importing the [`ConstHex`]
+/// trait. Since the array length is inferred from the field type, no type annotations
+/// are necessary. See [`upper_const_hex_bytes`] for the upper-case variant.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_derive::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(with = "hex_buffer_serde::const_hex_bytes")]
+///     nonce: [u8; 4],
+/// }
+///
+/// let message = Message { nonce: [1, 2, 3, 4] };
+/// let json = serde_json::to_string(&message)?;
+/// assert_eq!(json, r#"{"nonce":"01020304"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+pub mod const_hex_bytes {
+    use serde::{Deserializer, Serializer};
+
+    use super::{ConstHex, ConstHexForm};
+
+    /// Serializes `bytes` as a lower-case hex string for human-readable serializers,
+    /// and as a byte array otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors returned by the serializer.
+    pub fn serialize<const N: usize, S: Serializer>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ConstHexForm::<[u8; N]>::serialize(bytes, serializer)
+    }
+
+    /// Deserializes a byte array from a hex string for human-readable deserializers,
+    /// and from a byte array otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a valid hex string / byte array,
+    /// or has an unexpected length.
+    pub fn deserialize<'de, const N: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        ConstHexForm::<[u8; N]>::deserialize(deserializer)
+    }
+}
+
+/// Analogue of [`const_hex_bytes`] that serializes byte arrays as upper-case hex strings.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_derive::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(with = "hex_buffer_serde::upper_const_hex_bytes")]
+///     nonce: [u8; 4],
+/// }
+///
+/// let message = Message { nonce: [0xc0, 0xff, 0xee, 0x01] };
+/// let json = serde_json::to_string(&message)?;
+/// assert_eq!(json, r#"{"nonce":"C0FFEE01"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+pub mod upper_const_hex_bytes {
+    use serde::{Deserializer, Serializer};
+
+    use super::{ConstHex, UpperConstHexForm};
+
+    /// Serializes `bytes` as an upper-case hex string for human-readable serializers,
+    /// and as a byte array otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors returned by the serializer.
+    pub fn serialize<const N: usize, S: Serializer>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        UpperConstHexForm::<[u8; N]>::serialize(bytes, serializer)
+    }
+
+    /// Deserializes a byte array in the same way as
+    /// [`const_hex_bytes::deserialize()`](super::const_hex_bytes::deserialize()).
+    /// Hex strings are case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a valid hex string / byte array,
+    /// or has an unexpected length.
+    pub fn deserialize<'de, const N: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        UpperConstHexForm::<[u8; N]>::deserialize(deserializer)
+    }
+}
+
+/// Analogue of [`serialize_hex()`](crate::serialize_hex()) for [`ConstHex`] implementations.
+///
+/// Since const generic arguments cannot be inferred when using turbofish syntax, the byte length
+/// `N` needs to be specified explicitly.
+///
+/// # Errors
+///
+/// Propagates errors returned by the serializer.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::ConstHexForm;
+/// # use serde_derive::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Message {
+///     #[serde(
+///         serialize_with = "hex_buffer_serde::serialize_const_hex::<_, ConstHexForm<_>, _, 4>"
+///     )]
+///     nonce: [u8; 4],
+/// }
+///
+/// let message = Message { nonce: [0xc0, 0xff, 0xee, 0x01] };
+/// let json = serde_json::to_string(&message)?;
+/// assert_eq!(json, r#"{"nonce":"c0ffee01"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+pub fn serialize_const_hex<T, H, S, const N: usize>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    H: ConstHex<T, N> + ?Sized,
+    S: Serializer,
+{
+    H::serialize(value, serializer)
+}
+
+/// Analogue of [`deserialize_hex()`](crate::deserialize_hex()) for [`ConstHex`] implementations.
+///
+/// Since const generic arguments cannot be inferred when using turbofish syntax, the byte length
+/// `N` needs to be specified explicitly.
+///
+/// # Errors
+///
+/// Returns an error if the input is not a valid hex string / byte array of the expected length,
+/// or if it cannot be converted to `T`.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::ConstHexForm;
+/// # use serde_derive::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Message {
+///     #[serde(
+///         deserialize_with = "hex_buffer_serde::deserialize_const_hex::<_, ConstHexForm<_>, _, 4>"
+///     )]
+///     nonce: [u8; 4],
+/// }
+///
+/// let message: Message = serde_json::from_str(r#"{"nonce":"c0ffee01"}"#)?;
+/// assert_eq!(message.nonce, [0xc0, 0xff, 0xee, 0x01]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+pub fn deserialize_const_hex<'de, T, H, D, const N: usize>(deserializer: D) -> Result<T, D::Error>
+where
+    H: ConstHex<T, N> + ?Sized,
+    D: Deserializer<'de>,
+{
+    H::deserialize(deserializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{format, string::ToString};
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Arrays {
+        #[serde(with = "ConstHexForm")]
+        array: [u8; 16],
+        #[serde(with = "ConstHexForm")]
+        longer_array: [u8; 32],
+    }
+
+    #[test]
+    fn serializing_arrays() {
+        let arrays = Arrays {
+            array: [11; 16],
+            longer_array: [240; 32],
+        };
+        let json = serde_json::to_string(&arrays).unwrap();
+        assert!(json.contains(&"0b".repeat(16)));
+
+        let arrays_copy: Arrays = serde_json::from_str(&json).unwrap();
+        assert_eq!(arrays_copy, arrays);
+    }
+
+    #[test]
+    fn default_values() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct DefaultTest {
+            #[serde(with = "ConstHexForm", default)]
+            array: [u8; 16],
+            tag: u8,
+        }
+
+        let value: DefaultTest = serde_json::from_str(r#"{ "tag": 1 }"#).unwrap();
+        assert_eq!(
+            value,
+            DefaultTest {
+                array: [0; 16],
+                tag: 1
+            }
+        );
+
+        let json = format!(r#"{{ "array": "{}", "tag": 1 }}"#, "0b".repeat(16));
+        let value: DefaultTest = serde_json::from_str(&json).unwrap();
+        assert_eq!(value.array, [11; 16]);
+    }
+
+    #[test]
+    fn deserializing_array_with_incorrect_length() {
+        let json = serde_json::json!({
+            "array": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "longer_array": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+        });
+        let err = serde_json::from_value::<Arrays>(json)
+            .unwrap_err()
+            .to_string();
+
+        assert!(
+            err.contains("expected hex string of 64 characters (for 32 bytes), got 32 characters"),
+            "{}",
+            err
+        );
+
+        // Too long string.
+        let json = serde_json::json!({
+            "array": "0b".repeat(17),
+            "longer_array": "0b".repeat(32),
+        });
+        let err = serde_json::from_value::<Arrays>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("expected hex string of 32 characters (for 16 bytes), got 34 characters"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn deserializing_array_with_incorrect_length_from_binary_format() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct ArrayHolder<const N: usize>(#[serde(with = "ConstHexForm")] [u8; N]);
+
+        let buffer = bincode::serialize(&ArrayHolder([5; 6])).unwrap();
+        let err = bincode::deserialize::<ArrayHolder<4>>(&buffer).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid length 6, expected byte array of length 4"
+        );
+    }
+
+    #[test]
+    fn always_hex_and_binary_flags() {
+        struct ModeHex<const HEX: bool, const BINARY: bool>(());
+
+        impl<const HEX: bool, const BINARY: bool> ConstHex<[u8; 3], 3> for ModeHex<HEX, BINARY> {
+            type Error = core::convert::Infallible;
+            const ALWAYS_HEX: bool = HEX;
+            const ALWAYS_BINARY: bool = BINARY;
+
+            fn create_bytes(value: &[u8; 3]) -> [u8; 3] {
+                *value
+            }
+
+            fn from_bytes(bytes: [u8; 3]) -> Result<[u8; 3], Self::Error> {
+                Ok(bytes)
+            }
+        }
+
+        fn assert_roundtrip<H: ConstHex<[u8; 3], 3>>(
+            expected_json: &serde_json::Value,
+            expected_bincode: &[u8],
+        ) {
+            let value = [0xc0, 0xff, 0xee];
+            let json = H::serialize(&value, serde_json::value::Serializer).unwrap();
+            assert_eq!(json, *expected_json);
+            assert_eq!(H::deserialize(json).unwrap(), value);
+
+            let mut bytes = alloc::vec![];
+            let mut serializer = bincode::Serializer::new(&mut bytes, bincode::options());
+            H::serialize(&value, &mut serializer).unwrap();
+            assert_eq!(bytes, expected_bincode);
+            let mut deserializer = bincode::Deserializer::from_slice(&bytes, bincode::options());
+            assert_eq!(H::deserialize(&mut deserializer).unwrap(), value);
+        }
+
+        let hex_json = serde_json::json!("c0ffee");
+        let hex_bincode = [6, b'c', b'0', b'f', b'f', b'e', b'e'];
+        let binary_json = serde_json::json!([0xc0, 0xff, 0xee]);
+        let binary_bincode = [3, 0xc0, 0xff, 0xee];
+
+        assert_roundtrip::<ModeHex<false, false>>(&hex_json, &binary_bincode);
+        assert_roundtrip::<ModeHex<true, false>>(&hex_json, &hex_bincode);
+        assert_roundtrip::<ModeHex<false, true>>(&binary_json, &binary_bincode);
+        // `ALWAYS_HEX` takes precedence.
+        assert_roundtrip::<ModeHex<true, true>>(&hex_json, &hex_bincode);
+    }
+
+    #[test]
+    fn custom_type_name() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "PublicKeyHex")]
+            key: [u8; 32],
+            #[serde(with = "ConstHexForm")]
+            array: [u8; 4],
+        }
+
+        struct PublicKeyHex(());
+
+        impl ConstHex<[u8; 32], 32> for PublicKeyHex {
+            type Error = core::convert::Infallible;
+            const TYPE_NAME: &'static str = "Ed25519PublicKey";
+
+            fn create_bytes(value: &[u8; 32]) -> [u8; 32] {
+                *value
+            }
+
+            fn from_bytes(bytes: [u8; 32]) -> Result<[u8; 32], Self::Error> {
+                Ok(bytes)
+            }
+        }
+
+        let json = serde_json::json!({ "key": 1, "array": "01020304" });
+        let err = serde_json::from_value::<Test>(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid type: integer `1`, expected 32-byte hex-encoded Ed25519PublicKey"
+        );
+
+        let json = serde_json::json!({ "key": "00".repeat(32), "array": 1 });
+        let err = serde_json::from_value::<Test>(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid type: integer `1`, expected hex-encoded byte array of length 4"
+        );
+    }
+
+    #[test]
+    fn form_trait_impls() {
+        fn clone_via_trait<T: Clone>(value: &T) -> T {
+            value.clone()
+        }
+
+        let form = ConstHexForm::<[u8; 32]>::default();
+        let form_copy = form;
+        assert_eq!(form, form_copy);
+        assert_eq!(clone_via_trait(&form), form);
+
+        let buf = ConstHexBuf::<[u8; 4], ConstHexForm<[u8; 4]>, 4>::default();
+        assert_eq!(*buf, [0; 4]);
+        let buf_copy = buf;
+        assert_eq!(buf_copy, buf);
+    }
+
+    #[test]
+    fn custom_expecting() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "ForceConstHexStr::<PublicKeyHex>")]
+            key: [u8; 32],
+        }
+
+        struct PublicKeyHex(());
+
+        impl ConstHex<[u8; 32], 32> for PublicKeyHex {
+            type Error = core::convert::Infallible;
+
+            fn create_bytes(value: &[u8; 32]) -> [u8; 32] {
+                *value
+            }
+
+            fn from_bytes(bytes: [u8; 32]) -> Result<[u8; 32], Self::Error> {
+                Ok(bytes)
+            }
+
+            fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("32-byte Ed25519 public key in hex encoding")
+            }
+        }
+
+        let json = serde_json::json!({ "key": 1 });
+        let err = serde_json::from_value::<Test>(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid type: integer `1`, expected 32-byte Ed25519 public key in hex encoding"
+        );
+    }
+
+    #[test]
+    fn custom_type() {
+        use ed25519_compact::PublicKey;
+
+        struct PublicKeyHex(());
+        impl ConstHex<PublicKey, 32> for PublicKeyHex {
+            type Error = ed25519_compact::Error;
+
+            fn create_bytes(pk: &PublicKey) -> [u8; 32] {
+                **pk
+            }
+
+            fn from_bytes(bytes: [u8; 32]) -> Result<PublicKey, Self::Error> {
+                PublicKey::from_slice(&bytes)
+            }
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Holder {
+            #[serde(with = "PublicKeyHex")]
+            public_key: PublicKey,
+        }
+
+        let json = serde_json::json!({
+            "public_key": "06fac1f22240cffd637ead6647188429fafda9c9cb7eae43386ac17f61115075",
+        });
+        let holder: Holder = serde_json::from_value(json).unwrap();
+        assert_eq!(holder.public_key[0], 6);
+
+        let bogus_json = serde_json::json!({
+            "public_key": "06fac1f22240cffd637ead6647188429fafda9c9cb7eae43386ac17f6111507",
+        });
+        let err = serde_json::from_value::<Holder>(bogus_json).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("hex string has odd length 63; expected even number of characters"));
+
+        let bogus_json = serde_json::json!({
+            "public_key": "06fac1f22240cffd637ead6647188429fafda9c9cb7eae43386ac17f611150",
+        });
+        let err = serde_json::from_value::<Holder>(bogus_json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected hex string of 64 characters (for 32 bytes), got 62 characters"));
+    }
+
+    #[test]
+    fn validating_bytes() {
+        /// Rejects all-zero arrays.
+        struct NonZeroHex(());
+
+        impl ConstHex<[u8; 4], 4> for NonZeroHex {
+            type Error = &'static str;
+
+            fn create_bytes(buffer: &[u8; 4]) -> [u8; 4] {
+                *buffer
+            }
+
+            fn from_bytes(bytes: [u8; 4]) -> Result<[u8; 4], Self::Error> {
+                Ok(bytes)
+            }
+
+            fn validate_bytes(bytes: &[u8; 4]) -> Result<(), Self::Error> {
+                if *bytes == [0; 4] {
+                    Err("array is all zeros")
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ValidatedTest {
+            #[serde(with = "NonZeroHex")]
+            array: [u8; 4],
+            #[serde(with = "ConstHexOption::<ForceConstBinary<NonZeroHex>>")]
+            optional_array: Option<[u8; 4]>,
+        }
+
+        let value = ValidatedTest {
+            array: [0, 0, 0, 1],
+            optional_array: Some([2; 4]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        let value_copy: ValidatedTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_jsons = [
+            serde_json::json!({ "array": "00000000", "optional_array": null }),
+            serde_json::json!({ "array": "00000001", "optional_array": [0, 0, 0, 0] }),
+        ];
+        for bogus_json in bogus_jsons {
+            let err = serde_json::from_value::<ValidatedTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert_eq!(err, "array is all zeros");
+        }
+
+        let bogus_value = ValidatedTest {
+            array: [0; 4],
+            optional_array: None,
+        };
+        let bytes = bincode::serialize(&bogus_value).unwrap();
+        let err = bincode::deserialize::<ValidatedTest>(&bytes).unwrap_err();
+        assert_eq!(err.to_string(), "array is all zeros");
+    }
+
+    #[test]
+    fn const_hex_buf_wrapper() {
+        type HexArray = ConstHexBuf<[u8; 4], UpperConstHexForm<[u8; 4]>, 4>;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct HexBufTest {
+            array: HexArray,
+            optional_array: Option<ConstHexBuf<[u8; 2], ConstHexForm<[u8; 2]>, 2>>,
+        }
+
+        let mut value = HexBufTest {
+            array: HexArray::new([0xab; 4]),
+            optional_array: Some([0xc0, 0xff].into()),
+        };
+        value.array[0] = 0xcd; // uses `DerefMut`
+
+        let json = serde_json::to_value(&value).unwrap();
+        let expected_json = serde_json::json!({ "array": "CDABABAB", "optional_array": "c0ff" });
+        assert_eq!(json, expected_json);
+        let value_copy: HexBufTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: HexBufTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+        assert_eq!(value_copy.array.into_inner(), [0xcd, 0xab, 0xab, 0xab]);
+
+        let err = serde_json::from_value::<HexArray>(serde_json::json!("abab")).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected hex string of 8 characters (for 4 bytes), got 4 characters"));
+    }
+
+    #[test]
+    fn formatting_const_hex_buf() {
+        let array = ConstHexBuf::<_, ConstHexForm<[u8; 4]>, 4>::new([0xde, 0xad, 0xbe, 0xef]);
+        let expected = "deadbeef";
+        assert_eq!(array.to_string(), expected);
+        assert_eq!(format!("{array:x}"), expected);
+        assert_eq!(format!("{array:X}"), "DEADBEEF");
+        assert_eq!(format!("{array:<10}|"), format!("{expected}  |"));
+
+        let empty = ConstHexBuf::<_, ConstHexForm<[u8; 0]>, 0>::new([]);
+        assert_eq!(empty.to_string(), "");
+    }
+
+    #[test]
+    fn zero_length_arrays() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Empty(#[serde(with = "ConstHexForm")] [u8; 0]);
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct LenientEmpty(#[serde(with = "LenientConstHexForm")] [u8; 0]);
+
+        let json = serde_json::to_value(Empty([])).unwrap();
+        assert_eq!(json, serde_json::json!(""));
+        let empty: Empty = serde_json::from_value(json).unwrap();
+        assert_eq!(empty, Empty([]));
+        let empty: LenientEmpty = serde_json::from_str(r#"" \t ""#).unwrap();
+        assert_eq!(empty, LenientEmpty([]));
+
+        let err = serde_json::from_str::<Empty>(r#""00""#).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected hex string of 0 characters (for 0 bytes), got 2 characters"),
+            "{err}"
+        );
+
+        // `bincode` encodes an empty byte sequence as its zero length.
+        let bytes = bincode::serialize(&Empty([])).unwrap();
+        assert_eq!(bytes, [0; 8]);
+        let empty: Empty = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(empty, Empty([]));
+        let bytes = bincode::serialize(&[0_u8] as &[u8]).unwrap();
+        assert!(bincode::deserialize::<Empty>(&bytes).is_err());
+
+        let buf: ConstHexBuf<[u8; 0], ConstHexForm<[u8; 0]>, 0> = "".parse().unwrap();
+        assert!(buf.is_empty());
+        assert_eq!(buf.to_string(), "");
+        assert_eq!(crate::codec::encode_const(&[]).as_str(), "");
+    }
+
+    #[test]
+    fn parsing_const_hex_buf() {
+        type HexArray = ConstHexBuf<[u8; 4], ConstHexForm<[u8; 4]>, 4>;
+
+        /// Form rejecting all-zero arrays in `validate_bytes()` only.
+        struct NonZeroConstHex(());
+
+        impl ConstHex<[u8; 4], 4> for NonZeroConstHex {
+            type Error = &'static str;
+
+            fn create_bytes(value: &[u8; 4]) -> [u8; 4] {
+                *value
+            }
+
+            fn from_bytes(bytes: [u8; 4]) -> Result<[u8; 4], Self::Error> {
+                Ok(bytes)
+            }
+
+            fn validate_bytes(bytes: &[u8; 4]) -> Result<(), Self::Error> {
+                if *bytes == [0; 4] {
+                    Err("all-zero array")
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        let array: HexArray = "deADbeef".parse().unwrap();
+        assert_eq!(*array, [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(array.to_string(), "deadbeef");
+
+        let err = "deadbeeg".parse::<HexArray>().unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ParseHexError::Hex(HexDecodeError::InvalidChar {
+                    position: 7,
+                    byte: b'g'
+                })
+            ),
+            "{err:?}"
+        );
+        let err = "deadbee".parse::<HexArray>().unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ParseHexError::Hex(HexDecodeError::OddLength { len: 7 })
+            ),
+            "{err:?}"
+        );
+        let err = "dead".parse::<HexArray>().unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ParseHexError::Hex(HexDecodeError::WrongLength {
+                    expected: 8,
+                    got: 4
+                })
+            ),
+            "{err:?}"
+        );
+        assert_eq!(
+            err.to_string(),
+            "expected hex string of 8 characters (for 4 bytes), got 4 characters"
+        );
+
+        // Bytes are validated in the same way as on deserialization.
+        let err = "00000000"
+            .parse::<ConstHexBuf<[u8; 4], NonZeroConstHex, 4>>()
+            .unwrap_err();
+        assert!(matches!(err, ParseHexError::Conversion(_)), "{err:?}");
+        assert_eq!(err.to_string(), "all-zero array");
+        let array: ConstHexBuf<[u8; 4], NonZeroConstHex, 4> = "00000001".parse().unwrap();
+        assert_eq!(*array, [0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn lenient_deserialization() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Lenient {
+            #[serde(with = "LenientConstHexForm")]
+            array: [u8; 4],
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Strict {
+            #[serde(with = "ConstHexForm")]
+            #[allow(dead_code)]
+            array: [u8; 4],
+        }
+
+        let valid_strings = [
+            "01020304",
+            " 01020304\n",
+            "01 02 03 04",
+            "0102\t0304",
+            "0 102030 4",
+        ];
+        for s in valid_strings {
+            let json = serde_json::json!({ "array": s });
+            let value: Lenient = serde_json::from_value(json).unwrap();
+            assert_eq!(value.array, [1, 2, 3, 4], "{s:?}");
+        }
+
+        let json = serde_json::json!({ "array": "01 02 0g 04" });
+        let err = serde_json::from_value::<Lenient>(json).unwrap_err();
+        assert_eq!(err.to_string(), "invalid hex character 'g' at position 7");
+
+        let json = serde_json::json!({ "array": "01 02 03" });
+        let err = serde_json::from_value::<Lenient>(json).unwrap_err();
+        assert!(err.to_string().ends_with("got 6 characters"), "{err}");
+        let json = serde_json::json!({ "array": "01 02 03 04 05" });
+        let err = serde_json::from_value::<Lenient>(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected hex string of 8 characters (for 4 bytes), got 10 characters"
+        );
+        let json = serde_json::json!({ "array": "01 02 03 0" });
+        let err = serde_json::from_value::<Lenient>(json).unwrap_err();
+        assert!(
+            err.to_string().starts_with("hex string has odd length 7"),
+            "{err}"
+        );
+
+        let json = serde_json::json!({ "array": "0102 304" });
+        let err = serde_json::from_value::<Strict>(json).unwrap_err();
+        assert_eq!(err.to_string(), "invalid hex character ' ' at position 4");
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_serialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "UuidHex")]
+            compact: uuid::Uuid,
+            #[serde(with = "HyphenatedUuidHex")]
+            hyphenated: uuid::Uuid,
+        }
+
+        const COMPACT: &str = "67e5504410b1426f9247bb680e5fe0c8";
+        const HYPHENATED: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+        let id = uuid::Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        let value = Test {
+            compact: id,
+            hyphenated: id,
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "compact": COMPACT, "hyphenated": HYPHENATED })
+        );
+        // The default `Uuid` serialization is hyphenated, unlike the compact form.
+        assert_ne!(json["compact"], serde_json::to_value(id).unwrap());
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = serde_json::json!({
+            "compact": COMPACT.to_uppercase(),
+            "hyphenated": HYPHENATED.to_uppercase(),
+        });
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[8..24], *id.as_bytes());
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_ids = [
+            COMPACT,
+            "67e55044-10b1-426f-9247bb68-0e5fe0c8",
+            "67e55044-10b1-426f-9247-bb680e5fe0cx",
+        ];
+        for bogus_id in bogus_ids {
+            let json = serde_json::json!({ "compact": COMPACT, "hyphenated": bogus_id });
+            let err = serde_json::from_value::<Test>(json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("expected hyphenated UUID string"), "{err}");
+        }
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn pod_serialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "PodHex::<[f32; 4], 16>")]
+            color: [f32; 4],
+        }
+
+        let value = Test {
+            color: [1.0, 0.5, -0.25, 0.0],
+        };
+        let expected_bytes: alloc::vec::Vec<u8> =
+            value.color.iter().flat_map(|x| x.to_ne_bytes()).collect();
+
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "color": hex::encode(&expected_bytes) })
+        );
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[8..], expected_bytes);
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+    }
+
+    #[test]
+    fn newtype_serialization() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Key([u8; 32]);
+
+        impl From<[u8; 32]> for Key {
+            fn from(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl From<Key> for [u8; 32] {
+            fn from(key: Key) -> Self {
+                key.0
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "ConstHexForm")]
+            key: Key,
+        }
+
+        let value = Test {
+            key: Key([0xab; 32]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, serde_json::json!({ "key": "ab".repeat(32) }));
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+    }
+
+    #[test]
+    fn as_ref_serialization() {
+        // Neither `Copy` nor `AsRef<[u8]>`.
+        #[derive(Debug, PartialEq)]
+        struct Key([u8; 16]);
+
+        impl AsRef<[u8; 16]> for Key {
+            fn as_ref(&self) -> &[u8; 16] {
+                &self.0
+            }
+        }
+
+        impl From<[u8; 16]> for Key {
+            fn from(bytes: [u8; 16]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "AsRefConstHexForm")]
+            key: Key,
+        }
+
+        let value = Test {
+            key: Key([0xcd; 16]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, serde_json::json!({ "key": "cd".repeat(16) }));
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[8..], [0xcd; 16]);
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let err = serde_json::from_value::<Test>(serde_json::json!({ "key": "cd" })).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected hex string of 32 characters"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn const_hex_bytes() {
+        let bytes = [0xc0, 0xff, 0xee];
+        let hex_bytes = ConstHexBytes::new(&bytes);
+        assert_eq!(hex_bytes, ConstHexBytes::from(&bytes));
+        assert_eq!(hex_bytes.to_string(), "c0ffee");
+        assert_eq!(format!("{hex_bytes:?}"), "ConstHexBytes(c0ffee)");
+        assert_eq!(hex_bytes.as_ref(), bytes);
+
+        let json = serde_json::to_value(hex_bytes).unwrap();
+        assert_eq!(json, "c0ffee");
+        let encoded = bincode::serialize(&hex_bytes).unwrap();
+        assert_eq!(encoded[8..], bytes);
+        let decoded: [u8; 3] =
+            <ConstHexForm<_>>::deserialize(&mut serde_json::Deserializer::from_str(r#""c0ffee""#))
+                .unwrap();
+        assert_eq!(decoded, bytes);
+    }
+
+    #[test]
+    fn encoding_hex_str_on_stack() {
+        fn assert_encoding<const N: usize>(bytes: [u8; N]) {
+            let mut expected = [0_u8; 512];
+            let expected = &mut expected[..2 * N];
+            hex::encode_to_slice(bytes, expected).unwrap();
+
+            let mut buffer = [0_u16; N];
+            let hex_str = encode_hex_str(&bytes, &mut buffer, false);
+            assert_eq!(hex_str.as_bytes(), expected);
+            let hex_str = encode_hex_str(&bytes, &mut buffer, true);
+            expected.make_ascii_uppercase();
+            assert_eq!(hex_str.as_bytes(), expected);
+        }
+
+        assert_encoding([]);
+        assert_encoding([0xc0]);
+        assert_encoding([0xc0, 0xff, 0xee]);
+        assert_encoding([0xab; 32]);
+        let mut bytes = [0_u8; 256];
+        for (byte, i) in bytes.iter_mut().zip(0_u8..=255) {
+            *byte = i;
+        }
+        assert_encoding(bytes);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn heap_encoding_matches_stack_encoding() {
+        fn assert_encoding<const N: usize>(bytes: [u8; N]) {
+            let mut buffer = [0_u16; N];
+            for upper_case in [false, true] {
+                let expected = encode_hex_str(&bytes, &mut buffer, upper_case);
+                assert_eq!(encode_hex_string(&bytes, upper_case, ""), expected);
+                let expected = format!("0x{expected}");
+                assert_eq!(encode_hex_string(&bytes, upper_case, "0x"), expected);
+            }
+
+            // Compare serialization via the heap path (`ConstHexForm`) and the stack path
+            // (`ConstHexBytes`).
+            let json = ConstHexForm::serialize(&bytes, serde_json::value::Serializer).unwrap();
+            assert_eq!(json, serde_json::to_value(ConstHexBytes(&bytes)).unwrap());
+            let bincode = bincode::serialize(&ConstHexBuf::<_, ConstHexForm<_>, N>::new(bytes));
+            assert_eq!(
+                bincode.unwrap(),
+                bincode::serialize(&ConstHexBytes(&bytes)).unwrap()
+            );
+        }
+
+        assert_encoding([]);
+        assert_encoding([0xc0]);
+        assert_encoding([0xc0, 0xff, 0xee]);
+        assert_encoding([0xab; 32]);
+        let mut bytes = [0_u8; 256];
+        for (byte, i) in bytes.iter_mut().zip(0_u8..=255) {
+            *byte = i;
+        }
+        assert_encoding(bytes);
+    }
+
+    #[test]
+    fn encoding_in_compile_time() {
+        const HEX: [u8; 64] = encode_to_array(&[0xab; 32]);
+        const EMPTY: [u8; 0] = encode_to_array(&[]);
+
+        assert_eq!(HEX.as_slice(), b"ab".repeat(32));
+        assert!(EMPTY.is_empty());
+
+        let bytes: [u8; 256] = {
+            let mut bytes = [0_u8; 256];
+            for (byte, i) in bytes.iter_mut().zip(0_u8..=255) {
+                *byte = i;
+            }
+            bytes
+        };
+        let hex: [u8; 512] = encode_to_array(&bytes);
+        let mut expected = [0_u8; 512];
+        hex::encode_to_slice(bytes, &mut expected).unwrap();
+        assert_eq!(hex, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "output length must be twice the input length")]
+    fn encoding_in_compile_time_with_invalid_output_len() {
+        let _: [u8; 3] = encode_to_array(&[1, 2]);
+    }
+
+    #[cfg(feature = "const_fn_encode")]
+    #[test]
+    fn const_hex_macro() {
+        const KEY: [u8; 32] = [0xab; 32];
+        const KEY_HEX: &str = crate::const_hex!(KEY);
+        assert_eq!(KEY_HEX, "ab".repeat(32));
+        assert_eq!(crate::const_hex!([0x01, 0x02, 0xff]), "0102ff");
+        assert_eq!(crate::const_hex!([]), "");
+    }
+
+    #[test]
+    fn const_hex_writer() {
+        use core::fmt::Write as _;
+
+        let bytes: [u8; 64] = {
+            let mut bytes = [0_u8; 64];
+            for (byte, i) in bytes.iter_mut().zip(0_u8..) {
+                *byte = i;
+            }
+            bytes
+        };
+        let mut expected = [0_u8; 128];
+        hex::encode_to_slice(bytes, &mut expected).unwrap();
+        let expected = str::from_utf8(&expected).unwrap();
+
+        let mut writer = ConstHexWriter::<64>::new();
+        assert!(writer.is_empty());
+        for &byte in &bytes {
+            writer.write_byte(byte).unwrap();
+        }
+        assert_eq!(writer.as_str(), expected);
+        assert!(writer.write_byte(0).is_err());
+
+        let mut writer = ConstHexWriter::<64>::new();
+        for chunk in bytes.chunks(10) {
+            writer.write_bytes(chunk).unwrap();
+        }
+        assert_eq!(writer.to_string(), expected);
+
+        let mut writer = ConstHexWriter::<3>::default();
+        write!(writer, "{}", 42).unwrap();
+        assert_eq!(writer.as_str(), "3432");
+        assert_eq!(writer.len(), 2);
+        assert!(write!(writer, "ab").is_err());
+        assert_eq!(format!("{writer:?}"), r#"ConstHexWriter { hex: "3432" }"#);
+
+        assert_eq!(ConstHexWriter::<0>::new().as_str(), "");
+    }
+
+    #[cfg(feature = "generic-array")]
+    #[test]
+    #[allow(deprecated)]
+    fn generic_array_serialization() {
+        // `GenericArray` is deprecated in recent 0.14.x releases in favor of generic-array 1.x,
+        // but the crates this integration is meant for (e.g., `digest`) still use 0.14.
+        use generic_array::{typenum, GenericArray};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Digest {
+            #[serde(with = "ConstHexForm")]
+            hash: GenericArray<u8, typenum::U32>,
+            #[serde(with = "ConstHexForm")]
+            long_hash: GenericArray<u8, typenum::U64>,
+        }
+
+        let digest = Digest {
+            hash: GenericArray::clone_from_slice(&[0xab; 32]),
+            long_hash: GenericArray::clone_from_slice(&[0xcd; 64]),
+        };
+        let json = serde_json::to_value(&digest).unwrap();
+        let hash_str = json["hash"].as_str().unwrap();
+        assert_eq!(hash_str.len(), 64);
+        assert_eq!(hash_str, "ab".repeat(32));
+        assert_eq!(json["long_hash"], "cd".repeat(64));
+        let digest_copy: Digest = serde_json::from_value(json).unwrap();
+        assert_eq!(digest_copy, digest);
+
+        let bytes = bincode::serialize(&digest).unwrap();
+        assert_eq!(bytes[8..40], [0xab; 32]); // first 8 bytes encode the length
+        let digest_copy: Digest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(digest_copy, digest);
+
+        let json = serde_json::json!({ "hash": "ab".repeat(31), "long_hash": "cd".repeat(64) });
+        let err = serde_json::from_value::<Digest>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected hex string of 64 characters (for 32 bytes)"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn upper_case_serialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct UpperArrays {
+            #[serde(with = "UpperConstHexForm")]
+            array: [u8; 4],
+            #[serde(with = "UpperConstHexForm")]
+            empty_array: [u8; 0],
+        }
+
+        let arrays = UpperArrays {
+            array: [0xde, 0xad, 0xbe, 0xef],
+            empty_array: [],
+        };
+        let json = serde_json::to_value(&arrays).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "array": "DEADBEEF", "empty_array": "" })
+        );
+        let arrays_copy: UpperArrays = serde_json::from_value(json).unwrap();
+        assert_eq!(arrays_copy, arrays);
+
+        // Lower-case and mixed-case inputs should be accepted as well.
+        let json = serde_json::json!({ "array": "deADbeef", "empty_array": "" });
+        let arrays_copy: UpperArrays = serde_json::from_value(json).unwrap();
+        assert_eq!(arrays_copy, arrays);
+
+        // Binary serialization should be unaffected.
+        let bytes = bincode::serialize(&arrays).unwrap();
+        assert!(bytes
+            .windows(4)
+            .any(|window| window == [0xde, 0xad, 0xbe, 0xef]));
+        let arrays_copy: UpperArrays = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(arrays_copy, arrays);
+
+        // Upper-case output should be readable with `ConstHexForm`.
         let json = serde_json::json!({
-            "public_key": "06fac1f22240cffd637ead6647188429fafda9c9cb7eae43386ac17f61115075",
+            "array": "0B".repeat(16),
+            "longer_array": "F0".repeat(32),
         });
-        let holder: Holder = serde_json::from_value(json).unwrap();
-        assert_eq!(holder.public_key[0], 6);
+        let arrays: Arrays = serde_json::from_value(json).unwrap();
+        assert_eq!(arrays.array, [11; 16]);
+        assert_eq!(arrays.longer_array, [240; 32]);
+    }
+
+    #[test]
+    fn prefixed_serialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct PrefixedArrays {
+            #[serde(with = "PrefixedConstHexForm")]
+            array: [u8; 4],
+            #[serde(with = "PrefixedConstHexForm")]
+            empty_array: [u8; 0],
+        }
+
+        let arrays = PrefixedArrays {
+            array: [0xde, 0xad, 0xbe, 0xef],
+            empty_array: [],
+        };
+        let json = serde_json::to_value(&arrays).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "array": "0xdeadbeef", "empty_array": "0x" })
+        );
+        let arrays_copy: PrefixedArrays = serde_json::from_value(json).unwrap();
+        assert_eq!(arrays_copy, arrays);
+
+        let buffer = bincode::serialize(&arrays).unwrap();
+        assert!(buffer.windows(4).any(|window| window == arrays.array));
+        let arrays_copy: PrefixedArrays = bincode::deserialize(&buffer).unwrap();
+        assert_eq!(arrays_copy, arrays);
+
+        let json = serde_json::json!({ "array": "deadbeef", "empty_array": "0x" });
+        let err = serde_json::from_value::<PrefixedArrays>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid value"), "{err}");
+        assert!(err.contains("expected `0x`-prefixed hex string"), "{err}");
+    }
+
+    #[test]
+    fn deserializing_array_with_invalid_chars() {
+        #[derive(Debug, Deserialize)]
+        struct PrefixedArray(
+            #[serde(with = "PrefixedConstHexForm")]
+            #[allow(dead_code)]
+            [u8; 2],
+        );
+
+        let json = serde_json::json!({
+            "array": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "longer_array": "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0bxb",
+        });
+        let err = serde_json::from_value::<Arrays>(json)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "invalid hex character 'x' at position 62");
+
+        let err = serde_json::from_str::<PrefixedArray>(r#""0x0\u00fc0""#)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.starts_with("invalid hex character (byte 0xc3) at position 3"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn optional_values() {
+        use ed25519_compact::PublicKey;
+
+        struct PublicKeyHex(());
+        impl ConstHex<PublicKey, 32> for PublicKeyHex {
+            type Error = ed25519_compact::Error;
+
+            fn create_bytes(pk: &PublicKey) -> [u8; 32] {
+                **pk
+            }
+
+            fn from_bytes(bytes: [u8; 32]) -> Result<PublicKey, Self::Error> {
+                PublicKey::from_slice(&bytes)
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct OptionalTest {
+            #[serde(with = "ConstHexOption::<PublicKeyHex>")]
+            public_key: Option<PublicKey>,
+            #[serde(with = "ConstHexOption::<ConstHexForm<_>>")]
+            array: Option<[u8; 4]>,
+        }
+
+        const KEY_HEX: &str = "06fac1f22240cffd637ead6647188429fafda9c9cb7eae43386ac17f61115075";
+        let mut key_bytes = [0_u8; 32];
+        hex::decode_to_slice(KEY_HEX, &mut key_bytes).unwrap();
+        let public_key = PublicKey::from_slice(&key_bytes).unwrap();
+        let values = [
+            OptionalTest {
+                public_key: Some(public_key),
+                array: None,
+            },
+            OptionalTest {
+                public_key: None,
+                array: Some([1, 2, 3, 4]),
+            },
+        ];
+        let expected_jsons = [
+            serde_json::json!({ "public_key": KEY_HEX, "array": null }),
+            serde_json::json!({ "public_key": null, "array": "01020304" }),
+        ];
+
+        for (value, expected_json) in values.iter().zip(expected_jsons) {
+            let json = serde_json::to_value(value).unwrap();
+            assert_eq!(json, expected_json);
+            let value_copy: OptionalTest = serde_json::from_value(json).unwrap();
+            assert_eq!(value_copy, *value);
+
+            let bytes = bincode::serialize(value).unwrap();
+            assert_eq!(bytes[0], u8::from(value.public_key.is_some()));
+            let value_copy: OptionalTest = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(value_copy, *value);
+        }
+
+        let bogus_json = serde_json::json!({ "public_key": null, "array": "010203" });
+        let err = serde_json::from_value::<OptionalTest>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected hex string of 8 characters"), "{err}");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vectors() {
+        use alloc::{vec, vec::Vec};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct VecTest {
+            #[serde(with = "ConstHexVec::<ConstHexForm<_>>")]
+            arrays: Vec<[u8; 4]>,
+        }
+
+        let values = [
+            VecTest { arrays: vec![] },
+            VecTest {
+                arrays: vec![[1, 2, 3, 4], [5, 6, 7, 8]],
+            },
+        ];
+        let expected_jsons = [
+            serde_json::json!({ "arrays": [] }),
+            serde_json::json!({ "arrays": ["01020304", "05060708"] }),
+        ];
+        for (value, expected_json) in values.iter().zip(expected_jsons) {
+            let json = serde_json::to_value(value).unwrap();
+            assert_eq!(json, expected_json);
+            let value_copy: VecTest = serde_json::from_value(json).unwrap();
+            assert_eq!(value_copy, *value);
+
+            let bytes = bincode::serialize(value).unwrap();
+            let value_copy: VecTest = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(value_copy, *value);
+        }
+
+        // `bincode` encodes each array with its length, followed by raw bytes.
+        let bytes = bincode::serialize(&values[1]).unwrap();
+        assert_eq!(bytes[..8], 2_u64.to_le_bytes());
+        assert_eq!(bytes[8..16], 4_u64.to_le_bytes());
+        assert_eq!(bytes[16..20], [1, 2, 3, 4]);
+        assert_eq!(bytes[28..], [5, 6, 7, 8]);
+
+        let bogus_jsons = [
+            (
+                serde_json::json!({ "arrays": ["01020304", "0506"] }),
+                "element 1: expected hex string of 8 characters",
+            ),
+            (
+                serde_json::json!({ "arrays": [true] }),
+                "element 0: invalid type: boolean `true`, expected hex-encoded byte array",
+            ),
+            (
+                serde_json::json!({ "arrays": "01020304" }),
+                "expected sequence of hex-encoded byte arrays of length 4",
+            ),
+        ];
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<VecTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+    }
+
+    #[test]
+    fn strict_deserialization() {
+        type Strict = StrictConstHex<ConstHexForm<[u8; 2]>>;
+
+        let bytes = Strict::deserialize(serde_json::json!("aabb")).unwrap();
+        assert_eq!(bytes, [0xaa, 0xbb]);
+        let bytes = ConstHexForm::<[u8; 2]>::deserialize(serde_json::json!("AABB")).unwrap();
+        assert_eq!(bytes, [0xaa, 0xbb]);
+
+        let bogus_inputs = [
+            (
+                "AABB",
+                "strict hex requires lowercase characters only; got 'A' at position 0",
+            ),
+            ("0xaabb", "invalid hex character 'x' at position 1"),
+            (
+                "aabbcc",
+                "expected hex string of 4 characters (for 2 bytes), got 6 characters",
+            ),
+        ];
+        for (input, expected_err) in bogus_inputs {
+            let err = Strict::deserialize(serde_json::json!(input))
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+
+        let bytes = bincode::serialize(&ConstHexBuf::<_, Strict, 2>::new([0xaa, 0xbb])).unwrap();
+        let buffer: ConstHexBuf<[u8; 2], Strict, 2> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(*buffer, [0xaa, 0xbb]);
+    }
+
+    #[test]
+    fn flexible_deserialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct FlexTest {
+            #[serde(with = "ConstFlexHex::<ConstHexForm<_>>")]
+            array: [u8; 4],
+            #[serde(with = "ConstFlexHex::<PrefixedConstHexForm<_>>")]
+            prefixed: [u8; 2],
+        }
+
+        let value = FlexTest {
+            array: [0xc0, 0xff, 0xee, 0],
+            prefixed: [1, 2],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "array": "c0ffee00", "prefixed": "0x0102" })
+        );
+        let value_copy: FlexTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = serde_json::json!({ "array": [0xc0, 0xff, 0xee, 0], "prefixed": [1, 2] });
+        let value_copy: FlexTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: FlexTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = serde_json::json!({ "array": [1, 2], "prefixed": [1, 2] });
+        let err = serde_json::from_value::<FlexTest>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid length 2"), "{err}");
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn flexible_deserialization_with_base64() {
+        type Form = ConstFlexHex<ConstHexForm<[u8; 4]>>;
+
+        let bytes = Form::deserialize(serde_json::json!("3q2+7w==")).unwrap();
+        assert_eq!(bytes, [0xde, 0xad, 0xbe, 0xef]);
+        // Valid both as hex and base64; hex takes priority.
+        let bytes = Form::deserialize(serde_json::json!("c0ffee00")).unwrap();
+        assert_eq!(bytes, [0xc0, 0xff, 0xee, 0]);
+
+        let err = Form::deserialize(serde_json::json!("AQID"))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.starts_with("invalid length 3, expected hex-encoded byte array of length 4"),
+            "{err}"
+        );
+        let err = Form::deserialize(serde_json::json!("!!"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("not valid base64 either"), "{err}");
+    }
+
+    #[test]
+    fn pairs() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct PairTest {
+            #[serde(with = "ConstHexPair::<ConstHexForm<_>, UpperConstHexForm<_>>")]
+            pair: ([u8; 2], [u8; 4]),
+        }
+
+        let value = PairTest {
+            pair: ([1, 2], [3, 4, 5, 0xab]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, serde_json::json!({ "pair": ["0102", "030405AB"] }));
+        let value_copy: PairTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[..8], 2_u64.to_le_bytes());
+        assert_eq!(bytes[8..10], [1, 2]);
+        assert_eq!(bytes[10..18], 4_u64.to_le_bytes());
+        assert_eq!(bytes[18..], [3, 4, 5, 0xab]);
+        let value_copy: PairTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_jsons = [
+            (
+                serde_json::json!({ "pair": ["0102"] }),
+                "invalid length 1, expected pair of hex-encoded byte arrays of lengths 2 and 4",
+            ),
+            (
+                serde_json::json!({ "pair": ["0102", "0304"] }),
+                "element 1: expected hex string of 8 characters",
+            ),
+        ];
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<PairTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn concatenated_vectors() {
+        use alloc::{vec, vec::Vec};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ConcatTest {
+            #[serde(with = "ConstHexConcat::<ConstHexForm<_>>")]
+            keys: Vec<[u8; 2]>,
+        }
+
+        let values = [
+            ConcatTest { keys: vec![] },
+            ConcatTest {
+                keys: vec![[0xc0, 0xff]],
+            },
+            ConcatTest {
+                keys: vec![[1, 2], [3, 4], [0xab, 0xcd]],
+            },
+        ];
+        let expected_jsons = [
+            serde_json::json!({ "keys": "" }),
+            serde_json::json!({ "keys": "c0ff" }),
+            serde_json::json!({ "keys": "01020304abcd" }),
+        ];
+        for (value, expected_json) in values.iter().zip(expected_jsons) {
+            let json = serde_json::to_value(value).unwrap();
+            assert_eq!(json, expected_json);
+            let value_copy: ConcatTest = serde_json::from_value(json).unwrap();
+            assert_eq!(value_copy, *value);
+
+            let bytes = bincode::serialize(value).unwrap();
+            let value_copy: ConcatTest = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(value_copy, *value);
+        }
+
+        // `bincode` encodes all elements as a single byte array.
+        let bytes = bincode::serialize(&values[2]).unwrap();
+        assert_eq!(bytes[..8], 6_u64.to_le_bytes());
+        assert_eq!(bytes[8..], [1, 2, 3, 4, 0xab, 0xcd]);
+
+        let bogus_jsons = [
+            (
+                serde_json::json!({ "keys": "010203" }),
+                "expected hex string with length divisible by 4, got 6 characters",
+            ),
+            (serde_json::json!({ "keys": "01020" }), "odd length"),
+            (
+                serde_json::json!({ "keys": "0102030g" }),
+                "invalid hex character",
+            ),
+        ];
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<ConcatTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+
+        let bytes = bincode::serialize(&[1_u8, 2, 3].as_slice()).unwrap();
+        let err = bincode::deserialize::<ConcatTest>(&bytes)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("expected byte array with length divisible by 2, got 3 bytes"),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn concatenated_pairs() {
+        struct NonZeroKey(());
+
+        impl ConstHex<[u8; 4], 4> for NonZeroKey {
+            type Error = &'static str;
+
+            fn create_bytes(value: &[u8; 4]) -> [u8; 4] {
+                *value
+            }
+
+            fn from_bytes(bytes: [u8; 4]) -> Result<[u8; 4], Self::Error> {
+                if bytes == [0; 4] {
+                    Err("all-zero key")
+                } else {
+                    Ok(bytes)
+                }
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct PairTest {
+            #[serde(with = "ConstHexConcatPair::<ConstHexForm<_>, NonZeroKey>")]
+            nonce_and_key: ([u8; 2], [u8; 4]),
+        }
+
+        let value = PairTest {
+            nonce_and_key: ([0xc0, 0xff], [1, 2, 3, 4]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, serde_json::json!({ "nonce_and_key": "c0ff01020304" }));
+        let value_copy: PairTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[..8], 6_u64.to_le_bytes());
+        assert_eq!(bytes[8..], [0xc0, 0xff, 1, 2, 3, 4]);
+        let value_copy: PairTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_jsons = [
+            (
+                serde_json::json!({ "nonce_and_key": "c0ff010203" }),
+                "expected hex string of 12 characters (for 6 bytes), got 10 characters",
+            ),
+            (
+                serde_json::json!({ "nonce_and_key": "c0ff0102030405" }),
+                "expected hex string of 12 characters (for 6 bytes), got 14 characters",
+            ),
+            (
+                serde_json::json!({ "nonce_and_key": "c0ff00000000" }),
+                "element 1: all-zero key",
+            ),
+            (
+                serde_json::json!({ "nonce_and_key": "c0ff0102030" }),
+                "odd length",
+            ),
+        ];
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<PairTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+
+        let bytes = bincode::serialize(&[1_u8, 2, 3].as_slice()).unwrap();
+        let err = bincode::deserialize::<PairTest>(&bytes)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("expected byte array of 6 bytes, got 3 bytes"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn forced_hex_strings() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ForceTest {
+            #[serde(with = "ForceConstHexStr::<ConstHexForm<_>>")]
+            array: [u8; 4],
+            #[serde(with = "ConstHexOption::<ForceConstHexStr<UpperConstHexForm<_>>>")]
+            optional_array: Option<[u8; 2]>,
+        }
+
+        let value = ForceTest {
+            array: [0xde, 0xad, 0xbe, 0xef],
+            optional_array: Some([0xc0, 0xff]),
+        };
+        let bytes = bincode::serialize(&value).unwrap();
+        let expected_bytes = bincode::serialize(&("deadbeef", Some("C0FF"))).unwrap();
+        assert_eq!(bytes, expected_bytes);
+        let value_copy: ForceTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = serde_json::to_value(&value).unwrap();
+        let expected_json = serde_json::json!({ "array": "deadbeef", "optional_array": "C0FF" });
+        assert_eq!(json, expected_json);
+        let value_copy: ForceTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_bytes = bincode::serialize(&("dead", Some("c0ff"))).unwrap();
+        let err = bincode::deserialize::<ForceTest>(&bogus_bytes)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected hex string of 8 characters"), "{err}");
+    }
+
+    #[test]
+    fn hex_or_bytes_deserialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "ConstHexOrBytes::<ConstHexForm<_>>")]
+            array: [u8; 4],
+        }
+
+        let expected = Test {
+            array: [0xde, 0xad, 0xbe, 0xef],
+        };
+        let inputs = [
+            serde_json::json!({ "array": "deadbeef" }),
+            serde_json::json!({ "array": "DEADbeef" }),
+            serde_json::json!({ "array": [0xde, 0xad, 0xbe, 0xef] }),
+        ];
+        for input in inputs {
+            let value: Test = serde_json::from_value(input).unwrap();
+            assert_eq!(value, expected);
+        }
+        let json = serde_json::to_value(&expected).unwrap();
+        assert_eq!(json, serde_json::json!({ "array": "deadbeef" }));
+
+        let bytes = bincode::serialize(&expected).unwrap();
+        assert_eq!(bytes[8..], [0xde, 0xad, 0xbe, 0xef]);
+        let value: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value, expected);
+
+        let bogus_jsons = [
+            (
+                serde_json::json!({ "array": "deadbeeg" }),
+                "invalid hex character 'g' at position 7",
+            ),
+            (
+                serde_json::json!({ "array": "dead" }),
+                "expected hex string of 8 characters",
+            ),
+            (
+                serde_json::json!({ "array": [1, 2, 3] }),
+                "invalid length 3",
+            ),
+            (
+                serde_json::json!({ "array": 1 }),
+                "expected hex-encoded byte array of length 4 or sequence of 4 bytes",
+            ),
+        ];
+        for (json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<Test>(json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+    }
+
+    #[test]
+    fn forced_binary_serialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ForceTest {
+            #[serde(with = "ForceConstBinary::<ConstHexForm<_>>")]
+            array: [u8; 4],
+        }
+
+        let value = ForceTest {
+            array: [0xde, 0xad, 0xbe, 0xef],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "array": [0xde, 0xad, 0xbe, 0xef] })
+        );
+        let value_copy: ForceTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: ForceTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_jsons = [
+            (
+                serde_json::json!({ "array": [1, 2, 3] }),
+                "invalid length 3",
+            ),
+            (
+                serde_json::json!({ "array": [1, 2, 3, 4, 5, 6] }),
+                "invalid length 6",
+            ),
+            (
+                serde_json::json!({ "array": "deadbeef" }),
+                "expected byte array",
+            ),
+        ];
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<ForceTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+    }
+
+    #[test]
+    fn colon_separated_serialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ColonTest {
+            #[serde(with = "ColonConstHexForm")]
+            mac: [u8; 6],
+        }
+
+        let value = ColonTest {
+            mac: [0x01, 0x23, 0x45, 0x67, 0x89, 0xab],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, serde_json::json!({ "mac": "01:23:45:67:89:AB" }));
+        let value_copy: ColonTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = serde_json::json!({ "mac": "0123456789AB" });
+        let value_copy: ColonTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: ColonTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_macs = [
+            ("01:23:45:67:89:", "expected colon-separated hex string"),
+            ("01:23:45:67:89ab", "expected colon-separated hex string"),
+            (
+                "01:23:45:67:89:xy",
+                "invalid hex character 'x' at position 15",
+            ),
+            ("01:23:45:67:89", "invalid length 5"),
+        ];
+        for (bogus_mac, expected_err) in bogus_macs {
+            let bogus_json = serde_json::json!({ "mac": bogus_mac });
+            let err = serde_json::from_value::<ColonTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroizing_decoded_array() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ZeroizeTest {
+            #[serde(with = "ZeroizeConstHexForm")]
+            secret: [u8; 4],
+        }
+
+        let mut bytes = [0xab; 4];
+        let array = from_array_zeroizing::<_, ZeroizeConstHexForm<_>, 4>(&mut bytes).unwrap();
+        assert_eq!(array, [0xab; 4]);
+        assert_eq!(bytes, [0; 4]);
+
+        let value = ZeroizeTest { secret: [0xc0; 4] };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, serde_json::json!({ "secret": "c0c0c0c0" }));
+        let value_copy: ZeroizeTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: ZeroizeTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bridging_to_hex() {
+        use crate::{ForceHexStr, Hex, HexVec};
+        use alloc::{vec, vec::Vec};
+
+        #[derive(Debug, PartialEq)]
+        struct Key([u8; 4]);
+
+        struct KeyHex(());
+
+        impl ConstHex<Key, 4> for KeyHex {
+            type Error = &'static str;
+
+            fn create_bytes(key: &Key) -> [u8; 4] {
+                key.0
+            }
+
+            fn from_bytes(bytes: [u8; 4]) -> Result<Key, Self::Error> {
+                if bytes == [0; 4] {
+                    Err("zero key")
+                } else {
+                    Ok(Key(bytes))
+                }
+            }
+        }
+
+        type KeyBridge = ConstHexBridge<KeyHex, 4>;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct BridgeTest {
+            #[serde(with = "KeyBridge")]
+            key: Key,
+            #[serde(with = "HexVec::<KeyBridge>")]
+            keys: Vec<Key>,
+            #[serde(with = "ForceHexStr::<KeyBridge>")]
+            forced_key: Key,
+        }
+
+        let value = BridgeTest {
+            key: Key([1, 2, 3, 4]),
+            keys: vec![Key([5; 4]), Key([6; 4])],
+            forced_key: Key([7; 4]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        let expected_json = serde_json::json!({
+            "key": "01020304",
+            "keys": ["05050505", "06060606"],
+            "forced_key": "07070707",
+        });
+        assert_eq!(json, expected_json);
+        let value_copy: BridgeTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: BridgeTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        assert_eq!(KeyBridge::create_bytes(&value.key).as_ref(), [1, 2, 3, 4]);
+        let err = KeyBridge::from_bytes(&[1, 2, 3]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid length 3, expected byte array of length 4"
+        );
+        let err = KeyBridge::from_bytes(&[0; 4]).unwrap_err();
+        assert_eq!(err.to_string(), "zero key");
 
         let bogus_json = serde_json::json!({
-            "public_key": "06fac1f22240cffd637ead6647188429fafda9c9cb7eae43386ac17f6111507",
+            "key": "01020304",
+            "keys": ["00000000"],
+            "forced_key": "07070707",
         });
-        let err = serde_json::from_value::<Holder>(bogus_json).unwrap_err();
-        assert!(err
-            .to_string()
-            .contains("expected hex-encoded byte array of length 32"));
+        let err = serde_json::from_value::<BridgeTest>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "element 0: zero key");
+    }
+
+    #[test]
+    fn const_hex_bytes_functions() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "crate::const_hex_bytes")]
+            short: [u8; 4],
+            #[serde(with = "crate::const_hex_bytes")]
+            medium: [u8; 16],
+            #[serde(with = "crate::const_hex_bytes")]
+            long: [u8; 32],
+        }
+
+        let value = Test {
+            short: [1, 2, 3, 4],
+            medium: [0xab; 16],
+            long: [0xc0; 32],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "short": "01020304",
+                "medium": "ab".repeat(16),
+                "long": "c0".repeat(32),
+            })
+        );
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = serde_json::json!({
+            "short": "010203",
+            "medium": "ab".repeat(16),
+            "long": "c0".repeat(32),
+        });
+        let err = serde_json::from_value::<Test>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected hex string of 8 characters"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn upper_const_hex_bytes_functions() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "crate::upper_const_hex_bytes")]
+            key: [u8; 16],
+        }
+
+        let key = [
+            0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54,
+            0x32, 0x10_u8,
+        ];
+        let hex_str =
+            crate::upper_const_hex_bytes::serialize(&key, serde_json::value::Serializer).unwrap();
+        assert_eq!(hex_str, "ABCDEF0123456789FEDCBA9876543210");
+
+        let value = Test { key };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "key": "ABCDEF0123456789FEDCBA9876543210" })
+        );
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+        let json = serde_json::json!({ "key": "abcdef0123456789fedcba9876543210" });
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+    }
+
+    #[test]
+    fn separate_serialize_with_and_deserialize_with() {
+        #[derive(Debug, PartialEq, Serialize)]
+        struct SerializeTest {
+            #[serde(serialize_with = "crate::serialize_const_hex::<_, ConstHexForm<_>, _, 4>")]
+            key: [u8; 4],
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct DeserializeTest {
+            #[serde(
+                deserialize_with = "crate::deserialize_const_hex::<_, UpperConstHexForm<_>, _, 4>"
+            )]
+            key: [u8; 4],
+        }
+
+        let value = SerializeTest {
+            key: [0xc0, 0xff, 0xee, 0x01],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, serde_json::json!({ "key": "c0ffee01" }));
+        let restored: DeserializeTest = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.key, value.key);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let restored: DeserializeTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(restored.key, value.key);
+
+        let json = serde_json::json!({ "key": "c0ffee" });
+        let err = serde_json::from_value::<DeserializeTest>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected hex string of 8 characters"),
+            "{err}"
+        );
     }
 }
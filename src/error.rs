@@ -0,0 +1,251 @@
+//! Error types.
+
+use hex::FromHexError;
+use serde::de::Error as DeError;
+
+use core::fmt;
+
+/// Error decoding a hex string.
+///
+/// This error is used to report hex decoding failures when deserializing [`Hex`] or [`ConstHex`]
+/// implementations from human-readable formats, and is returned by [`codec::decode()`].
+///
+/// [`Hex`]: crate::Hex
+/// [`ConstHex`]: crate::ConstHex
+/// [`codec::decode()`]: crate::codec::decode()
+///
+/// # Examples
+///
+/// ```
+/// # use hex_buffer_serde::{codec, Hex as _, HexDecodeError, HexForm};
+/// # use serde_derive::Deserialize;
+/// #[derive(Debug, Deserialize)]
+/// struct Test {
+///     #[serde(with = "HexForm")]
+///     buffer: Vec<u8>,
+/// }
+///
+/// let err = serde_json::from_str::<Test>(r#"{ "buffer": "c0ffeg" }"#).unwrap_err();
+/// assert!(err
+///     .to_string()
+///     .starts_with("invalid hex character 'g' at position 5"));
+///
+/// let err = codec::decode("c0ffeg").unwrap_err();
+/// assert_eq!(err, HexDecodeError::InvalidChar { position: 5, byte: b'g' });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HexDecodeError {
+    /// Hex string has an odd number of hex digits.
+    OddLength {
+        /// Number of hex digits in the string.
+        len: usize,
+    },
+    /// Hex string contains an invalid char.
+    InvalidChar {
+        /// Zero-based position of the invalid char in the hex string, in bytes.
+        position: usize,
+        /// Byte at `position`. For non-ASCII chars, this is the first byte of their UTF-8 encoding.
+        byte: u8,
+    },
+    /// Hex string has an unexpected number of hex digits (e.g., when decoding
+    /// a fixed-length byte array).
+    WrongLength {
+        /// Expected number of hex digits.
+        expected: usize,
+        /// Actual number of hex digits.
+        got: usize,
+    },
+}
+
+impl HexDecodeError {
+    pub(crate) fn new(position: usize, byte: u8) -> Self {
+        Self::InvalidChar { position, byte }
+    }
+
+    pub(crate) fn odd_length(len: usize) -> Self {
+        Self::OddLength { len }
+    }
+
+    /// Converts an error returned by the `hex` crate when decoding `hex_str`. `offset` is added
+    /// to the reported position; it is useful if `hex_str` is a substring of the original string.
+    ///
+    /// Returns `None` if the error is not caused by an invalid char.
+    pub(crate) fn from_hex_error(err: FromHexError, hex_str: &str, offset: usize) -> Option<Self> {
+        if let FromHexError::InvalidHexCharacter { index, .. } = err {
+            // `hex` reports the byte at `index` cast to `char`, which is incorrect
+            // for non-ASCII chars; we take the byte from `hex_str` instead.
+            Some(Self::new(offset + index, hex_str.as_bytes()[index]))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for HexDecodeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength { len } => write!(
+                formatter,
+                "hex string has odd length {len}; expected even number of characters"
+            ),
+            Self::InvalidChar { position, byte } if byte.is_ascii() => write!(
+                formatter,
+                "invalid hex character {:?} at position {position}",
+                char::from(*byte)
+            ),
+            Self::InvalidChar { position, byte } => write!(
+                formatter,
+                "invalid hex character (byte {byte:#04x}) at position {position}"
+            ),
+            Self::WrongLength { expected, got } => write!(
+                formatter,
+                "expected hex string of {expected} characters (for {} bytes), got {got} characters",
+                expected / 2
+            ),
+        }
+    }
+}
+
+impl core::error::Error for HexDecodeError {}
+
+/// Error parsing a [`HexBuf`] or [`ConstHexBuf`] from a hex string via [`FromStr`].
+///
+/// [`HexBuf`]: crate::HexBuf
+/// [`ConstHexBuf`]: crate::ConstHexBuf
+/// [`FromStr`]: core::str::FromStr
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ParseHexError<E> {
+    /// The string is not a valid hex string.
+    Hex(HexDecodeError),
+    /// Error converting decoded bytes into a value.
+    Conversion(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ParseHexError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hex(err) => fmt::Display::fmt(err, formatter),
+            Self::Conversion(err) => fmt::Display::fmt(err, formatter),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for ParseHexError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Hex(err) => Some(err),
+            Self::Conversion(err) => Some(err),
+        }
+    }
+}
+
+/// Function describing the expected value in deserialization errors; see `Hex::expecting()`
+/// and `ConstHex::expecting()`.
+pub(crate) type ExpectingFn = fn(&mut fmt::Formatter<'_>) -> fmt::Result;
+
+/// Checks that a hex string with `len` digits has even length. `hex` rejects odd-length strings
+/// as well, but its error cannot be converted into a descriptive deserialization error.
+pub(crate) fn check_hex_len<E: DeError>(len: usize) -> Result<(), E> {
+    if len % 2 == 0 {
+        Ok(())
+    } else {
+        Err(E::custom(HexDecodeError::odd_length(len)))
+    }
+}
+
+/// Checks that `hex_str` consists of hex digits and ASCII whitespace. `offset` is added
+/// to the reported position of an invalid char.
+pub(crate) fn check_lenient_hex_chars<E: DeError>(hex_str: &str, offset: usize) -> Result<(), E> {
+    let invalid_byte = hex_str
+        .bytes()
+        .enumerate()
+        .find(|&(_, byte)| !byte.is_ascii_hexdigit() && !byte.is_ascii_whitespace());
+    match invalid_byte {
+        Some((position, byte)) => Err(E::custom(HexDecodeError::new(offset + position, byte))),
+        None => Ok(()),
+    }
+}
+
+/// Checks that `hex_str` consists of lower-case hex digits only.
+pub(crate) fn check_strict_hex_chars<E: DeError>(hex_str: &str) -> Result<(), E> {
+    let invalid_byte = hex_str
+        .bytes()
+        .enumerate()
+        .find(|&(_, byte)| !matches!(byte, b'0'..=b'9' | b'a'..=b'f'));
+    match invalid_byte {
+        Some((position, byte)) if byte.is_ascii_hexdigit() => Err(E::custom(format_args!(
+            "strict hex requires lowercase characters only; got {:?} at position {position}",
+            char::from(byte)
+        ))),
+        Some((position, byte)) => Err(E::custom(HexDecodeError::new(position, byte))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString;
+
+    #[test]
+    fn converting_hex_errors() {
+        let hex_str = "0102g3";
+        let err = hex::decode_to_slice(hex_str, &mut [0; 3]).unwrap_err();
+        let err = HexDecodeError::from_hex_error(err, hex_str, 0).unwrap();
+        assert_eq!(
+            err,
+            HexDecodeError::InvalidChar {
+                position: 4,
+                byte: b'g'
+            }
+        );
+        assert_eq!(err.to_string(), "invalid hex character 'g' at position 4");
+
+        let err = hex::decode_to_slice(hex_str, &mut [0; 3]).unwrap_err();
+        let err = HexDecodeError::from_hex_error(err, hex_str, 2).unwrap();
+        assert!(
+            matches!(err, HexDecodeError::InvalidChar { position: 6, .. }),
+            "{err:?}"
+        );
+
+        let hex_str = "01\u{fc}";
+        let err = hex::decode_to_slice(hex_str, &mut [0; 2]).unwrap_err();
+        let err = HexDecodeError::from_hex_error(err, hex_str, 0).unwrap();
+        assert_eq!(
+            err,
+            HexDecodeError::InvalidChar {
+                position: 2,
+                byte: 0xc3
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid hex character (byte 0xc3) at position 2"
+        );
+
+        let err = hex::decode_to_slice("012", &mut [0; 1]).unwrap_err();
+        assert!(HexDecodeError::from_hex_error(err, "012", 0).is_none());
+    }
+
+    #[test]
+    fn length_errors() {
+        let err = HexDecodeError::odd_length(3);
+        assert_eq!(err, HexDecodeError::OddLength { len: 3 });
+        assert_eq!(
+            err.to_string(),
+            "hex string has odd length 3; expected even number of characters"
+        );
+
+        let err = HexDecodeError::WrongLength {
+            expected: 8,
+            got: 6,
+        };
+        assert_eq!(
+            err.to_string(),
+            "expected hex string of 8 characters (for 4 bytes), got 6 characters"
+        );
+    }
+}
@@ -0,0 +1,26 @@
+//! Helpers for `FlexHex` and `ConstFlexHex` accepting multiple input formats.
+
+/// Describes formats accepted in addition to the inner form.
+pub(crate) const EXPECTING_SUFFIX: &str = if cfg!(feature = "base64") {
+    ", base64-encoded string, byte array or sequence of bytes"
+} else {
+    ", byte array or sequence of bytes"
+};
+
+/// Decodes a base64 string with the standard alphabet and padding. `hex_err` is the error
+/// returned by the inner form for the same string; it is included into the returned error.
+#[cfg(feature = "base64")]
+pub(crate) fn decode_base64<E: serde::de::Error>(
+    value: &str,
+    hex_err: &E,
+) -> Result<alloc::vec::Vec<u8>, E> {
+    use base64::Engine as _;
+
+    base64::engine::general_purpose::STANDARD
+        .decode(value)
+        .map_err(|base64_err| {
+            E::custom(format_args!(
+                "{hex_err}; string is not valid base64 either: {base64_err}"
+            ))
+        })
+}
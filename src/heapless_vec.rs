@@ -0,0 +1,242 @@
+//! (De)serialization for `heapless::Vec<u8, _>`.
+
+use serde::{
+    de::{Error as DeError, IgnoredAny, SeqAccess, Unexpected, Visitor},
+    Deserializer, Serializer,
+};
+
+use core::fmt;
+
+use crate::{backend, error::check_hex_len, HexBytes, HexDecodeError};
+
+/// A dummy container for use inside `#[serde(with)]` attribute for [`heapless::Vec`]`<u8, _>`
+/// from the [`heapless`](https://crates.io/crates/heapless) crate.
+///
+/// Vectors are (de)serialized in the same way as with [`HexForm`](crate::HexForm).
+/// Deserialization fails if the decoded bytes do not fit into the vector capacity.
+/// (`HexForm` cannot be used for `heapless::Vec` directly since the `TryFrom<&[u8]>`
+/// conversion for it returns a non-displayable error.)
+///
+/// The inherent [`Self::serialize()`] and [`Self::deserialize()`] methods do not allocate,
+/// so the container can be used without the `alloc` feature. If the `alloc` feature is enabled,
+/// [`Hex`](crate::Hex) is implemented for the container as well, so that it can be used
+/// in generic wrappers like [`HexVec`](crate::HexVec).
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::HeaplessHex;
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Packet {
+///     #[serde(with = "HeaplessHex")]
+///     payload: heapless::Vec<u8, 4>,
+/// }
+///
+/// let packet: Packet = serde_json::from_str(r#"{"payload":"c0ffee"}"#)?;
+/// assert_eq!(packet.payload, [0xc0, 0xff, 0xee]);
+///
+/// let err = serde_json::from_str::<Packet>(r#"{"payload":"c0ffee0000"}"#).err().unwrap();
+/// assert!(err.to_string().starts_with("expected 0 to 4 bytes, got 5"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+#[derive(Debug)]
+pub struct HeaplessHex(());
+
+impl HeaplessHex {
+    /// Serializes the vector as a lower-case hex string for [human-readable][hr] serializers,
+    /// and as a byte array for other serializers.
+    ///
+    /// [hr]: serde::Serializer::is_human_readable()
+    ///
+    /// # Errors
+    ///
+    /// Propagates serialization errors.
+    pub fn serialize<S: Serializer, const CAP: usize>(
+        value: &heapless::Vec<u8, CAP>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&HexBytes::new(value), serializer)
+    }
+
+    /// Deserializes the vector from a hex string or a byte sequence for [human-readable][hr]
+    /// deserializers, and from a byte array for other deserializers.
+    ///
+    /// [hr]: serde::Deserializer::is_human_readable()
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid hex, or if the decoded bytes do not fit
+    /// into the vector capacity.
+    pub fn deserialize<'de, D: Deserializer<'de>, const CAP: usize>(
+        deserializer: D,
+    ) -> Result<heapless::Vec<u8, CAP>, D::Error> {
+        if deserializer.is_human_readable() {
+            // We use `deserialize_any()` since the input may be a sequence of bytes
+            // rather than a string.
+            deserializer.deserialize_any(HeaplessVisitor)
+        } else {
+            deserializer.deserialize_bytes(HeaplessVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const CAP: usize> crate::Hex<heapless::Vec<u8, CAP>> for HeaplessHex {
+    type Error = crate::BoundedHexError<core::convert::Infallible>;
+
+    fn create_bytes(buffer: &heapless::Vec<u8, CAP>) -> alloc::borrow::Cow<'_, [u8]> {
+        alloc::borrow::Cow::Borrowed(buffer)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<heapless::Vec<u8, CAP>, Self::Error> {
+        heapless::Vec::from_slice(bytes).map_err(|()| crate::BoundedHexError::Length {
+            min: 0,
+            max: CAP,
+            actual: bytes.len(),
+        })
+    }
+}
+
+/// Returns the same error as `BoundedHexError::Length`, but without relying on `alloc`.
+fn length_error<E: DeError>(capacity: usize, actual: usize) -> E {
+    E::custom(format_args!("expected 0 to {capacity} bytes, got {actual}"))
+}
+
+struct HeaplessVisitor<const CAP: usize>;
+
+impl<'de, const CAP: usize> Visitor<'de> for HeaplessVisitor<CAP> {
+    type Value = heapless::Vec<u8, CAP>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "hex-encoded byte array of length at most {CAP}")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        check_hex_len(value.len())?;
+        let byte_len = value.len() / 2;
+        if byte_len > CAP {
+            return Err(length_error(CAP, byte_len));
+        }
+
+        let mut bytes = heapless::Vec::new();
+        bytes.resize_default(byte_len).unwrap();
+        // ^ `unwrap()` is safe: we've checked the length above.
+        backend::decode_to_slice(value, &mut bytes).map_err(|err| {
+            match HexDecodeError::from_hex_error(err, value, 0) {
+                Some(err) => E::custom(err),
+                None => E::invalid_type(Unexpected::Str(value), &self),
+            }
+        })?;
+        Ok(bytes)
+    }
+
+    fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
+        heapless::Vec::from_slice(value).map_err(|()| length_error(CAP, value.len()))
+    }
+
+    // Allows byte sequences produced e.g. by JavaScript `Uint8Array`s.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = heapless::Vec::new();
+        while let Some(byte) = seq.next_element::<u8>()? {
+            if bytes.push(byte).is_err() {
+                // Count the remaining elements to provide a helpful error message.
+                let mut len = CAP + 1;
+                while seq.next_element::<IgnoredAny>()?.is_some() {
+                    len += 1;
+                }
+                return Err(length_error(CAP, len));
+            }
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString;
+    use serde_derive::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct HeaplessTest {
+        #[serde(with = "HeaplessHex")]
+        buffer: heapless::Vec<u8, 4>,
+    }
+
+    #[test]
+    fn heapless_integration() {
+        let value = HeaplessTest {
+            buffer: heapless::Vec::from_slice(&[0xde, 0xad, 0xbe, 0xef]).unwrap(),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "buffer": "deadbeef" }));
+        let value_copy: HeaplessTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+        let value_copy: HeaplessTest =
+            serde_json::from_value(json!({ "buffer": [0xde, 0xad, 0xbe, 0xef] })).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert!(bytes
+            .windows(4)
+            .any(|window| window == [0xde, 0xad, 0xbe, 0xef]));
+        let value_copy: HeaplessTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_jsons = [
+            (
+                json!({ "buffer": "deadbeef00" }),
+                "expected 0 to 4 bytes, got 5",
+            ),
+            (
+                json!({ "buffer": [1, 2, 3, 4, 5, 6] }),
+                "expected 0 to 4 bytes, got 6",
+            ),
+            (json!({ "buffer": "dea" }), "hex string has odd length 3"),
+            (
+                json!({ "buffer": "de?d" }),
+                "invalid hex character '?' at position 2",
+            ),
+        ];
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<HeaplessTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.starts_with(expected_err), "{err}");
+        }
+
+        let bytes = bincode::serialize(&[0_u8; 5] as &[u8]).unwrap();
+        let err = bincode::deserialize::<HeaplessTest>(&bytes).unwrap_err();
+        assert_eq!(err.to_string(), "expected 0 to 4 bytes, got 5");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn heapless_hex_trait_impl() {
+        use alloc::{vec, vec::Vec};
+
+        use crate::{Hex, HexVec};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct VecTest {
+            #[serde(with = "HexVec::<HeaplessHex>")]
+            buffers: Vec<heapless::Vec<u8, 2>>,
+        }
+
+        let value = VecTest {
+            buffers: vec![heapless::Vec::from_slice(&[0xc0, 0xff]).unwrap()],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "buffers": ["c0ff"] }));
+        let value_copy: VecTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let err = <HeaplessHex as Hex<heapless::Vec<u8, 2>>>::from_bytes(&[0; 3]).unwrap_err();
+        assert_eq!(err.to_string(), "expected 0 to 2 bytes, got 3");
+    }
+}
@@ -0,0 +1,259 @@
+//! Streaming hex encoding and decoding.
+
+use core::{fmt, iter::FusedIterator};
+
+use crate::{HexBytes, HexDecodeError};
+
+/// Decodes a single hex digit.
+fn decode_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Iterator lazily decoding bytes from a hex string without allocations.
+///
+/// Each item corresponds to a pair of hex digits in the string. If a pair contains an invalid
+/// char, the corresponding item is an error; decoding proceeds with the next pair.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{HexDecodeError, HexDecoder};
+///
+/// let decoder = HexDecoder::new("c0FFee")?;
+/// assert_eq!(decoder.len(), 3);
+/// let bytes: Result<Vec<u8>, _> = decoder.collect();
+/// assert_eq!(bytes?, [0xc0, 0xff, 0xee]);
+///
+/// let mut decoder = HexDecoder::new("c0ffeg")?;
+/// assert_eq!(decoder.next(), Some(Ok(0xc0)));
+/// assert_eq!(decoder.next(), Some(Ok(0xff)));
+/// let err = decoder.next().unwrap().unwrap_err();
+/// assert_eq!(err.to_string(), "invalid hex character 'g' at position 5");
+///
+/// let err = HexDecoder::new("c0ffe").unwrap_err();
+/// assert_eq!(err, HexDecodeError::OddLength { len: 5 });
+/// # Ok::<_, hex_buffer_serde::HexDecodeError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct HexDecoder<'a> {
+    hex_str: &'a str,
+    position: usize,
+}
+
+impl<'a> HexDecoder<'a> {
+    /// Creates a decoder for the specified hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string has an odd length.
+    pub fn new(hex_str: &'a str) -> Result<Self, HexDecodeError> {
+        if hex_str.len() % 2 == 0 {
+            Ok(Self {
+                hex_str,
+                position: 0,
+            })
+        } else {
+            Err(HexDecodeError::odd_length(hex_str.len()))
+        }
+    }
+
+    fn invalid_char_error(&self, position: usize) -> HexDecodeError {
+        // `position` may point inside a multi-byte char; report the start of this char.
+        let start = (0..=position)
+            .rev()
+            .find(|&i| self.hex_str.is_char_boundary(i))
+            .unwrap_or(0);
+        HexDecodeError::new(start, self.hex_str.as_bytes()[start])
+    }
+}
+
+impl Iterator for HexDecoder<'_> {
+    type Item = Result<u8, HexDecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let digits = self
+            .hex_str
+            .as_bytes()
+            .get(self.position..self.position + 2)?;
+        let position = self.position;
+        self.position += 2;
+
+        let Some(high) = decode_digit(digits[0]) else {
+            return Some(Err(self.invalid_char_error(position)));
+        };
+        let Some(low) = decode_digit(digits[1]) else {
+            return Some(Err(self.invalid_char_error(position + 1)));
+        };
+        Some(Ok((high << 4) | low))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.hex_str.len() - self.position) / 2;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for HexDecoder<'_> {}
+
+impl FusedIterator for HexDecoder<'_> {}
+
+/// Writer of hex-encoded bytes into a [`fmt::Write`] sink, such as a `String`
+/// or a [`fmt::Formatter`].
+///
+/// Bytes are encoded as lower-case hex digits directly into the sink, without intermediate
+/// allocations. The encoder implements [`fmt::Write`] itself, hex-encoding the UTF-8 bytes
+/// of the written strings.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::HexEncoder;
+///
+/// let mut encoder = HexEncoder::new(String::new());
+/// encoder.write_byte(0xc0)?;
+/// encoder.write_bytes(&[0xff, 0xee])?;
+/// assert_eq!(encoder.finish(), "c0ffee");
+/// # Ok::<_, core::fmt::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct HexEncoder<W> {
+    writer: W,
+}
+
+impl<W: fmt::Write> HexEncoder<W> {
+    /// Creates an encoder writing to the specified sink.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Writes a single byte.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from the underlying writer.
+    pub fn write_byte(&mut self, byte: u8) -> fmt::Result {
+        self.write_bytes(&[byte])
+    }
+
+    /// Writes bytes.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from the underlying writer.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> fmt::Result {
+        write!(self.writer, "{}", HexBytes(bytes))
+    }
+
+    /// Returns the underlying writer.
+    pub fn finish(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for HexEncoder<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_bytes(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decoding_bytes() {
+        let bytes: [u8; 256] = {
+            let mut bytes = [0_u8; 256];
+            for (byte, i) in bytes.iter_mut().zip(0_u8..=255) {
+                *byte = i;
+            }
+            bytes
+        };
+        let mut buffer = [0_u8; 512];
+        hex::encode_to_slice(bytes, &mut buffer).unwrap();
+        let hex_str = core::str::from_utf8(&buffer).unwrap();
+
+        let decoder = HexDecoder::new(hex_str).unwrap();
+        assert!(decoder
+            .zip(bytes)
+            .all(|(decoded, byte)| decoded == Ok(byte)));
+
+        let upper_hex_str = "C0FFEE";
+        let decoder = HexDecoder::new(upper_hex_str).unwrap();
+        assert!(decoder.eq([Ok(0xc0), Ok(0xff), Ok(0xee)]));
+
+        assert_eq!(HexDecoder::new("").unwrap().next(), None);
+    }
+
+    #[test]
+    fn decoding_errors() {
+        let err = HexDecoder::new("c0ffe").unwrap_err();
+        assert_eq!(err, HexDecodeError::odd_length(5));
+
+        let mut decoder = HexDecoder::new("c0fgee").unwrap();
+        assert_eq!(decoder.next(), Some(Ok(0xc0)));
+        assert_eq!(decoder.next(), Some(Err(HexDecodeError::new(3, b'g'))));
+        // Decoding proceeds after an error.
+        assert_eq!(decoder.next(), Some(Ok(0xee)));
+        assert_eq!(decoder.next(), None);
+
+        // The `\u{fc}` char takes 2 bytes.
+        let decoder = HexDecoder::new("0\u{fc}102").unwrap();
+        let items = [
+            Err(HexDecodeError::new(1, 0xc3)),
+            Err(HexDecodeError::new(1, 0xc3)),
+            Ok(0x02),
+        ];
+        assert!(decoder.eq(items));
+    }
+
+    #[test]
+    fn encoding_bytes() {
+        use alloc::string::String;
+        use core::fmt::Write as _;
+
+        let bytes: alloc::vec::Vec<u8> = (0_u8..=255).collect();
+        let mut expected = [0_u8; 512];
+        hex::encode_to_slice(&bytes, &mut expected).unwrap();
+        let expected = core::str::from_utf8(&expected).unwrap();
+
+        let mut encoder = HexEncoder::new(String::new());
+        for &byte in &bytes {
+            encoder.write_byte(byte).unwrap();
+        }
+        assert_eq!(encoder.finish(), expected);
+
+        let mut encoder = HexEncoder::new(String::new());
+        for chunk in bytes.chunks(50) {
+            encoder.write_bytes(chunk).unwrap();
+        }
+        assert_eq!(encoder.finish(), expected);
+
+        let mut encoder = HexEncoder::new(String::from("0x"));
+        write!(encoder, "{}", 42).unwrap();
+        assert_eq!(encoder.finish(), "0x3432");
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut decoder = HexDecoder::new("c0ffee").unwrap();
+        assert_eq!(decoder.len(), 3);
+        decoder.next();
+        assert_eq!(decoder.len(), 2);
+        decoder.next();
+        decoder.next();
+        assert_eq!(decoder.len(), 0);
+        assert_eq!(decoder.next(), None);
+        assert_eq!(decoder.len(), 0);
+
+        let mut decoder = HexDecoder::new("xx00").unwrap();
+        assert_eq!(decoder.len(), 2);
+        assert!(decoder.next().unwrap().is_err());
+        assert_eq!(decoder.len(), 1);
+    }
+}
@@ -0,0 +1,104 @@
+//! Conversions between [`serde_json::Value`]s and values (de)serialized via [`Hex`].
+//!
+//! These helpers are useful if a JSON value is already parsed (e.g., in a REST API handler),
+//! and only a single hex-encoded field needs to be extracted or produced.
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_buffer_serde::{json, HexForm, PrefixedHexForm};
+//! use serde_json::json;
+//!
+//! let request = json!({ "key": "c0ffee", "nonce": "0x0102" });
+//! let key: Vec<u8> = json::from_json_hex::<_, HexForm<_>>(&request["key"])?;
+//! assert_eq!(key, [0xc0, 0xff, 0xee]);
+//! let nonce: [u8; 2] = json::from_json_hex::<_, PrefixedHexForm<_>>(&request["nonce"])?;
+//! assert_eq!(nonce, [1, 2]);
+//!
+//! let response = json::to_json_hex::<_, HexForm<_>>(&key)?;
+//! assert_eq!(response, json!("c0ffee"));
+//! # Ok::<_, serde_json::Error>(())
+//! ```
+
+use serde::de::{Error as DeError, Unexpected};
+use serde_json::Value;
+
+use crate::Hex;
+
+/// Deserializes a value from a hex-encoded JSON string via the `H` implementation.
+///
+/// # Errors
+///
+/// Returns an error if `value` is not a string, or if it cannot be deserialized via `H`
+/// (e.g., it is not a valid hex string, or the decoded bytes cannot be converted to a value).
+pub fn from_json_hex<T, H: Hex<T>>(value: &Value) -> Result<T, serde_json::Error> {
+    let unexpected = match value {
+        Value::String(_) => return H::deserialize(value),
+        Value::Null => Unexpected::Unit,
+        Value::Bool(value) => Unexpected::Bool(*value),
+        Value::Number(_) => Unexpected::Other("number"),
+        Value::Array(_) => Unexpected::Seq,
+        Value::Object(_) => Unexpected::Map,
+    };
+    Err(DeError::invalid_type(unexpected, &"hex string"))
+}
+
+/// Serializes a value into a hex-encoded JSON string via the `H` implementation.
+///
+/// # Errors
+///
+/// Returns an error if `H` fails serializing the value. This should not happen
+/// for well-behaved implementations.
+pub fn to_json_hex<T, H: Hex<T>>(value: &T) -> Result<Value, serde_json::Error> {
+    H::serialize(value, serde_json::value::Serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{string::ToString, vec::Vec};
+    use serde_json::json;
+
+    use crate::{ColonHexForm, HexForm};
+
+    #[test]
+    fn converting_json_values() {
+        let bytes: Vec<u8> = from_json_hex::<_, HexForm<_>>(&json!("c0FFee")).unwrap();
+        assert_eq!(bytes, [0xc0, 0xff, 0xee]);
+        let json = to_json_hex::<_, HexForm<_>>(&bytes).unwrap();
+        assert_eq!(json, json!("c0ffee"));
+
+        let json = to_json_hex::<_, ColonHexForm<_>>(&bytes).unwrap();
+        assert_eq!(json, json!("C0:FF:EE"));
+        let bytes_copy: Vec<u8> = from_json_hex::<_, ColonHexForm<_>>(&json).unwrap();
+        assert_eq!(bytes_copy, bytes);
+
+        let empty: Vec<u8> = from_json_hex::<_, HexForm<_>>(&json!("")).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn errors_converting_json_values() {
+        let bogus_values = [
+            (json!(null), "invalid type: null, expected hex string"),
+            (json!(42), "invalid type: number, expected hex string"),
+            (json!([1, 2]), "invalid type: sequence, expected hex string"),
+            (
+                json!({ "key": "c0ffee" }),
+                "invalid type: map, expected hex string",
+            ),
+            (json!("c0ffe"), "odd length"),
+            (json!("c0ffeg"), "invalid hex character 'g' at position 5"),
+        ];
+        for (value, expected_err) in bogus_values {
+            let err = from_json_hex::<Vec<u8>, HexForm<_>>(&value)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+
+        let err = from_json_hex::<[u8; 4], HexForm<_>>(&json!("c0ffee")).unwrap_err();
+        assert_eq!(err.to_string(), "could not convert slice to array");
+    }
+}
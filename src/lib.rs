@@ -18,15 +18,134 @@
 //! for (de)serialization with the help of the `#[serde(with)]` attribute.
 //!
 //! [`ConstHex`] is an analogue of [`Hex`] that can be used if the serialized buffer has
-//! constant length known in compile time.
+//! constant length known in compile time. [`ConstHexBridge`] allows to use a `ConstHex`
+//! implementation where a `Hex` one is expected. [`AsRefConstHexForm`] is a variation
+//! of [`ConstHexForm`] for types exposing their bytes via `AsRef<[u8; N]>`.
+//!
+//! [`UpperHexForm`] and [`UpperConstHexForm`] are variations of [`HexForm`] and [`ConstHexForm`]
+//! that serialize buffers as upper-case hex strings. [`PrefixedHexForm`], [`PrefixedUpperHexForm`]
+//! and [`PrefixedConstHexForm`] serialize buffers as `0x`-prefixed hex strings.
+//!
+//! [`LenientHexForm`] and [`LenientConstHexForm`] ignore whitespace in hex strings
+//! on deserialization (e.g., `01 02 03 04`). [`ConfiguredHexForm`] allows to combine
+//! these options (case, prefix, whitespace handling etc.) via a [`HexConfig`].
+//!
+//! [`BoundedHexForm`] checks the byte length of deserialized values against
+//! compile-time bounds; [`ExactLenHex`] requires an exact byte length for values
+//! (de)serialized with an arbitrary [`Hex`] implementation, and [`PadHex`] left-pads
+//! deserialized bytes with zeros to the specified length.
+//! [`ValidatedHex`] checks deserialized bytes with a custom [`BytesValidator`].
+//!
+//! [`ColonHexForm`] and [`ColonConstHexForm`] serialize buffers as colon-separated
+//! upper-case hex pairs (e.g., `01:AB:CD`), as is customary for MAC addresses.
+//! [`CustomAlphabetHex`] encodes nibbles with a custom [`HexAlphabet`].
+//! [`Utf8HexForm`] (de)serializes strings as hex-encoded UTF-8 bytes.
+//!
+//! [`FlexHex`] and [`ConstFlexHex`] accept hex strings, byte arrays, byte sequences
+//! and (with the `base64` feature) base64 strings on deserialization, which is useful
+//! when ingesting data from external sources. Conversely, [`StrictHex`] and [`StrictConstHex`]
+//! only accept lower-case hex strings.
+//!
+//! [`HexOption`] and [`ConstHexOption`] allow to (de)serialize optional values
+//! using a [`Hex`] / [`ConstHex`] implementation for the underlying type. Similarly,
+//! [`HexVec`] and [`ConstHexVec`] allow to (de)serialize vectors elementwise, [`HexPair`]
+//! and [`ConstHexPair`] do the same for pairs, and [`HexPtr`]
+//! handles smart pointers (`Box`, `Rc` and `Arc`). [`ConstHexConcat`] serializes a vector
+//! of constant-length values as a single concatenated hex string; [`ConstHexConcatPair`]
+//! does the same for pairs.
+//!
+//! The [`hex_bytes`](mod@hex_bytes) and [`upper_hex_bytes`] modules provide free functions usable
+//! with `#[serde(with)]` for byte vectors, without the need to specify a form.
+//! [`const_hex_bytes`] and [`upper_const_hex_bytes`] are their analogues for byte arrays.
+//! [`serialize_hex()`] / [`deserialize_hex()`] (and their const-length analogues) wrap
+//! an arbitrary form and can be used separately with `#[serde(serialize_with)]`
+//! or `#[serde(deserialize_with)]`.
+//!
+//! [`HexBuf`] is a wrapper type implementing `Serialize` / `Deserialize` via a [`Hex`]
+//! implementation; it can be used as a field type without `#[serde(with)]` annotations.
+//! [`ConstHexBuf`] is its analogue for [`ConstHex`].
+//!
+//! [`ByteOrderConstHexForm`] implements [`ConstHex`] for integer primitives, so that integers
+//! can be (de)serialized as fixed-width hex strings with the specified byte order.
+//! [`BigEndianU64`], [`LittleEndianU64`] and similar aliases are provided for convenience.
+//! `f32` and `f64` are supported as well and are encoded via their IEEE 754 bit patterns.
+//! Similarly, [`IpConstHexForm`] implements [`ConstHex`] for IPv4 and IPv6 addresses,
+//! and [`MacAddressForm`] serializes 6-byte MAC addresses in the colon-separated form.
+//!
+//! [`HexBytes`] and [`ConstHexBytes`] are serialize-only wrappers for borrowed byte slices
+//! and arrays that do not allocate.
+//!
+//! [`ForceHexStr`] and [`ForceConstHexStr`] wrap a [`Hex`] / [`ConstHex`] implementation
+//! and always (de)serialize values as hex strings, even for non-human-readable formats.
+//! Conversely, [`ForceBinary`] and [`ForceConstBinary`] always (de)serialize values
+//! as byte arrays, even for human-readable formats. [`ConstHexOrBytes`] accepts both hex strings
+//! and byte sequences on deserialization, which helps migrating from a raw-bytes presentation.
+//!
+//! [`HexVisitor`] and [`BytesVisitor`] (and their const-length analogues, [`ConstHexVisitor`]
+//! and [`ConstBytesVisitor`]) are the `serde` visitors used by the forms; they can be embedded
+//! into custom `Deserialize` implementations.
+//!
+//! The [`codec`] module provides free functions to encode and decode hex strings
+//! without going through `serde`, reporting errors via [`HexDecodeError`].
+//! [`HexDecoder`] lazily decodes bytes from a hex string without allocations, and
+//! [`HexEncoder`] writes hex-encoded bytes to a [`fmt::Write`](core::fmt::Write) sink.
+//! [`ConstHexWriter`] is a no-alloc analogue of `HexEncoder` writing to a stack buffer.
 //!
 //! # Crate Features
 //!
 //! - `alloc` (enabled by default). Enables types that depend on the `alloc` crate:
-//!   [`Hex`] and [`HexForm`].
+//!   [`Hex`] and the corresponding forms, such as [`HexForm`].
 //! - `const_len` (disabled by default). Enables types that depend on const generics:
-//!   [`ConstHex`] and [`ConstHexForm`].
+//!   [`ConstHex`] and the corresponding forms, such as [`ConstHexForm`].
+//! - `const_fn_encode` (disabled by default; implies `const_len`). Exposes
+//!   [`codec::encode_to_array()`] and the [`const_hex!`] macro allowing to hex-encode byte arrays
+//!   in compile time.
+//! - `hex_macro` (disabled by default; implies `const_fn_encode`). Exposes
+//!   [`codec::decode_to_array()`] and the [`compile_hex!`], [`hex_bytes!`] and [`const_hex_str!`]
+//!   macros allowing to embed hex literals decoded in compile time.
+//! - `bytemuck` (disabled by default; implies `const_len`). Enables [`PodHex`] allowing to
+//!   (de)serialize [`Pod`](bytemuck::Pod) types from the [`bytemuck`](https://crates.io/crates/bytemuck)
+//!   crate via their byte representation.
+//! - `bytes` (disabled by default; implies `alloc`). Enables [`BytesHex`] allowing to
+//!   (de)serialize [`Bytes`](bytes::Bytes) from the [`bytes`](https://crates.io/crates/bytes)
+//!   crate.
+//! - `generic-array` (disabled by default; implies `const_len`). Allows to (de)serialize
+//!   `GenericArray<u8, _>` from the [`generic-array`](https://crates.io/crates/generic-array)
+//!   crate with [`ConstHexForm`].
+//! - `heapless` (disabled by default). Enables [`HeaplessHex`] allowing to (de)serialize
+//!   [`heapless::Vec`] from the [`heapless`](https://crates.io/crates/heapless) crate.
+//!   Does not require the `alloc` feature.
+//! - `wasm-bindgen` (disabled by default; implies `alloc`). Enables [`Uint8ArrayHex`] allowing to
+//!   (de)serialize [`Uint8Array`](js_sys::Uint8Array)s from the [`js-sys`](https://crates.io/crates/js-sys)
+//!   crate in WASM.
+//! - `derive` (disabled by default; implies `alloc`). Enables the [`HexProxy`] derive macro
+//!   and the [`hex_proxy`](macro@hex_proxy) attribute macro generating [`Hex`] implementations
+//!   for proxy types.
+//! - `uuid` (disabled by default; implies `const_len`). Enables [`UuidHex`] and [`HyphenatedUuidHex`]
+//!   allowing to (de)serialize [`Uuid`](uuid::Uuid)s from the [`uuid`](https://crates.io/crates/uuid)
+//!   crate as compact and hyphenated hex strings respectively.
+//! - `zeroize` (disabled by default). Enables [`ZeroizeHexForm`] and [`ZeroizeConstHexForm`]
+//!   that zeroize intermediate decoded bytes on deserialization using
+//!   the [`zeroize`](https://crates.io/crates/zeroize) crate.
+//! - `serde_with` (disabled by default). Implements [`SerializeAs`] / [`DeserializeAs`] traits
+//!   from the [`serde_with`](https://crates.io/crates/serde_with) crate for the forms
+//!   (e.g., `HexForm<_>` or `ConstHexForm<_>`), so that they can be used in `#[serde_as]`
+//!   annotations. Custom implementations can be used with `#[serde_as]` via the [`HexAs`]
+//!   and [`ConstHexAs`] adapters.
+//! - `simd` (disabled by default). Uses the SIMD-accelerated [`const-hex`](https://crates.io/crates/const-hex)
+//!   crate for hex encoding and decoding instead of [`hex`](https://crates.io/crates/hex).
+//!   This only affects performance; outputs and errors are the same. On targets without
+//!   SIMD support, `const-hex` falls back to a scalar implementation.
+//! - `serde_json` (disabled by default; implies `alloc`). Enables the [`json`] module
+//!   with conversions between [`serde_json::Value`]s and values (de)serialized via [`Hex`].
+//! - `testing` (disabled by default; implies `alloc`). Enables the [`testing`] module
+//!   with helpers for testing [`Hex`] implementations.
+//! - `base64` (disabled by default; implies `alloc`). Makes [`FlexHex`] and [`ConstFlexHex`]
+//!   accept base64-encoded strings on deserialization using
+//!   the [`base64`](https://crates.io/crates/base64) crate.
 //!
+//! [`SerializeAs`]: serde_with::SerializeAs
+//! [`DeserializeAs`]: serde_with::DeserializeAs
 //! [`sodiumoxide`]: https://crates.io/crates/sodiumoxide
 //!
 //! # Examples
@@ -129,14 +248,113 @@
 #[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+mod alphabet;
+mod backend;
+mod borrowed;
+pub mod codec;
+mod colon;
+mod error;
+mod flex;
+#[cfg(feature = "heapless")]
+mod heapless_vec;
+mod iter;
+#[cfg(feature = "serde_json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+pub mod json;
+#[cfg(feature = "hex_macro")]
+mod macros;
+#[cfg(feature = "heapless")]
+pub use self::heapless_vec::HeaplessHex;
+pub use self::{
+    borrowed::HexBytes,
+    error::{HexDecodeError, ParseHexError},
+    iter::{HexDecoder, HexEncoder},
+};
+
 #[cfg(feature = "const_len")]
 mod const_len;
 #[cfg(feature = "const_len")]
-pub use self::const_len::{ConstHex, ConstHexForm};
+pub use self::const_len::{
+    const_hex_bytes, deserialize_const_hex, serialize_const_hex, upper_const_hex_bytes,
+};
+#[cfg(feature = "const_len")]
+pub use self::const_len::{
+    AsRefConstHexForm, ColonConstHexForm, ConstBytesVisitor, ConstFlexHex, ConstHex, ConstHexBuf,
+    ConstHexBytes, ConstHexForm, ConstHexOption, ConstHexOrBytes, ConstHexPair, ConstHexVisitor,
+    ConstHexWriter, ForceConstBinary, ForceConstHexStr, LenientConstHexForm, PrefixedConstHexForm,
+    StrictConstHex, UpperConstHexForm,
+};
+#[cfg(feature = "derive")]
+pub use hex_buffer_serde_derive::{hex_proxy, HexProxy};
+
+#[cfg(feature = "const_len")]
+mod numeric;
+#[cfg(feature = "const_len")]
+pub use self::numeric::{
+    BigEndian, BigEndianConstHexForm, BigEndianF32, BigEndianF64, BigEndianI128, BigEndianI16,
+    BigEndianI32, BigEndianI64, BigEndianI8, BigEndianU128, BigEndianU16, BigEndianU32,
+    BigEndianU64, BigEndianU8, ByteOrder, ByteOrderConstHexForm, LittleEndian,
+    LittleEndianConstHexForm, LittleEndianF32, LittleEndianF64, LittleEndianI128, LittleEndianI16,
+    LittleEndianI32, LittleEndianI64, LittleEndianI8, LittleEndianU128, LittleEndianU16,
+    LittleEndianU32, LittleEndianU64, LittleEndianU8,
+};
+
+#[cfg(feature = "const_len")]
+mod net;
+#[cfg(feature = "const_len")]
+pub use self::net::{IpConstHexForm, MacAddressForm};
+
+#[cfg(feature = "serde_with")]
+mod serde_as;
+#[cfg(feature = "alloc")]
+pub use self::alphabet::{CustomAlphabetHex, HexAlphabet, LowerCaseAlphabet, UpperCaseAlphabet};
+#[cfg(feature = "bytemuck")]
+pub use self::const_len::PodHex;
+#[cfg(all(feature = "zeroize", feature = "const_len"))]
+pub use self::const_len::ZeroizeConstHexForm;
+#[cfg(all(feature = "const_len", feature = "alloc"))]
+pub use self::const_len::{
+    ConstHexBridge, ConstHexBridgeError, ConstHexConcat, ConstHexConcatPair, ConstHexVec,
+};
+#[cfg(feature = "uuid")]
+pub use self::const_len::{HyphenatedUuidHex, UuidHex};
+#[cfg(all(feature = "serde_with", feature = "const_len"))]
+pub use self::serde_as::ConstHexAs;
+#[cfg(all(feature = "serde_with", feature = "alloc"))]
+pub use self::serde_as::HexAs;
+#[cfg(all(feature = "alloc", any(test, feature = "testing")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 #[cfg(feature = "alloc")]
 mod var_len;
+#[cfg(feature = "bytes")]
+pub use self::var_len::BytesHex;
+#[cfg(feature = "wasm-bindgen")]
+pub use self::var_len::Uint8ArrayHex;
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+pub use self::var_len::ZeroizeHexForm;
+#[cfg(feature = "alloc")]
+pub use self::var_len::{deserialize_hex, hex_bytes, serialize_hex, upper_hex_bytes};
 #[cfg(feature = "alloc")]
-pub use self::var_len::{Hex, HexForm};
+pub use self::var_len::{
+    BoundedHexError, BoundedHexForm, BytesValidator, BytesVisitor, ColonHexForm, ConfiguredHexForm,
+    DefaultHexConfig, ExactLenHex, FlexHex, ForceBinary, ForceHexStr, Hex, HexBuf, HexConfig,
+    HexForm, HexOption, HexPair, HexPtr, HexVec, HexVisitor, LenientHexConfig, LenientHexForm,
+    PadHex, PrefixedHexConfig, PrefixedHexForm, PrefixedUpperHexConfig, PrefixedUpperHexForm,
+    StrictHex, UpperHexConfig, UpperHexForm, Utf8HexError, Utf8HexForm, ValidatedHex,
+    ValidatedHexError,
+};
+
+/// Re-exports used by the macros. Not a part of the public API.
+#[cfg(any(feature = "alloc", feature = "hex_macro"))]
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "hex_macro")]
+    pub use crate::macros::decoded_len;
+    #[cfg(feature = "alloc")]
+    pub use alloc::borrow::Cow;
+}
 
 #[cfg(not(any(feature = "const_len", feature = "alloc")))]
 compile_error!(
@@ -0,0 +1,222 @@
+//! Macros for hex literals decoded in compile time.
+
+/// Counts hex digits in `hex_str`, skipping ASCII whitespace. Other chars are not checked.
+const fn count_digits(hex_str: &str) -> usize {
+    let chars = hex_str.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].is_ascii_whitespace() {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Returns the number of bytes encoded by `hex_str`. Used by `compile_hex!` to determine
+/// the output array length.
+#[doc(hidden)] // only used by the macros
+pub const fn decoded_len(hex_str: &str) -> usize {
+    count_digits(hex_str) / 2
+}
+
+const fn decode_nibble(ch: u8) -> u8 {
+    match ch {
+        b'0'..=b'9' => ch - b'0',
+        b'a'..=b'f' => ch - b'a' + 10,
+        b'A'..=b'F' => ch - b'A' + 10,
+        _ => panic!("invalid hex character"),
+    }
+}
+
+/// Hex-decodes `hex_str` into a byte array in compile time. Both lower-case and upper-case
+/// hex digits are accepted; ASCII whitespace between digits is ignored.
+///
+/// This is the inverse of [`encode_to_array()`](crate::codec::encode_to_array); see
+/// [`compile_hex!`](macro@crate::compile_hex) for a more convenient wrapper.
+///
+/// # Panics
+///
+/// Panics if `hex_str` contains invalid chars, has an odd number of hex digits, or if `N`
+/// is not equal to half of the number of hex digits. If the function is evaluated
+/// in compile time, this results in a compilation error.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::codec::decode_to_array;
+///
+/// const KEY: [u8; 4] = decode_to_array("dead BEEF");
+/// assert_eq!(KEY, [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "hex_macro")))]
+pub const fn decode_to_array<const N: usize>(hex_str: &str) -> [u8; N] {
+    let digit_count = count_digits(hex_str);
+    assert!(
+        digit_count % 2 == 0,
+        "hex string has an odd number of hex digits"
+    );
+    assert!(
+        digit_count == 2 * N,
+        "output length must be half the number of hex digits"
+    );
+
+    let chars = hex_str.as_bytes();
+    let mut output = [0_u8; N];
+    let mut digit_idx = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        let ch = chars[i];
+        i += 1;
+        if ch.is_ascii_whitespace() {
+            continue;
+        }
+
+        let nibble = decode_nibble(ch);
+        if digit_idx % 2 == 0 {
+            output[digit_idx / 2] = nibble << 4;
+        } else {
+            output[digit_idx / 2] |= nibble;
+        }
+        digit_idx += 1;
+    }
+    output
+}
+
+/// Decodes a hex string literal (or another `&'static str` constant) into a byte array
+/// in compile time.
+///
+/// Both lower-case and upper-case hex digits are accepted; ASCII whitespace between digits
+/// is ignored. Invalid chars or an odd number of hex digits result in a compilation error.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::compile_hex;
+///
+/// const KEY: [u8; 4] = compile_hex!("0102abcd");
+/// assert_eq!(KEY, [0x01, 0x02, 0xab, 0xcd]);
+/// assert_eq!(compile_hex!("c0 FF ee"), [0xc0, 0xff, 0xee]);
+/// ```
+///
+/// Invalid hex strings are rejected in compile time:
+///
+/// ```compile_fail
+/// # use hex_buffer_serde::compile_hex;
+/// let bytes = compile_hex!("c0ffe");
+/// ```
+///
+/// ```compile_fail
+/// # use hex_buffer_serde::compile_hex;
+/// let bytes = compile_hex!("c0ffeg");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "hex_macro")))]
+#[macro_export]
+macro_rules! compile_hex {
+    ($hex_str:expr) => {{
+        const HEX_STR: &str = $hex_str;
+        const BYTES: [u8; $crate::__private::decoded_len(HEX_STR)] =
+            $crate::codec::decode_to_array(HEX_STR);
+        BYTES
+    }};
+}
+
+/// Decodes space-separated hex digits into a byte array in compile time.
+///
+/// This is a version of [`compile_hex!`](macro@crate::compile_hex) accepting unquoted tokens.
+/// Note that some digit combinations (e.g., `1e` or `0b`) are not valid Rust tokens;
+/// such inputs can be decoded with `compile_hex!` instead.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::hex_bytes;
+///
+/// const KEY: [u8; 4] = hex_bytes!(01 02 ab cd);
+/// assert_eq!(KEY, [0x01, 0x02, 0xab, 0xcd]);
+/// assert_eq!(hex_bytes!(c0ffee), [0xc0, 0xff, 0xee]);
+/// ```
+///
+/// Invalid hex digits are rejected in compile time:
+///
+/// ```compile_fail
+/// # use hex_buffer_serde::hex_bytes;
+/// let bytes = hex_bytes!(01 02 g0);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "hex_macro")))]
+#[macro_export]
+macro_rules! hex_bytes {
+    ($($digits:tt)*) => {
+        $crate::compile_hex!(::core::stringify!($($digits)*))
+    };
+}
+
+/// Hex-encodes comma-separated bytes in compile time, producing a `&'static str`.
+///
+/// This is a shortcut for [`const_hex!`](macro@crate::const_hex) with an array literal.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::const_hex_str;
+///
+/// const KEY_HEX: &str = const_hex_str!(0x01, 0x02, 0xab, 0xcd);
+/// assert_eq!(KEY_HEX, "0102abcd");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "hex_macro")))]
+#[macro_export]
+macro_rules! const_hex_str {
+    ($($byte:expr),* $(,)?) => {
+        $crate::const_hex!([$($byte),*])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decoding_arrays() {
+        assert_eq!(decode_to_array::<0>(""), [0_u8; 0]);
+        assert_eq!(decode_to_array::<0>(" \n"), [0_u8; 0]);
+        assert_eq!(decode_to_array::<2>("0aF9"), [0x0a, 0xf9]);
+        assert_eq!(decode_to_array::<2>("\t0a f9\n"), [0x0a, 0xf9]);
+        assert_eq!(decoded_len("0a f9 "), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "odd number of hex digits")]
+    fn decoding_odd_length_string() {
+        decode_to_array::<1>("c0f");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hex character")]
+    fn decoding_invalid_char() {
+        decode_to_array::<2>("c0fg");
+    }
+
+    #[test]
+    #[should_panic(expected = "output length must be half the number of hex digits")]
+    fn decoding_with_incorrect_length() {
+        decode_to_array::<3>("c0ff");
+    }
+
+    #[test]
+    fn hex_literal_macros() {
+        const KEY: [u8; 4] = compile_hex!("0102abcd");
+        assert_eq!(KEY, [0x01, 0x02, 0xab, 0xcd]);
+        assert_eq!(compile_hex!(""), [0_u8; 0]);
+        assert_eq!(compile_hex!("C0 ff EE"), [0xc0, 0xff, 0xee]);
+
+        let tokens: [u8; 4] = hex_bytes!(01 02 ab cd);
+        assert_eq!(tokens, KEY);
+        assert_eq!(hex_bytes!(c0ffee 00), [0xc0, 0xff, 0xee, 0]);
+        assert_eq!(hex_bytes!(FF 9a), [0xff, 0x9a]);
+
+        assert_eq!(const_hex_str!(0x01, 0x02, 0xab, 0xcd), "0102abcd");
+        assert_eq!(const_hex_str!(0xc0, 0xff, 0xee,), "c0ffee");
+        assert_eq!(const_hex_str!(), "");
+    }
+}
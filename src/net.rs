@@ -0,0 +1,216 @@
+//! `ConstHex` implementations for network addresses.
+
+use core::{
+    convert::Infallible,
+    marker::PhantomData,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+use serde::{Deserializer, Serializer};
+
+use crate::{ColonConstHexForm, ConstHex};
+
+/// [`ConstHex`] implementation for IP addresses ([`Ipv4Addr`] and [`Ipv6Addr`]) encoding them
+/// as their octets (4 and 16 bytes respectively).
+///
+/// This is a separate form rather than an implementation for [`ConstHexForm`](crate::ConstHexForm)
+/// because the latter is already generic over types convertible to and from byte arrays.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, IpConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use std::net::{Ipv4Addr, Ipv6Addr};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Peer {
+///     #[serde(with = "IpConstHexForm::<Ipv4Addr>")]
+///     v4: Ipv4Addr,
+///     #[serde(with = "IpConstHexForm::<Ipv6Addr>")]
+///     v6: Ipv6Addr,
+/// }
+///
+/// let peer = Peer { v4: Ipv4Addr::LOCALHOST, v6: Ipv6Addr::LOCALHOST };
+/// let json = serde_json::to_string(&peer)?;
+/// assert_eq!(
+///     json,
+///     r#"{"v4":"7f000001","v6":"00000000000000000000000000000001"}"#
+/// );
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct IpConstHexForm<T>(PhantomData<T>);
+
+impl ConstHex<Ipv4Addr, 4> for IpConstHexForm<Ipv4Addr> {
+    type Error = Infallible;
+
+    fn create_bytes(value: &Ipv4Addr) -> [u8; 4] {
+        value.octets()
+    }
+
+    fn from_bytes(bytes: [u8; 4]) -> Result<Ipv4Addr, Self::Error> {
+        Ok(Ipv4Addr::from(bytes))
+    }
+}
+
+impl ConstHex<Ipv6Addr, 16> for IpConstHexForm<Ipv6Addr> {
+    type Error = Infallible;
+
+    fn create_bytes(value: &Ipv6Addr) -> [u8; 16] {
+        value.octets()
+    }
+
+    fn from_bytes(bytes: [u8; 16]) -> Result<Ipv6Addr, Self::Error> {
+        Ok(Ipv6Addr::from(bytes))
+    }
+}
+
+/// [`ConstHex`] implementation for MAC addresses represented as `[u8; 6]`.
+///
+/// For [human-readable][hr] serializers, addresses are serialized as colon-separated
+/// upper-case hex pairs (e.g., `01:23:45:67:89:AB`). On deserialization, both colon-separated
+/// and bare hex strings (e.g., `0123456789ab`) are accepted; hex digits are case-insensitive.
+/// Binary serializers store addresses as 6 raw bytes.
+///
+/// This is a shortcut for [`ColonConstHexForm`] specialized for 6-byte arrays.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex as _, MacAddressForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Interface {
+///     #[serde(with = "MacAddressForm")]
+///     mac: [u8; 6],
+/// }
+///
+/// let interface = Interface { mac: [0x01, 0x23, 0x45, 0x67, 0x89, 0xab] };
+/// let json = serde_json::to_string(&interface)?;
+/// assert_eq!(json, r#"{"mac":"01:23:45:67:89:AB"}"#);
+///
+/// let bare: Interface = serde_json::from_str(r#"{"mac":"0123456789ab"}"#)?;
+/// assert_eq!(bare, interface);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct MacAddressForm(());
+
+impl ConstHex<[u8; 6], 6> for MacAddressForm {
+    type Error = Infallible;
+
+    fn create_bytes(value: &[u8; 6]) -> [u8; 6] {
+        *value
+    }
+
+    fn from_bytes(bytes: [u8; 6]) -> Result<[u8; 6], Self::Error> {
+        Ok(bytes)
+    }
+
+    fn serialize<S: Serializer>(value: &[u8; 6], serializer: S) -> Result<S::Ok, S::Error> {
+        ColonConstHexForm::serialize(value, serializer)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<[u8; 6], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ColonConstHexForm::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString;
+    use serde_derive::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Peer {
+        #[serde(with = "IpConstHexForm::<Ipv4Addr>")]
+        v4: Ipv4Addr,
+        #[serde(with = "IpConstHexForm::<Ipv6Addr>")]
+        v6: Ipv6Addr,
+    }
+
+    #[test]
+    fn serializing_ip_addresses() {
+        let peer: Peer = serde_json::from_value(json!({
+            "v4": "7f000001",
+            "v6": "20010db8000000000000000000000001",
+        }))
+        .unwrap();
+        assert_eq!(peer.v4, Ipv4Addr::LOCALHOST);
+        assert_eq!(peer.v6, Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+
+        let json = serde_json::to_value(&peer).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "v4": "7f000001",
+                "v6": "20010db8000000000000000000000001",
+            })
+        );
+
+        let bytes = bincode::serialize(&peer).unwrap();
+        assert_eq!(bytes[8..12], [127, 0, 0, 1]);
+        let peer_copy: Peer = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(peer_copy, peer);
+    }
+
+    #[test]
+    fn serializing_mac_addresses() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Interface {
+            #[serde(with = "MacAddressForm")]
+            mac: [u8; 6],
+        }
+
+        let interface = Interface {
+            mac: [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e],
+        };
+        let json = serde_json::to_value(&interface).unwrap();
+        assert_eq!(json, json!({ "mac": "00:1A:2B:3C:4D:5E" }));
+        let interface_copy: Interface = serde_json::from_value(json).unwrap();
+        assert_eq!(interface_copy, interface);
+
+        for input in ["001a2b3c4d5e", "001A2B3C4D5E", "00:1a:2b:3c:4d:5e"] {
+            let interface_copy: Interface =
+                serde_json::from_value(json!({ "mac": input })).unwrap();
+            assert_eq!(interface_copy, interface);
+        }
+
+        let bytes = bincode::serialize(&interface).unwrap();
+        assert_eq!(bytes[8..], interface.mac);
+        let interface_copy: Interface = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(interface_copy, interface);
+
+        let bogus_macs = [
+            ("001:a2:b3:c4:d5:e", "expected colon-separated hex string"),
+            ("00:1a:2b:3c:4d5e", "expected colon-separated hex string"),
+            ("00:1a:2b:3c:4d:5e:", "expected colon-separated hex string"),
+            (":00:1a:2b:3c:4d:5e", "expected colon-separated hex string"),
+            ("00::1a:2b:3c:4d:5e", "expected colon-separated hex string"),
+            ("00:1a:2b:3c:4d", "invalid length 5"),
+            ("00:1a:2b:3c:4d:5e:6f", "invalid length 7"),
+            (
+                "00:1a:2b:3c:4d:zz",
+                "invalid hex character 'z' at position 15",
+            ),
+        ];
+        for (bogus_mac, expected_err) in bogus_macs {
+            let err = serde_json::from_value::<Interface>(json!({ "mac": bogus_mac }))
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+    }
+}
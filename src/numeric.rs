@@ -0,0 +1,350 @@
+//! `ConstHex` implementations for integer and floating-point primitives.
+
+use core::{convert::Infallible, marker::PhantomData};
+
+use crate::ConstHex;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Byte order used by [`ByteOrderConstHexForm`]. This trait is sealed; it is implemented
+/// only for [`BigEndian`] and [`LittleEndian`].
+pub trait ByteOrder: private::Sealed {
+    /// Is this order big-endian?
+    const IS_BIG_ENDIAN: bool;
+}
+
+/// Big-endian [`ByteOrder`]: the most significant byte goes first.
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub enum BigEndian {}
+
+impl private::Sealed for BigEndian {}
+
+impl ByteOrder for BigEndian {
+    const IS_BIG_ENDIAN: bool = true;
+}
+
+/// Little-endian [`ByteOrder`]: the least significant byte goes first.
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub enum LittleEndian {}
+
+impl private::Sealed for LittleEndian {}
+
+impl ByteOrder for LittleEndian {
+    const IS_BIG_ENDIAN: bool = false;
+}
+
+/// [`ConstHex`] implementation for integer primitives (`u8`, `u16`, `u32`, `u64`, `u128`
+/// and their signed counterparts) encoding them as byte arrays with the specified [`ByteOrder`].
+/// Signed integers are encoded in two's complement form.
+///
+/// `f32` and `f64` are supported as well; they are encoded via their IEEE 754 bit patterns,
+/// so that all values (including NaN payloads and signed zeros) round-trip exactly.
+///
+/// Usually, it is more convenient to use [`BigEndianConstHexForm`] / [`LittleEndianConstHexForm`]
+/// aliases, or integer-specific aliases like [`BigEndianU64`].
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{BigEndianConstHexForm, ConstHex as _, LittleEndianConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Header {
+///     #[serde(with = "BigEndianConstHexForm::<u32>")]
+///     magic: u32,
+///     #[serde(with = "LittleEndianConstHexForm::<u32>")]
+///     len: u32,
+/// }
+///
+/// let header = Header { magic: 0x0102_0304, len: 0x0102_0304 };
+/// let json = serde_json::to_string(&header)?;
+/// assert_eq!(json, r#"{"magic":"01020304","len":"04030201"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "const_len")))]
+#[derive(Debug)]
+pub struct ByteOrderConstHexForm<T, O: ByteOrder>(PhantomData<(T, O)>);
+
+/// [`ByteOrderConstHexForm`] using the big-endian byte order.
+pub type BigEndianConstHexForm<T> = ByteOrderConstHexForm<T, BigEndian>;
+/// [`ByteOrderConstHexForm`] using the little-endian byte order.
+pub type LittleEndianConstHexForm<T> = ByteOrderConstHexForm<T, LittleEndian>;
+
+macro_rules! impl_int_forms {
+    ($($int:ty => $len:expr, $be_form:ident, $le_form:ident);+ $(;)?) => {
+        $(
+        impl<O: ByteOrder> ConstHex<$int, $len> for ByteOrderConstHexForm<$int, O> {
+            type Error = Infallible;
+
+            fn create_bytes(value: &$int) -> [u8; $len] {
+                if O::IS_BIG_ENDIAN {
+                    value.to_be_bytes()
+                } else {
+                    value.to_le_bytes()
+                }
+            }
+
+            fn from_bytes(bytes: [u8; $len]) -> Result<$int, Self::Error> {
+                Ok(if O::IS_BIG_ENDIAN {
+                    <$int>::from_be_bytes(bytes)
+                } else {
+                    <$int>::from_le_bytes(bytes)
+                })
+            }
+        }
+
+        #[doc = concat!(
+            "[`ConstHex`] implementation for `", stringify!($int), "` encoding it ",
+            "as a big-endian ", stringify!($len), "-byte array."
+        )]
+        pub type $be_form = BigEndianConstHexForm<$int>;
+
+        #[doc = concat!(
+            "[`ConstHex`] implementation for `", stringify!($int), "` encoding it ",
+            "as a little-endian ", stringify!($len), "-byte array."
+        )]
+        pub type $le_form = LittleEndianConstHexForm<$int>;
+        )+
+    };
+}
+
+impl_int_forms!(
+    u8 => 1, BigEndianU8, LittleEndianU8;
+    u16 => 2, BigEndianU16, LittleEndianU16;
+    u32 => 4, BigEndianU32, LittleEndianU32;
+    u64 => 8, BigEndianU64, LittleEndianU64;
+    u128 => 16, BigEndianU128, LittleEndianU128;
+    i8 => 1, BigEndianI8, LittleEndianI8;
+    i16 => 2, BigEndianI16, LittleEndianI16;
+    i32 => 4, BigEndianI32, LittleEndianI32;
+    i64 => 8, BigEndianI64, LittleEndianI64;
+    i128 => 16, BigEndianI128, LittleEndianI128;
+    f32 => 4, BigEndianF32, LittleEndianF32;
+    f64 => 8, BigEndianF64, LittleEndianF64;
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString;
+    use serde_derive::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Block {
+        #[serde(with = "BigEndianU64")]
+        height: u64,
+        #[serde(with = "LittleEndianU32")]
+        nonce: u32,
+        #[serde(with = "BigEndianU16")]
+        version: u16,
+        #[serde(with = "BigEndianU128")]
+        work: u128,
+    }
+
+    #[test]
+    fn serializing_integers() {
+        let block = Block {
+            height: 1,
+            nonce: 0x0102_0304,
+            version: 0xabcd,
+            work: u128::MAX - 1,
+        };
+        let json = serde_json::to_value(&block).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "height": "0000000000000001",
+                "nonce": "04030201",
+                "version": "abcd",
+                "work": "fffffffffffffffffffffffffffffffe",
+            })
+        );
+        let block_copy: Block = serde_json::from_value(json).unwrap();
+        assert_eq!(block_copy, block);
+
+        let bytes = bincode::serialize(&block).unwrap();
+        let block_copy: Block = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(block_copy, block);
+    }
+
+    #[test]
+    fn byte_order_forms() {
+        let value = 0x0102_0304_u32;
+        let be_bytes = BigEndianConstHexForm::<u32>::create_bytes(&value);
+        let le_bytes = LittleEndianConstHexForm::<u32>::create_bytes(&value);
+        assert_eq!(be_bytes, [1, 2, 3, 4]);
+        assert_eq!(le_bytes, [4, 3, 2, 1]);
+        assert_eq!(u32::from_le_bytes(le_bytes), value);
+
+        let restored = LittleEndianConstHexForm::<u32>::from_bytes(le_bytes).unwrap();
+        assert_eq!(restored, value);
+        let misread = BigEndianConstHexForm::<u32>::from_bytes(le_bytes).unwrap();
+        assert_eq!(misread, 0x0403_0201);
+
+        let json =
+            BigEndianConstHexForm::<u32>::serialize(&value, serde_json::value::Serializer).unwrap();
+        assert_eq!(json, "01020304");
+        let json =
+            LittleEndianConstHexForm::<u32>::serialize(&value, serde_json::value::Serializer)
+                .unwrap();
+        assert_eq!(json, "04030201");
+    }
+
+    #[test]
+    fn big_endian_aliases() {
+        use serde_json::value::Serializer;
+
+        assert_eq!(BigEndianU8::serialize(&1, Serializer).unwrap(), "01");
+        assert_eq!(BigEndianU16::serialize(&1, Serializer).unwrap(), "0001");
+        assert_eq!(BigEndianU32::serialize(&1, Serializer).unwrap(), "00000001");
+        assert_eq!(
+            BigEndianU64::serialize(&1, Serializer).unwrap(),
+            "0000000000000001"
+        );
+        assert_eq!(
+            BigEndianU128::serialize(&1, Serializer).unwrap(),
+            "00000000000000000000000000000001"
+        );
+
+        assert_eq!(BigEndianU8::deserialize(json!("ff")).unwrap(), u8::MAX);
+        assert_eq!(BigEndianU16::deserialize(json!("0102")).unwrap(), 0x0102);
+        assert_eq!(BigEndianU32::deserialize(json!("00000001")).unwrap(), 1_u32);
+        assert_eq!(
+            BigEndianU64::deserialize(json!("0102030405060708")).unwrap(),
+            0x0102_0304_0506_0708_u64
+        );
+        assert_eq!(
+            BigEndianU128::deserialize(json!("ffffffffffffffffffffffffffffffff")).unwrap(),
+            u128::MAX
+        );
+        assert_eq!(LittleEndianU8::deserialize(json!("2a")).unwrap(), 42);
+    }
+
+    #[test]
+    fn signed_integers() {
+        use serde_json::value::Serializer;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Offsets {
+            #[serde(with = "BigEndianI8")]
+            min: i8,
+            #[serde(with = "LittleEndianI32")]
+            delta: i32,
+        }
+
+        assert_eq!(BigEndianI8::serialize(&i8::MIN, Serializer).unwrap(), "80");
+        assert_eq!(BigEndianI8::deserialize(json!("80")).unwrap(), i8::MIN);
+        assert_eq!(LittleEndianI8::deserialize(json!("ff")).unwrap(), -1_i8);
+
+        assert_eq!(BigEndianI16::serialize(&-2, Serializer).unwrap(), "fffe");
+        assert_eq!(LittleEndianI16::serialize(&-2, Serializer).unwrap(), "feff");
+        assert_eq!(BigEndianI16::deserialize(json!("8000")).unwrap(), i16::MIN);
+
+        assert_eq!(
+            BigEndianI32::serialize(&-1, Serializer).unwrap(),
+            "ffffffff"
+        );
+        assert_eq!(
+            BigEndianI32::deserialize(json!("ffffffff")).unwrap(),
+            -1_i32
+        );
+        assert_eq!(
+            LittleEndianI32::serialize(&-256, Serializer).unwrap(),
+            "00ffffff"
+        );
+        assert_eq!(
+            LittleEndianI32::deserialize(json!("00ffffff")).unwrap(),
+            -256_i32
+        );
+
+        assert_eq!(
+            BigEndianI64::serialize(&i64::MAX, Serializer).unwrap(),
+            "7fffffffffffffff"
+        );
+        assert_eq!(
+            LittleEndianI64::deserialize(json!("0000000000000080")).unwrap(),
+            i64::MIN
+        );
+
+        assert_eq!(
+            BigEndianI128::serialize(&-1, Serializer).unwrap(),
+            "ffffffffffffffffffffffffffffffff"
+        );
+        assert_eq!(
+            LittleEndianI128::deserialize(json!("feffffffffffffffffffffffffffffff")).unwrap(),
+            -2_i128
+        );
+
+        let value = Offsets {
+            min: i8::MIN,
+            delta: -1_000,
+        };
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: Offsets = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+    }
+
+    #[test]
+    fn floats() {
+        use serde_json::value::Serializer;
+
+        assert_eq!(
+            BigEndianF32::serialize(&1.0, Serializer).unwrap(),
+            "3f800000"
+        );
+        assert_eq!(
+            LittleEndianF32::serialize(&1.0, Serializer).unwrap(),
+            "0000803f"
+        );
+        assert_eq!(
+            BigEndianF64::serialize(&-2.5, Serializer).unwrap(),
+            "c004000000000000"
+        );
+        let value: f64 = LittleEndianF64::deserialize(json!("000000000000f03f")).unwrap();
+        assert_eq!(value.to_bits(), 1.0_f64.to_bits());
+
+        let negative_zero: f32 = BigEndianF32::deserialize(json!("80000000")).unwrap();
+        assert_eq!(negative_zero.to_bits(), (-0.0_f32).to_bits());
+
+        // NaNs with non-default payloads must round-trip exactly.
+        let nan = f32::from_bits(0x7fc0_1234);
+        assert!(nan.is_nan());
+        let json = BigEndianF32::serialize(&nan, Serializer).unwrap();
+        assert_eq!(json, "7fc01234");
+        let restored: f32 = BigEndianF32::deserialize(json).unwrap();
+        assert_eq!(restored.to_bits(), nan.to_bits());
+
+        let nan = f64::from_bits(0xfff8_0000_dead_beef);
+        let json = LittleEndianF64::serialize(&nan, Serializer).unwrap();
+        let restored: f64 = LittleEndianF64::deserialize(json).unwrap();
+        assert_eq!(restored.to_bits(), nan.to_bits());
+
+        let bytes = bincode::serialize(&BigEndianF32::create_bytes(&f32::NAN)).unwrap();
+        let restored_bytes: [u8; 4] = bincode::deserialize(&bytes).unwrap();
+        let restored = BigEndianF32::from_bytes(restored_bytes).unwrap();
+        assert_eq!(restored.to_bits(), f32::NAN.to_bits());
+    }
+
+    #[test]
+    fn deserializing_integers_with_invalid_length() {
+        let json = json!({
+            "height": "01",
+            "nonce": "04030201",
+            "version": "abcd",
+            "work": "00",
+        });
+        let err = serde_json::from_value::<Block>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected hex string of 16 characters (for 8 bytes), got 2 characters"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,476 @@
+//! Integration with [`serde_with`](https://docs.rs/serde_with/).
+
+#[cfg(any(feature = "const_len", feature = "alloc"))]
+use serde::{Deserializer, Serializer};
+#[cfg(any(feature = "const_len", feature = "alloc"))]
+use serde_with::{DeserializeAs, Same, SerializeAs};
+
+#[cfg(any(feature = "const_len", feature = "alloc"))]
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "const_len")]
+use crate::{
+    ColonConstHexForm, ConstHex, ConstHexForm, LenientConstHexForm, PrefixedConstHexForm,
+    UpperConstHexForm,
+};
+#[cfg(feature = "alloc")]
+use crate::{
+    ColonHexForm, ConfiguredHexForm, Hex, HexConfig, HexForm, LenientHexForm, PrefixedHexForm,
+    PrefixedUpperHexForm, UpperHexForm,
+};
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_serde_as_for_form {
+    ($($form:ident),+) => {
+        $(
+        impl<T> SerializeAs<T> for $form<Same>
+        where
+            $form<T>: Hex<T>,
+        {
+            fn serialize_as<S: Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+                <$form<T>>::serialize(source, serializer)
+            }
+        }
+
+        impl<'de, T> DeserializeAs<'de, T> for $form<Same>
+        where
+            $form<T>: Hex<T>,
+        {
+            fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+                <$form<T>>::deserialize(deserializer)
+            }
+        }
+        )+
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_serde_as_for_form!(
+    HexForm,
+    UpperHexForm,
+    PrefixedHexForm,
+    PrefixedUpperHexForm,
+    ColonHexForm,
+    LenientHexForm
+);
+
+#[cfg(feature = "alloc")]
+impl<T, C: HexConfig> SerializeAs<T> for ConfiguredHexForm<Same, C>
+where
+    ConfiguredHexForm<T, C>: Hex<T>,
+{
+    fn serialize_as<S: Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        <ConfiguredHexForm<T, C>>::serialize(source, serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T, C: HexConfig> DeserializeAs<'de, T> for ConfiguredHexForm<Same, C>
+where
+    ConfiguredHexForm<T, C>: Hex<T>,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        <ConfiguredHexForm<T, C>>::deserialize(deserializer)
+    }
+}
+
+#[cfg(feature = "const_len")]
+macro_rules! impl_serde_as_for_const_form {
+    ($($form:ident),+) => {
+        $(
+        impl<const N: usize> SerializeAs<[u8; N]> for $form<Same> {
+            fn serialize_as<S: Serializer>(
+                source: &[u8; N],
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                <$form<[u8; N]>>::serialize(source, serializer)
+            }
+        }
+
+        impl<'de, const N: usize> DeserializeAs<'de, [u8; N]> for $form<Same> {
+            fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<[u8; N], D::Error> {
+                <$form<[u8; N]>>::deserialize(deserializer)
+            }
+        }
+        )+
+    };
+}
+
+#[cfg(feature = "const_len")]
+impl_serde_as_for_const_form!(
+    ConstHexForm,
+    UpperConstHexForm,
+    PrefixedConstHexForm,
+    ColonConstHexForm,
+    LenientConstHexForm
+);
+
+/// Adapter allowing to use a [`Hex`] implementation with [`serde_with`]'s `#[serde_as]`.
+///
+/// Forms provided by this crate (e.g., [`HexForm`]) can be used with `#[serde_as]` directly,
+/// with the `_` placeholder for the type param, as in `HexForm<_>`. Custom `Hex`
+/// implementations need to be wrapped in this adapter, since `serde_with` traits cannot be
+/// implemented for them generically due to Rust orphaning rules.
+///
+/// [`serde_with`]: https://docs.rs/serde_with/
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{Hex, HexAs, HexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use serde_with::serde_as;
+/// # use std::borrow::Cow;
+///
+/// pub struct Buffer([u8; 4]);
+///
+/// struct BufferHex(());
+///
+/// impl Hex<Buffer> for BufferHex {
+///     // snip
+/// #   type Error = &'static str;
+/// #   fn create_bytes(buffer: &Buffer) -> Cow<'_, [u8]> {
+/// #       Cow::Borrowed(&buffer.0)
+/// #   }
+/// #   fn from_bytes(bytes: &[u8]) -> Result<Buffer, Self::Error> {
+/// #       bytes.try_into().map(Buffer).map_err(|_| "invalid length")
+/// #   }
+/// }
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Test {
+///     #[serde_as(as = "Vec<HexAs<BufferHex>>")]
+///     buffers: Vec<Buffer>,
+///     #[serde_as(as = "Option<HexForm<_>>")]
+///     bytes: Option<Vec<u8>>,
+/// }
+///
+/// let test = Test {
+///     buffers: vec![Buffer([1, 2, 3, 4])],
+///     bytes: Some(vec![0xc0, 0xff, 0xee]),
+/// };
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"buffers":["01020304"],"bytes":"c0ffee"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde_with", feature = "alloc"))))]
+pub struct HexAs<H>(PhantomData<H>);
+
+#[cfg(feature = "alloc")]
+impl<H> fmt::Debug for HexAs<H> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_tuple("HexAs").finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, H: Hex<T>> SerializeAs<T> for HexAs<H> {
+    fn serialize_as<S: Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(source, serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T, H: Hex<T>> DeserializeAs<'de, T> for HexAs<H> {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        H::deserialize(deserializer)
+    }
+}
+
+/// Adapter allowing to use a [`ConstHex`] implementation with [`serde_with`]'s `#[serde_as]`.
+///
+/// This is an analogue of [`HexAs`](crate::HexAs) for `ConstHex`. Unlike `HexAs`, the byte
+/// length `N` needs to be specified explicitly.
+///
+/// [`serde_with`]: https://docs.rs/serde_with/
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConstHex, ConstHexAs, ConstHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use serde_with::serde_as;
+///
+/// pub struct Buffer([u8; 4]);
+///
+/// struct BufferHex(());
+///
+/// impl ConstHex<Buffer, 4> for BufferHex {
+///     // snip
+/// #   type Error = core::convert::Infallible;
+/// #   fn create_bytes(buffer: &Buffer) -> [u8; 4] {
+/// #       buffer.0
+/// #   }
+/// #   fn from_bytes(bytes: [u8; 4]) -> Result<Buffer, Self::Error> {
+/// #       Ok(Buffer(bytes))
+/// #   }
+/// }
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Test {
+///     #[serde_as(as = "Vec<ConstHexAs<BufferHex, 4>>")]
+///     buffers: Vec<Buffer>,
+///     #[serde_as(as = "Option<ConstHexForm<_>>")]
+///     array: Option<[u8; 2]>,
+/// }
+///
+/// let test = Test {
+///     buffers: vec![Buffer([1, 2, 3, 4])],
+///     array: Some([0xc0, 0xff]),
+/// };
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"buffers":["01020304"],"array":"c0ff"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "const_len")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde_with", feature = "const_len"))))]
+pub struct ConstHexAs<H, const N: usize>(PhantomData<H>);
+
+#[cfg(feature = "const_len")]
+impl<H, const N: usize> fmt::Debug for ConstHexAs<H, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_tuple("ConstHexAs").finish()
+    }
+}
+
+#[cfg(feature = "const_len")]
+impl<T, H: ConstHex<T, N>, const N: usize> SerializeAs<T> for ConstHexAs<H, N> {
+    fn serialize_as<S: Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(source, serializer)
+    }
+}
+
+#[cfg(feature = "const_len")]
+impl<'de, T, H: ConstHex<T, N>, const N: usize> DeserializeAs<'de, T> for ConstHexAs<H, N> {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        H::deserialize(deserializer)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    use serde_derive::{Deserialize, Serialize};
+    use serde_json::json;
+    use serde_with::serde_as;
+
+    use alloc::{borrow::Cow, string::ToString, vec, vec::Vec};
+    use core::convert::TryFrom;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Buffer([u8; 4]);
+
+    struct BufferHex(());
+
+    impl Hex<Buffer> for BufferHex {
+        type Error = &'static str;
+
+        fn create_bytes(buffer: &Buffer) -> Cow<'_, [u8]> {
+            Cow::Borrowed(&buffer.0)
+        }
+
+        fn from_bytes(bytes: &[u8]) -> Result<Buffer, Self::Error> {
+            <[u8; 4]>::try_from(bytes)
+                .map(Buffer)
+                .map_err(|_| "invalid buffer length")
+        }
+    }
+
+    #[test]
+    fn serde_as_for_var_len_forms() {
+        #[serde_as]
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde_as(as = "HexForm<_>")]
+            bytes: Vec<u8>,
+            #[serde_as(as = "Vec<UpperHexForm<_>>")]
+            byte_vecs: Vec<Vec<u8>>,
+            #[serde_as(as = "Option<PrefixedHexForm<_>>")]
+            optional_bytes: Option<[u8; 2]>,
+            #[serde_as(as = "HexAs<BufferHex>")]
+            buffer: Buffer,
+            #[serde_as(as = "Vec<HexAs<BufferHex>>")]
+            buffers: Vec<Buffer>,
+            #[serde_as(as = "ColonHexForm<_>")]
+            mac: Vec<u8>,
+            #[serde_as(as = "LenientHexForm<_>")]
+            lenient: Vec<u8>,
+            #[serde_as(as = "Option<ConfiguredHexForm<_, crate::PrefixedUpperHexConfig>>")]
+            configured: Option<Vec<u8>>,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct TestWith {
+            #[serde(with = "HexForm")]
+            bytes: Vec<u8>,
+            #[serde(with = "crate::HexVec::<UpperHexForm<Vec<u8>>>")]
+            byte_vecs: Vec<Vec<u8>>,
+            #[serde(with = "crate::HexOption::<PrefixedHexForm<[u8; 2]>>")]
+            optional_bytes: Option<[u8; 2]>,
+            #[serde(with = "BufferHex")]
+            buffer: Buffer,
+            #[serde(with = "crate::HexVec::<BufferHex>")]
+            buffers: Vec<Buffer>,
+            #[serde(with = "ColonHexForm")]
+            mac: Vec<u8>,
+            #[serde(with = "LenientHexForm")]
+            lenient: Vec<u8>,
+            #[serde(
+                with = "crate::HexOption::<ConfiguredHexForm<_, crate::PrefixedUpperHexConfig>>"
+            )]
+            configured: Option<Vec<u8>>,
+        }
+
+        let value = Test {
+            bytes: vec![0xc0, 0xff, 0xee],
+            byte_vecs: vec![vec![], vec![0xab, 0xcd]],
+            optional_bytes: Some([0xde, 0xad]),
+            buffer: Buffer([1, 2, 3, 4]),
+            buffers: vec![Buffer([5; 4]), Buffer([6; 4])],
+            mac: vec![0x01, 0xab, 0xcd],
+            lenient: vec![0xbe, 0xef],
+            configured: Some(vec![0xfe, 0xed]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "bytes": "c0ffee",
+                "byte_vecs": ["", "ABCD"],
+                "optional_bytes": "0xdead",
+                "buffer": "01020304",
+                "buffers": ["05050505", "06060606"],
+                "mac": "01:AB:CD",
+                "lenient": "beef",
+                "configured": "0xFEED",
+            })
+        );
+        let value_copy: Test = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(value_copy, value);
+
+        // Check that `serde_as` and `serde(with)` produce the same results.
+        let value_with: TestWith = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&value_with).unwrap(), json);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes, bincode::serialize(&value_with).unwrap());
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_json = json!({
+            "bytes": "c0ffee",
+            "byte_vecs": [],
+            "optional_bytes": "dead",
+            "buffer": "01020304",
+            "buffers": [],
+            "mac": "",
+            "lenient": "be ef",
+            "configured": null,
+        });
+        let err = serde_json::from_value::<Test>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected `0x`-prefixed hex string"), "{err}");
+    }
+
+    #[cfg(feature = "const_len")]
+    #[test]
+    fn serde_as_for_const_len_forms() {
+        struct BufferConstHex(());
+
+        impl ConstHex<Buffer, 4> for BufferConstHex {
+            type Error = core::convert::Infallible;
+
+            fn create_bytes(buffer: &Buffer) -> [u8; 4] {
+                buffer.0
+            }
+
+            fn from_bytes(bytes: [u8; 4]) -> Result<Buffer, Self::Error> {
+                Ok(Buffer(bytes))
+            }
+        }
+
+        #[serde_as]
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde_as(as = "ConstHexForm<_>")]
+            array: [u8; 2],
+            #[serde_as(as = "Vec<UpperConstHexForm<_>>")]
+            arrays: Vec<[u8; 2]>,
+            #[serde_as(as = "Option<PrefixedConstHexForm<_>>")]
+            optional_array: Option<[u8; 2]>,
+            #[serde_as(as = "Vec<ConstHexAs<BufferConstHex, 4>>")]
+            buffers: Vec<Buffer>,
+            #[serde_as(as = "ColonConstHexForm<_>")]
+            mac: [u8; 3],
+            #[serde_as(as = "LenientConstHexForm<_>")]
+            lenient: [u8; 2],
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct TestWith {
+            #[serde(with = "ConstHexForm")]
+            array: [u8; 2],
+            #[serde(
+                with = "crate::HexVec::<crate::ConstHexBridge<UpperConstHexForm<[u8; 2]>, 2>>"
+            )]
+            arrays: Vec<[u8; 2]>,
+            #[serde(with = "crate::ConstHexOption::<PrefixedConstHexForm<[u8; 2]>>")]
+            optional_array: Option<[u8; 2]>,
+            #[serde(with = "crate::HexVec::<crate::ConstHexBridge<BufferConstHex, 4>>")]
+            buffers: Vec<Buffer>,
+            #[serde(with = "ColonConstHexForm")]
+            mac: [u8; 3],
+            #[serde(with = "LenientConstHexForm")]
+            lenient: [u8; 2],
+        }
+
+        let value = Test {
+            array: [0xc0, 0xff],
+            arrays: vec![[0xab, 0xcd]],
+            optional_array: None,
+            buffers: vec![Buffer([1, 2, 3, 4])],
+            mac: [0x01, 0xab, 0xcd],
+            lenient: [0xbe, 0xef],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "array": "c0ff",
+                "arrays": ["ABCD"],
+                "optional_array": null,
+                "buffers": ["01020304"],
+                "mac": "01:AB:CD",
+                "lenient": "beef",
+            })
+        );
+        let value_copy: Test = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(value_copy, value);
+
+        // Check that `serde_as` and `serde(with)` produce the same results.
+        let value_with: TestWith = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&value_with).unwrap(), json);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes, bincode::serialize(&value_with).unwrap());
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = json!({
+            "array": "c0ff",
+            "arrays": [],
+            "optional_array": null,
+            "buffers": [],
+            "mac": "01ABCD",
+            "lenient": " be ef ",
+        });
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy.mac, value.mac);
+        assert_eq!(value_copy.lenient, value.lenient);
+    }
+}
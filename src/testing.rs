@@ -0,0 +1,141 @@
+//! Helpers for testing [`Hex`] implementations.
+//!
+//! The helpers panic with a descriptive message if a check fails, so they are meant to be used
+//! in unit tests. Human-readable (de)serialization is checked with `serde_json`,
+//! and binary (de)serialization with `postcard`.
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_buffer_serde::{testing, HexForm, PrefixedHexForm};
+//!
+//! testing::assert_hex_roundtrip::<_, HexForm<_>>(&vec![0xc0, 0xff, 0xee], "c0ffee");
+//! testing::assert_hex_roundtrip::<_, PrefixedHexForm<_>>(&[1_u8, 2], "0x0102");
+//! testing::assert_decode_error::<[u8; 2], HexForm<_>>("010", "odd length");
+//! testing::assert_binary_roundtrip::<_, HexForm<_>>(&vec![0xc0, 0xff, 0xee]);
+//! ```
+
+use alloc::string::ToString;
+use core::fmt;
+
+use crate::{var_len::HexSerialize, Hex};
+
+/// Checks that `value` is serialized via `H` as the `expected_hex` string in human-readable
+/// formats, and that the string is deserialized back to `value`.
+///
+/// # Panics
+///
+/// Panics if any of the checks fail.
+#[track_caller]
+pub fn assert_hex_roundtrip<T, H>(value: &T, expected_hex: &str)
+where
+    T: PartialEq + fmt::Debug,
+    H: Hex<T>,
+{
+    let serialized = H::serialize(value, serde_json::value::Serializer)
+        .unwrap_or_else(|err| panic!("failed serializing {value:?}: {err}"));
+    assert_eq!(
+        serialized,
+        serde_json::Value::from(expected_hex),
+        "unexpected human-readable presentation of {value:?}"
+    );
+
+    let restored = H::deserialize(serialized)
+        .unwrap_or_else(|err| panic!("failed deserializing {expected_hex:?}: {err}"));
+    assert_eq!(restored, *value, "round-trip mismatch for {expected_hex:?}");
+}
+
+/// Checks that deserializing `bad_hex` via `H` from a human-readable format fails
+/// with an error containing `expected_substr`.
+///
+/// # Panics
+///
+/// Panics if deserialization succeeds, or if the error does not contain `expected_substr`.
+#[track_caller]
+pub fn assert_decode_error<T, H>(bad_hex: &str, expected_substr: &str)
+where
+    T: fmt::Debug,
+    H: Hex<T>,
+{
+    match H::deserialize(serde_json::Value::from(bad_hex)) {
+        Ok(value) => panic!("unexpectedly deserialized {bad_hex:?} into {value:?}"),
+        Err(err) => {
+            let err = err.to_string();
+            assert!(
+                err.contains(expected_substr),
+                "error for {bad_hex:?} does not contain {expected_substr:?}: {err}"
+            );
+        }
+    }
+}
+
+/// Checks that `value` round-trips via `H` in a binary format. Unless [`Hex::ALWAYS_HEX`]
+/// is set for `H`, also checks that the value is encoded as raw bytes
+/// returned by [`Hex::create_bytes()`].
+///
+/// # Panics
+///
+/// Panics if any of the checks fail.
+#[track_caller]
+pub fn assert_binary_roundtrip<T, H>(value: &T)
+where
+    T: PartialEq + fmt::Debug,
+    H: Hex<T>,
+{
+    let bytes = postcard::to_allocvec(&HexSerialize::<T, H>::new(value))
+        .unwrap_or_else(|err| panic!("failed serializing {value:?}: {err}"));
+    if !H::ALWAYS_HEX {
+        let raw_bytes = H::create_bytes(value);
+        assert!(
+            bytes.ends_with(&raw_bytes),
+            "{value:?} is not encoded as raw bytes {raw_bytes:?}; got {bytes:?}"
+        );
+    }
+
+    let mut deserializer = postcard::Deserializer::from_bytes(&bytes);
+    let restored = H::deserialize(&mut deserializer)
+        .unwrap_or_else(|err| panic!("failed deserializing {bytes:?}: {err}"));
+    assert_eq!(restored, *value, "round-trip mismatch for {bytes:?}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{vec, vec::Vec};
+
+    use crate::{ForceHexStr, HexForm, UpperHexForm};
+
+    #[test]
+    fn successful_checks() {
+        assert_hex_roundtrip::<_, HexForm<_>>(&vec![0xc0, 0xff, 0xee], "c0ffee");
+        assert_hex_roundtrip::<_, HexForm<Vec<u8>>>(&vec![], "");
+        assert_hex_roundtrip::<_, UpperHexForm<_>>(&[0xab_u8; 2], "ABAB");
+
+        assert_decode_error::<Vec<u8>, HexForm<_>>("c0ffe", "odd length");
+        assert_decode_error::<Vec<u8>, HexForm<_>>("c0ffeg", "invalid hex character 'g'");
+        assert_decode_error::<[u8; 4], HexForm<_>>("c0ffee", "could not convert slice");
+
+        assert_binary_roundtrip::<_, HexForm<_>>(&vec![0xc0, 0xff, 0xee]);
+        assert_binary_roundtrip::<_, HexForm<Vec<u8>>>(&vec![]);
+        assert_binary_roundtrip::<_, ForceHexStr<HexForm<_>>>(&[1_u8; 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected human-readable presentation")]
+    fn failed_hex_roundtrip() {
+        assert_hex_roundtrip::<_, UpperHexForm<_>>(&vec![0xc0, 0xff, 0xee], "c0ffee");
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpectedly deserialized \"c0ffee\"")]
+    fn failed_decode_error_check() {
+        assert_decode_error::<Vec<u8>, HexForm<_>>("c0ffee", "odd length");
+    }
+
+    #[test]
+    #[should_panic(expected = "does not contain \"odd length\"")]
+    fn decode_error_mismatch() {
+        assert_decode_error::<Vec<u8>, HexForm<_>>("c0ffeg", "odd length");
+    }
+}
@@ -1,12 +1,30 @@
 //! Types dependent on the `alloc` crate.
 
 use serde::{
-    de::{Error as DeError, Unexpected, Visitor},
-    Deserializer, Serializer,
+    de::{
+        value::StrDeserializer, DeserializeSeed, Error as DeError, SeqAccess, Unexpected, Visitor,
+    },
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use alloc::{borrow::Cow, vec::Vec};
-use core::{convert::TryFrom, fmt, marker::PhantomData};
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use core::{
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use crate::{
+    backend, codec,
+    colon::{ColonHex, ColonHexBytes, ColonHexError},
+    error::{check_hex_len, check_lenient_hex_chars, check_strict_hex_chars, ExpectingFn},
+    flex, HexDecodeError, ParseHexError,
+};
 
 /// Provides hex-encoded (de)serialization for `serde`.
 ///
@@ -21,6 +39,31 @@ pub trait Hex<T> {
     /// Error returned on unsuccessful deserialization.
     type Error: fmt::Display;
 
+    /// Name of the deserialized type used in deserialization error messages, such as
+    /// `expected hex-encoded PublicKey`. Defaults to `byte array`.
+    const TYPE_NAME: &'static str = DEFAULT_TYPE_NAME;
+
+    /// If set to `true`, values are always (de)serialized as hex strings, regardless of whether
+    /// the (de)serializer is [human-readable][hr]. This takes precedence over
+    /// [`Self::ALWAYS_BINARY`]. Defaults to `false`.
+    ///
+    /// [hr]: serde::Serializer::is_human_readable()
+    const ALWAYS_HEX: bool = false;
+
+    /// If set to `true`, values are always (de)serialized as byte arrays, regardless of whether
+    /// the (de)serializer is [human-readable][hr]. Defaults to `false`.
+    ///
+    /// [hr]: serde::Serializer::is_human_readable()
+    const ALWAYS_BINARY: bool = false;
+
+    /// If set to `true`, hex strings are serialized with upper-case digits. Deserialization
+    /// is case-insensitive regardless of this value. Defaults to `false`.
+    const UPPER_CASE: bool = false;
+
+    /// Prefix of hex strings (e.g., `0x`). If set, the prefix is prepended to hex strings
+    /// on serialization and is required on deserialization. Defaults to `None`.
+    const PREFIX: Option<&'static str> = None;
+
     /// Converts the value into bytes. This is used for serialization.
     ///
     /// The returned buffer can be either borrowed from the type, or created by the method.
@@ -34,82 +77,389 @@ pub trait Hex<T> {
     /// to `serde` conventions (no upper-casing of the first letter, no punctuation at the end).
     fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error>;
 
-    /// Serializes the value for `serde`. This method is not meant to be overridden.
+    /// Validates bytes on deserialization before they are passed to [`Self::from_bytes()`].
+    /// This allows to enforce invariants (e.g., the byte length or domain-specific byte patterns)
+    /// without duplicating them in `from_bytes()`.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Error conventions are the same as for [`Self::from_bytes()`].
+    fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+        let _ = bytes;
+        Ok(())
+    }
+
+    /// Describes the expected hex-encoded value in deserialization error messages,
+    /// e.g. `32-byte Ed25519 public key in hex encoding`.
+    ///
+    /// The default implementation writes `hex-encoded {TYPE_NAME}` using [`Self::TYPE_NAME`],
+    /// or `` `{PREFIX}`-prefixed hex string `` if [`Self::PREFIX`] is set.
+    ///
+    /// # Errors
+    ///
+    /// Propagates formatting errors.
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(prefix) = Self::PREFIX {
+            write!(formatter, "`{prefix}`-prefixed hex string")
+        } else {
+            write!(formatter, "hex-encoded {}", Self::TYPE_NAME)
+        }
+    }
+
+    /// Serializes the value for `serde`. This method is not meant to be overridden;
+    /// use the associated constants to customize the output format.
     ///
     /// The serialization is a lower-case hex string
     /// for [human-readable][hr] serializers (e.g., JSON or TOML), and the original bytes
     /// returned by [`Self::create_bytes()`] for non-human-readable ones.
+    /// This can be changed with [`Self::ALWAYS_HEX`], [`Self::ALWAYS_BINARY`],
+    /// [`Self::UPPER_CASE`] and [`Self::PREFIX`].
     ///
     /// [hr]: serde::Serializer::is_human_readable()
     /// [`create_bytes`]: #tymethod.create_bytes
     fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
-        let value = Self::create_bytes(value);
-        if serializer.is_human_readable() {
-            serializer.serialize_str(&hex::encode(value))
+        let bytes = Self::create_bytes(value);
+        let prefix = Self::PREFIX.unwrap_or("");
+        if Self::ALWAYS_HEX {
+            serialize_hex_str(&bytes, serializer, Self::UPPER_CASE, prefix)
+        } else if Self::ALWAYS_BINARY {
+            serializer.serialize_bytes(&bytes)
         } else {
-            serializer.serialize_bytes(value.as_ref())
+            serialize_bytes(&bytes, serializer, Self::UPPER_CASE, prefix)
         }
     }
 
-    /// Deserializes a value using `serde`. This method is not meant to be overridden.
+    /// Deserializes a value using `serde`. This method is not meant to be overridden;
+    /// use the associated constants to customize the input format.
     ///
     /// If the deserializer is [human-readable][hr] (e.g., JSON or TOML), this method
-    /// expects a hex-encoded string. Otherwise, the method expects a byte array.
+    /// expects a hex-encoded string; a sequence of bytes (e.g., `[1, 2, 3]` in JSON)
+    /// is accepted as well. Otherwise, the method expects a byte array.
+    /// This can be changed with [`Self::ALWAYS_HEX`], [`Self::ALWAYS_BINARY`]
+    /// and [`Self::PREFIX`].
     ///
     /// [hr]: serde::Serializer::is_human_readable()
     fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct HexVisitor;
+        let visitor =
+            HexVisitor::with_prefix(Self::PREFIX.unwrap_or("")).with_expecting(Self::expecting);
+        let bytes = if Self::ALWAYS_HEX {
+            deserializer.deserialize_str(visitor)?
+        } else if Self::ALWAYS_BINARY {
+            deserializer.deserialize_byte_buf(BytesVisitor::new())?
+        } else {
+            deserialize_bytes(deserializer, visitor)?
+        };
+        from_validated_bytes::<T, Self>(&bytes).map_err(D::Error::custom)
+    }
+}
 
-        impl Visitor<'_> for HexVisitor {
-            type Value = Vec<u8>;
+/// Default value of [`Hex::TYPE_NAME`].
+const DEFAULT_TYPE_NAME: &str = "byte array";
 
-            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("hex-encoded byte array")
-            }
+/// Validates `bytes` with [`Hex::validate_bytes()`] and converts them into a value.
+pub(crate) fn from_validated_bytes<T, H: Hex<T> + ?Sized>(bytes: &[u8]) -> Result<T, H::Error> {
+    H::validate_bytes(bytes)?;
+    H::from_bytes(bytes)
+}
 
-            fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
-                hex::decode(value).map_err(|_| E::invalid_type(Unexpected::Str(value), &self))
-            }
+/// Serializes `bytes` as a hex string or a byte array depending on whether the serializer
+/// is human-readable. `prefix` is prepended to the hex string.
+pub(crate) fn serialize_bytes<S: Serializer>(
+    bytes: &[u8],
+    serializer: S,
+    upper_case: bool,
+    prefix: &str,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serialize_hex_str(bytes, serializer, upper_case, prefix)
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
 
-            // See the `deserializing_flattened_field` test for an example why this is needed.
-            fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
-                Ok(value.to_vec())
-            }
-        }
+/// Serializes `bytes` as a hex string regardless of whether the serializer is human-readable.
+fn serialize_hex_str<S: Serializer>(
+    bytes: &[u8],
+    serializer: S,
+    upper_case: bool,
+    prefix: &str,
+) -> Result<S::Ok, S::Error> {
+    let hex_string = if upper_case {
+        backend::encode_upper(bytes)
+    } else {
+        backend::encode(bytes)
+    };
+    if prefix.is_empty() {
+        serializer.serialize_str(&hex_string)
+    } else {
+        serializer.collect_str(&format_args!("{prefix}{hex_string}"))
+    }
+}
 
-        struct BytesVisitor;
+/// Deserializes bytes from a hex string (using `visitor`) or a byte array
+/// depending on whether the deserializer is human-readable.
+pub(crate) fn deserialize_bytes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    visitor: HexVisitor,
+) -> Result<Vec<u8>, D::Error> {
+    if !deserializer.is_human_readable() {
+        deserializer.deserialize_byte_buf(BytesVisitor::new())
+    } else if visitor.accept_seq {
+        // We use `deserialize_any()` since the input may be a sequence of bytes
+        // rather than a string; see `HexVisitor::visit_seq()`.
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_str(visitor)
+    }
+}
 
-        impl Visitor<'_> for BytesVisitor {
-            type Value = Vec<u8>;
+/// [`Visitor`] producing a byte vector from a hex string.
+///
+/// This is the visitor used by [`HexForm`] and other [`Hex`] implementations
+/// when deserializing from human-readable formats. It can be embedded into custom
+/// [`Deserialize`](serde::Deserialize) implementations.
+///
+/// # Implemented methods
+///
+/// - `visit_str` decodes a hex string, which must start with the prefix
+///   specified on creation (if any). Both lower-case and upper-case digits are accepted.
+///   ASCII whitespace is rejected unless [`Self::strip_whitespace()`] is called.
+/// - `visit_bytes` copies raw bytes as-is; it is used e.g. for flattened fields.
+/// - `visit_seq` collects a sequence of bytes, such as one produced
+///   from a JavaScript `Uint8Array`, unless [`Self::reject_seq()`] is called.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::HexVisitor;
+/// use serde::Deserializer as _;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(r#""c0ffee""#);
+/// let bytes = deserializer.deserialize_str(HexVisitor::new())?;
+/// assert_eq!(bytes, [0xc0, 0xff, 0xee]);
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(r#""0xc0ffee""#);
+/// let bytes = deserializer.deserialize_str(HexVisitor::with_prefix("0x"))?;
+/// assert_eq!(bytes, [0xc0, 0xff, 0xee]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Copy)]
+pub struct HexVisitor {
+    prefix: &'static str,
+    strip_whitespace: bool,
+    accept_seq: bool,
+    type_name: &'static str,
+    expecting: Option<ExpectingFn>,
+}
 
-            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("byte array")
-            }
+impl Default for HexVisitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-            fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
-                Ok(value.to_vec())
-            }
+impl HexVisitor {
+    /// Creates a visitor for non-prefixed hex strings.
+    pub const fn new() -> Self {
+        Self::with_prefix("")
+    }
 
-            fn visit_byte_buf<E: DeError>(self, value: Vec<u8>) -> Result<Self::Value, E> {
-                Ok(value)
-            }
+    /// Creates a visitor for hex strings starting with the specified `prefix` (e.g., `0x`).
+    pub const fn with_prefix(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            strip_whitespace: false,
+            accept_seq: true,
+            type_name: DEFAULT_TYPE_NAME,
+            expecting: None,
         }
+    }
+
+    /// Makes the visitor ignore ASCII whitespace in hex strings (e.g., `01 02 03`).
+    /// The prefix (if any) must still immediately start the string.
+    #[must_use]
+    pub const fn strip_whitespace(mut self) -> Self {
+        self.strip_whitespace = true;
+        self
+    }
 
-        let maybe_bytes = if deserializer.is_human_readable() {
-            deserializer.deserialize_str(HexVisitor)
+    /// Makes the visitor reject byte sequences, so that only hex strings are accepted.
+    #[must_use]
+    pub const fn reject_seq(mut self) -> Self {
+        self.accept_seq = false;
+        self
+    }
+
+    /// Sets the type name used in error messages (see [`Hex::TYPE_NAME`]).
+    #[must_use]
+    pub const fn with_type_name(mut self, type_name: &'static str) -> Self {
+        self.type_name = type_name;
+        self
+    }
+
+    /// Sets the function describing the expected value in error messages (see [`Hex::expecting()`]).
+    /// This takes precedence over the type name and prefix.
+    pub(crate) const fn with_expecting(mut self, expecting: ExpectingFn) -> Self {
+        self.expecting = Some(expecting);
+        self
+    }
+
+    fn decode_stripped<E: DeError>(self, hex_str: &str) -> Result<Vec<u8>, E> {
+        check_lenient_hex_chars(hex_str, self.prefix.len())?;
+        let digits: String = hex_str
+            .chars()
+            .filter(|ch| !ch.is_ascii_whitespace())
+            .collect();
+        check_hex_len(digits.len())?;
+        backend::decode(digits).map_err(E::custom)
+    }
+}
+
+impl<'de> Visitor<'de> for HexVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(expecting) = self.expecting {
+            expecting(formatter)
+        } else if self.prefix.is_empty() {
+            write!(formatter, "hex-encoded {}", self.type_name)
         } else {
-            deserializer.deserialize_byte_buf(BytesVisitor)
+            write!(formatter, "`{}`-prefixed hex string", self.prefix)
+        }
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        let Some(hex_str) = value.strip_prefix(self.prefix) else {
+            return Err(E::invalid_value(Unexpected::Str(value), &self));
         };
-        maybe_bytes.and_then(|bytes| Self::from_bytes(&bytes).map_err(D::Error::custom))
+        if self.strip_whitespace {
+            return self.decode_stripped(hex_str);
+        }
+        check_hex_len(hex_str.len())?;
+        backend::decode(hex_str).map_err(|err| {
+            match HexDecodeError::from_hex_error(err, hex_str, self.prefix.len()) {
+                Some(err) => E::custom(err),
+                None => E::invalid_type(Unexpected::Str(value), &self),
+            }
+        })
+    }
+
+    // See the `deserializing_flattened_field` test for an example why this is needed.
+    fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(value.to_vec())
+    }
+
+    // Allows byte sequences produced e.g. by JavaScript `Uint8Array`s.
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        if self.accept_seq {
+            collect_bytes(seq)
+        } else {
+            Err(A::Error::invalid_type(Unexpected::Seq, &self))
+        }
+    }
+}
+
+fn collect_bytes<'de, A: SeqAccess<'de>>(mut seq: A) -> Result<Vec<u8>, A::Error> {
+    let mut bytes = Vec::with_capacity(cautious_capacity::<u8>(seq.size_hint()));
+    while let Some(byte) = seq.next_element::<u8>()? {
+        bytes.push(byte);
+    }
+    Ok(bytes)
+}
+
+/// Returns the capacity to preallocate for a sequence based on the size hint provided
+/// by a deserializer. We do not trust the hint blindly to avoid allocating excessive amounts
+/// of memory.
+pub(crate) fn cautious_capacity<T>(size_hint: Option<usize>) -> usize {
+    const MAX_PREALLOCATED_BYTES: usize = 64 * 1_024;
+
+    let max_len = MAX_PREALLOCATED_BYTES / mem::size_of::<T>().max(1);
+    size_hint.unwrap_or(0).min(max_len)
+}
+
+/// [`Visitor`] producing a byte vector from raw bytes.
+///
+/// This is the visitor used by [`HexForm`] and other [`Hex`] implementations
+/// when deserializing from binary formats.
+///
+/// # Implemented methods
+///
+/// - `visit_bytes` and `visit_byte_buf` accept native byte arrays.
+/// - `visit_seq` collects a sequence of bytes; formats without native byte array support
+///   (e.g., JSON) represent bytes this way.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::BytesVisitor;
+/// use serde::Deserializer as _;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3]");
+/// let bytes = deserializer.deserialize_seq(BytesVisitor::new())?;
+/// assert_eq!(bytes, [1, 2, 3]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BytesVisitor(());
+
+impl BytesVisitor {
+    /// Creates a new visitor.
+    pub const fn new() -> Self {
+        Self(())
+    }
+}
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("byte array")
+    }
+
+    fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(value.to_vec())
+    }
+
+    fn visit_byte_buf<E: DeError>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(value)
+    }
+
+    // Formats without native byte array support (e.g., JSON) represent bytes as sequences.
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        collect_bytes(seq)
     }
 }
 
 /// A dummy container for use inside `#[serde(with)]` attribute if the underlying type
 /// implements [`Hex`].
 ///
+/// # Supported types
+///
+/// `HexForm<T>` implements [`Hex`]`<T>` for all types `T` implementing [`AsRef`]`<[u8]>`
+/// and [`TryFrom`]`<&[u8]>` with a displayable error. Besides user-defined types, this covers
+/// the following standard types:
+///
+/// - `Vec<u8>`
+/// - `[u8; N]`. On length mismatch, deserialization fails with a generic
+///   "could not convert slice to array" error; wrap the form into [`ExactLenHex`]
+///   (or use [`ConstHexForm`](crate::ConstHexForm)) to get a more descriptive error
+/// - `Box<[u8]>`, `Rc<[u8]>` and `Arc<[u8]>` (via the blanket [`TryFrom`] implementation
+///   derived from `From<&[u8]>` implementations for these types)
+///
+/// Some third-party types are supported as well without any additional features, e.g.:
+///
+/// - `ArrayVec<u8, CAP>` from the [`arrayvec`](https://crates.io/crates/arrayvec) crate;
+///   deserialization fails if the decoded bytes exceed the vector capacity
+/// - `SmallVec<[u8; N]>` from the [`smallvec`](https://crates.io/crates/smallvec) crate;
+///   the vector spills to the heap if the decoded bytes do not fit inline
+///
 /// # Why a separate container?
 ///
 /// We need a separate type (instead of just using `impl<T> Hex<T> for T`)
@@ -119,6 +469,71 @@ pub trait Hex<T> {
 #[derive(Debug)]
 pub struct HexForm<T>(PhantomData<T>);
 
+// Traits are implemented manually since derives would require bounds on `T`.
+impl<T> Clone for HexForm<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for HexForm<T> {}
+
+impl<T> Default for HexForm<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> PartialEq for HexForm<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for HexForm<T> {}
+
+impl<T> Hash for HexForm<T> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {
+        // No state to hash
+    }
+}
+
+/// Conversions to / from hex strings outside `serde`. Unlike [`Hex`] methods,
+/// these do not require the trait to be in scope.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::HexForm;
+///
+/// let hex_str = HexForm::encode(&vec![0xc0, 0xff, 0xee]);
+/// assert_eq!(hex_str, "c0ffee");
+/// let bytes: Vec<u8> = HexForm::decode("c0FFee")?;
+/// assert_eq!(bytes, [0xc0, 0xff, 0xee]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+impl<T, E> HexForm<T>
+where
+    T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
+{
+    /// Encodes the value as a lower-case hex string.
+    pub fn encode(value: &T) -> String {
+        backend::encode(value.as_ref())
+    }
+
+    /// Decodes the value from a hex string. Both lower-case and upper-case hex digits
+    /// are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid hex string, or if decoded bytes
+    /// cannot be converted to a value.
+    pub fn decode(hex_str: &str) -> Result<T, ParseHexError<E>> {
+        let bytes = codec::decode(hex_str).map_err(ParseHexError::Hex)?;
+        T::try_from(&bytes).map_err(ParseHexError::Conversion)
+    }
+}
+
 impl<T, E> Hex<T> for HexForm<T>
 where
     T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
@@ -135,206 +550,4331 @@ where
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    use serde_derive::{Deserialize, Serialize};
-    use serde_json::json;
+/// Analogue of [`HexForm`] that uses upper-case hex strings for [human-readable][hr] serializers.
+///
+/// Deserialization is case-insensitive, so values serialized with `HexForm` can be read back
+/// with `UpperHexForm` and vice versa.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{Hex as _, UpperHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Fingerprint {
+///     #[serde(with = "UpperHexForm")]
+///     bytes: Vec<u8>,
+/// }
+///
+/// let fingerprint = Fingerprint { bytes: vec![0xc0, 0xff, 0xee] };
+/// let json = serde_json::to_string(&fingerprint)?;
+/// assert_eq!(json, r#"{"bytes":"C0FFEE"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct UpperHexForm<T>(PhantomData<T>);
 
-    use alloc::{
-        borrow::ToOwned,
-        string::{String, ToString},
-        vec,
-    };
-    use core::array::TryFromSliceError;
+impl<T, E> Hex<T> for UpperHexForm<T>
+where
+    T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
+    E: fmt::Display,
+{
+    type Error = E;
+    const UPPER_CASE: bool = true;
 
-    #[derive(Debug, Serialize, Deserialize)]
-    struct Buffer([u8; 8]);
+    fn create_bytes(buffer: &T) -> Cow<'_, [u8]> {
+        HexForm::create_bytes(buffer)
+    }
 
-    impl AsRef<[u8]> for Buffer {
-        fn as_ref(&self) -> &[u8] {
-            &self.0
-        }
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        HexForm::from_bytes(bytes)
     }
+}
 
-    impl TryFrom<&[u8]> for Buffer {
-        type Error = TryFromSliceError;
+/// Analogue of [`HexForm`] that prepends the `0x` prefix to hex strings
+/// for [human-readable][hr] serializers, as is customary in Ethereum-related code.
+///
+/// On deserialization, the prefix is required; hex strings without it are rejected.
+/// Binary serialization is not affected.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{Hex as _, PrefixedHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Transaction {
+///     #[serde(with = "PrefixedHexForm")]
+///     data: Vec<u8>,
+/// }
+///
+/// let tx = Transaction { data: vec![0xc0, 0xff, 0xee] };
+/// let json = serde_json::to_string(&tx)?;
+/// assert_eq!(json, r#"{"data":"0xc0ffee"}"#);
+///
+/// let err = serde_json::from_str::<Transaction>(r#"{"data":"c0ffee"}"#)
+///     .map(drop)
+///     .unwrap_err();
+/// assert!(err.to_string().contains("expected `0x`-prefixed hex string"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct PrefixedHexForm<T>(PhantomData<T>);
 
-        fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
-            <[u8; 8]>::try_from(slice).map(Buffer)
-        }
+impl<T, E> Hex<T> for PrefixedHexForm<T>
+where
+    T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
+    E: fmt::Display,
+{
+    type Error = E;
+    const PREFIX: Option<&'static str> = Some("0x");
+
+    fn create_bytes(buffer: &T) -> Cow<'_, [u8]> {
+        HexForm::create_bytes(buffer)
     }
 
-    #[derive(Debug, Serialize, Deserialize)]
-    struct Test {
-        #[serde(with = "HexForm::<Buffer>")]
-        buffer: Buffer,
-        other_field: String,
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        HexForm::from_bytes(bytes)
     }
+}
 
-    #[test]
-    fn internal_type() {
-        let json = json!({ "buffer": "0001020304050607", "other_field": "abc" });
-        let value: Test = serde_json::from_value(json.clone()).unwrap();
-        assert!(value
-            .buffer
-            .0
-            .iter()
-            .enumerate()
-            .all(|(i, &byte)| i == usize::from(byte)));
+/// Combination of [`PrefixedHexForm`] and [`UpperHexForm`]: serializes buffers
+/// as `0x`-prefixed upper-case hex strings for [human-readable][hr] serializers.
+///
+/// As with `PrefixedHexForm`, the prefix is required on deserialization.
+/// The hex digits themselves are case-insensitive.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct PrefixedUpperHexForm<T>(PhantomData<T>);
 
-        let json_copy = serde_json::to_value(&value).unwrap();
-        assert_eq!(json, json_copy);
-    }
+impl<T, E> Hex<T> for PrefixedUpperHexForm<T>
+where
+    T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
+    E: fmt::Display,
+{
+    type Error = E;
+    const UPPER_CASE: bool = true;
+    const PREFIX: Option<&'static str> = Some("0x");
 
-    #[test]
-    fn error_reporting() {
-        let bogus_jsons = vec![
-            serde_json::json!({
-                "buffer": "bogus",
-                "other_field": "test",
-            }),
-            serde_json::json!({
-                "buffer": "c0ffe",
-                "other_field": "test",
-            }),
-        ];
+    fn create_bytes(buffer: &T) -> Cow<'_, [u8]> {
+        HexForm::create_bytes(buffer)
+    }
 
-        for bogus_json in bogus_jsons {
-            let err = serde_json::from_value::<Test>(bogus_json)
-                .unwrap_err()
-                .to_string();
-            assert!(err.contains("expected hex-encoded byte array"), "{}", err);
-        }
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        HexForm::from_bytes(bytes)
     }
+}
 
-    #[test]
-    fn internal_type_with_derived_serde_code() {
-        // ...and here, we may use original `serde` code.
-        #[derive(Serialize, Deserialize)]
-        struct OriginalTest {
-            buffer: Buffer,
-            other_field: String,
-        }
+/// Analogue of [`HexForm`] that ignores ASCII whitespace in hex strings on deserialization
+/// (e.g., `01 02 03 04` or `0102 0304`).
+///
+/// Serialization and binary deserialization are the same as for `HexForm`; in particular,
+/// serialized hex strings contain no whitespace. `HexForm` itself rejects whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{Hex as _, LenientHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Packet {
+///     #[serde(with = "LenientHexForm")]
+///     payload: Vec<u8>,
+/// }
+///
+/// let packet: Packet = serde_json::from_str(r#"{"payload":"c0 ff ee"}"#)?;
+/// assert_eq!(packet.payload, [0xc0, 0xff, 0xee]);
+/// assert_eq!(serde_json::to_string(&packet)?, r#"{"payload":"c0ffee"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct LenientHexForm<T>(PhantomData<T>);
+
+impl<T, E> Hex<T> for LenientHexForm<T>
+where
+    T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
+    E: fmt::Display,
+{
+    type Error = E;
+
+    fn create_bytes(buffer: &T) -> Cow<'_, [u8]> {
+        HexForm::create_bytes(buffer)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        HexForm::from_bytes(bytes)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_bytes(deserializer, HexVisitor::new().strip_whitespace())?;
+        from_validated_bytes::<T, Self>(&bytes).map_err(D::Error::custom)
+    }
+}
+
+/// Configuration of hex (de)serialization used by [`ConfiguredHexForm`].
+///
+/// All constants have default values matching the behavior of [`HexForm`], so that
+/// implementations only need to override the relevant ones.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ConfiguredHexForm, Hex as _, HexConfig};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// enum StrictUpperConfig {}
+///
+/// impl HexConfig for StrictUpperConfig {
+///     const UPPER_CASE: bool = true;
+///     const ACCEPT_SEQ: bool = false;
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ConfiguredHexForm::<_, StrictUpperConfig>")]
+///     bytes: Vec<u8>,
+/// }
+///
+/// let test: Test = serde_json::from_str(r#"{"bytes":"c0ffee"}"#)?;
+/// assert_eq!(serde_json::to_string(&test)?, r#"{"bytes":"C0FFEE"}"#);
+/// assert!(serde_json::from_str::<Test>(r#"{"bytes":[1,2,3]}"#).is_err());
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub trait HexConfig {
+    /// Whether to use upper-case hex digits on serialization. Deserialization
+    /// is always case-insensitive.
+    const UPPER_CASE: bool = false;
+    /// Prefix prepended to hex strings on serialization and required on deserialization.
+    const PREFIX: Option<&'static str> = None;
+    /// Whether to ignore ASCII whitespace in hex strings on deserialization.
+    const STRIP_WHITESPACE: bool = false;
+    /// Whether to accept byte sequences (e.g., `[1, 2, 3]` in JSON) in addition to hex strings
+    /// on deserialization from human-readable formats.
+    const ACCEPT_SEQ: bool = true;
+}
+
+/// Default [`HexConfig`] matching [`HexForm`].
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub enum DefaultHexConfig {}
+
+impl HexConfig for DefaultHexConfig {}
+
+/// [`HexConfig`] matching [`UpperHexForm`].
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub enum UpperHexConfig {}
+
+impl HexConfig for UpperHexConfig {
+    const UPPER_CASE: bool = true;
+}
+
+/// [`HexConfig`] matching [`PrefixedHexForm`].
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub enum PrefixedHexConfig {}
+
+impl HexConfig for PrefixedHexConfig {
+    const PREFIX: Option<&'static str> = Some("0x");
+}
+
+/// [`HexConfig`] matching [`PrefixedUpperHexForm`].
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub enum PrefixedUpperHexConfig {}
+
+impl HexConfig for PrefixedUpperHexConfig {
+    const UPPER_CASE: bool = true;
+    const PREFIX: Option<&'static str> = Some("0x");
+}
+
+/// [`HexConfig`] matching [`LenientHexForm`].
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub enum LenientHexConfig {}
+
+impl HexConfig for LenientHexConfig {
+    const STRIP_WHITESPACE: bool = true;
+}
+
+/// Analogue of [`HexForm`] with (de)serialization parameterized by a [`HexConfig`].
+///
+/// See `HexConfig` docs for an example of usage.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct ConfiguredHexForm<T, C: HexConfig = DefaultHexConfig>(PhantomData<(T, C)>);
+
+impl<T, C: HexConfig> ConfiguredHexForm<T, C> {
+    fn visitor() -> HexVisitor {
+        let mut visitor = HexVisitor::with_prefix(C::PREFIX.unwrap_or_default());
+        if C::STRIP_WHITESPACE {
+            visitor = visitor.strip_whitespace();
+        }
+        if !C::ACCEPT_SEQ {
+            visitor = visitor.reject_seq();
+        }
+        visitor
+    }
+}
+
+impl<T, E, C> Hex<T> for ConfiguredHexForm<T, C>
+where
+    T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
+    E: fmt::Display,
+    C: HexConfig,
+{
+    type Error = E;
+    const UPPER_CASE: bool = C::UPPER_CASE;
+    const PREFIX: Option<&'static str> = C::PREFIX;
+
+    fn create_bytes(buffer: &T) -> Cow<'_, [u8]> {
+        HexForm::create_bytes(buffer)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        HexForm::from_bytes(bytes)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_bytes(deserializer, Self::visitor())?;
+        from_validated_bytes::<T, Self>(&bytes).map_err(D::Error::custom)
+    }
+}
+
+/// Analogue of [`HexForm`] that serializes buffers as upper-case hex pairs separated by colons
+/// (e.g., `01:AB:CD`) for [human-readable][hr] serializers. This format is customary for MAC
+/// addresses and key fingerprints.
+///
+/// On deserialization, both colon-separated and bare hex strings are accepted (the hex digits
+/// are case-insensitive). If colons are present, they must separate all hex pairs;
+/// e.g., `01:ABCD` or `01:AB:` are rejected. Binary serialization is not affected.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ColonHexForm, Hex as _};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Fingerprint {
+///     #[serde(with = "ColonHexForm")]
+///     bytes: Vec<u8>,
+/// }
+///
+/// let fingerprint = Fingerprint { bytes: vec![0xc0, 0xff, 0xee] };
+/// let json = serde_json::to_string(&fingerprint)?;
+/// assert_eq!(json, r#"{"bytes":"C0:FF:EE"}"#);
+/// let copy: Fingerprint = serde_json::from_str(r#"{"bytes":"c0ffee"}"#)?;
+/// assert_eq!(copy, fingerprint);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct ColonHexForm<T>(PhantomData<T>);
+
+impl<T, E> Hex<T> for ColonHexForm<T>
+where
+    T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
+    E: fmt::Display,
+{
+    type Error = E;
+
+    fn create_bytes(buffer: &T) -> Cow<'_, [u8]> {
+        HexForm::create_bytes(buffer)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        HexForm::from_bytes(bytes)
+    }
+
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = Self::create_bytes(value);
+        if serializer.is_human_readable() {
+            serializer.collect_str(&ColonHex(&bytes))
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ColonHexVisitor)?
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor::new())?
+        };
+        from_validated_bytes::<T, Self>(&bytes).map_err(D::Error::custom)
+    }
+}
+
+struct ColonHexVisitor;
+
+impl Visitor<'_> for ColonHexVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("colon-separated hex string")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        match ColonHexBytes::new(value) {
+            Ok(bytes) => {
+                let mut decoded = Vec::with_capacity(bytes.len());
+                decoded.extend(bytes.iter());
+                Ok(decoded)
+            }
+            Err(ColonHexError::InvalidChar(err)) => Err(E::custom(err)),
+            Err(ColonHexError::InvalidFormat) => {
+                Err(E::invalid_value(Unexpected::Str(value), &self))
+            }
+        }
+    }
+}
+
+/// Analogue of [`HexForm`] that checks that the byte length of a deserialized value
+/// is within the `MIN..=MAX` range before converting bytes into a value.
+///
+/// Out-of-range inputs are rejected with an error like "expected 16 to 32 bytes, got 12",
+/// which is usually cleaner than the error returned by the `TryFrom` implementation.
+/// Serialization is the same as for `HexForm`.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{BoundedHexForm, Hex as _};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "BoundedHexForm::<_, 2, 4>")]
+///     salt: Vec<u8>,
+/// }
+///
+/// let test: Test = serde_json::from_str(r#"{"salt":"c0ffee"}"#)?;
+/// assert_eq!(test.salt, [0xc0, 0xff, 0xee]);
+/// let err = serde_json::from_str::<Test>(r#"{"salt":"c0"}"#).unwrap_err();
+/// assert!(err.to_string().starts_with("expected 2 to 4 bytes, got 1"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct BoundedHexForm<T, const MIN: usize, const MAX: usize>(PhantomData<T>);
+
+impl<T, const MIN: usize, const MAX: usize> BoundedHexForm<T, MIN, MAX> {
+    const VALID_BOUNDS: () = assert!(MIN <= MAX, "`MIN` must not exceed `MAX`");
+}
+
+impl<T, E, const MIN: usize, const MAX: usize> Hex<T> for BoundedHexForm<T, MIN, MAX>
+where
+    T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
+    E: fmt::Display,
+{
+    type Error = BoundedHexError<E>;
+
+    fn create_bytes(buffer: &T) -> Cow<'_, [u8]> {
+        HexForm::create_bytes(buffer)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        Self::validate_bytes(bytes)?;
+        HexForm::from_bytes(bytes).map_err(BoundedHexError::Conversion)
+    }
+
+    fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+        #[allow(clippy::let_unit_value)] // needed to check bounds in compile time
+        let () = Self::VALID_BOUNDS;
+
+        if (MIN..=MAX).contains(&bytes.len()) {
+            Ok(())
+        } else {
+            Err(BoundedHexError::Length {
+                min: MIN,
+                max: MAX,
+                actual: bytes.len(),
+            })
+        }
+    }
+}
+
+/// Wrapper around a [`Hex`] implementation that checks that deserialized values
+/// have exactly `N` bytes before converting them with the wrapped implementation.
+///
+/// This is similar to using [`ConstHex`](crate::ConstHex), but does not require
+/// the `const_len` feature or a separate trait implementation. On length mismatch,
+/// an error like "expected exactly 32 bytes, got 31" is returned.
+///
+/// Serialization is delegated to the wrapped implementation. On deserialization, the wrapper
+/// forwards the format constants of the wrapped implementation (such as [`Hex::PREFIX`]
+/// or [`Hex::ALWAYS_HEX`]) and the expected value description in error messages, so that
+/// the bytes can be checked before they are converted into a value. The `deserialize()`
+/// method of the wrapped implementation is not called; thus, the wrapper should not be used
+/// with implementations overriding it (e.g., [`ColonHexForm`]).
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ExactLenHex, Hex as _, HexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ExactLenHex::<HexForm<Vec<u8>>, 4>")]
+///     buffer: Vec<u8>,
+/// }
+///
+/// let test: Test = serde_json::from_str(r#"{"buffer":"deadbeef"}"#)?;
+/// assert_eq!(test.buffer, [0xde, 0xad, 0xbe, 0xef]);
+/// let err = serde_json::from_str::<Test>(r#"{"buffer":"c0ffee"}"#).unwrap_err();
+/// assert!(err.to_string().starts_with("expected exactly 4 bytes, got 3"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct ExactLenHex<H, const N: usize>(PhantomData<H>);
+
+impl<T, H: Hex<T>, const N: usize> Hex<T> for ExactLenHex<H, N> {
+    type Error = BoundedHexError<H::Error>;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+    const ALWAYS_HEX: bool = H::ALWAYS_HEX;
+    const ALWAYS_BINARY: bool = H::ALWAYS_BINARY;
+    const UPPER_CASE: bool = H::UPPER_CASE;
+    const PREFIX: Option<&'static str> = H::PREFIX;
+
+    fn create_bytes(value: &T) -> Cow<'_, [u8]> {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        Self::check_len(bytes)?;
+        H::from_bytes(bytes).map_err(BoundedHexError::Conversion)
+    }
+
+    fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+        Self::check_len(bytes)?;
+        H::validate_bytes(bytes).map_err(BoundedHexError::Conversion)
+    }
+
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(value, serializer)
+    }
+}
+
+impl<H, const N: usize> ExactLenHex<H, N> {
+    fn check_len<E>(bytes: &[u8]) -> Result<(), BoundedHexError<E>> {
+        if bytes.len() == N {
+            Ok(())
+        } else {
+            Err(BoundedHexError::Length {
+                min: N,
+                max: N,
+                actual: bytes.len(),
+            })
+        }
+    }
+}
+
+/// Wrapper around a [`Hex`] implementation that left-pads deserialized bytes with zeros
+/// to exactly `N` bytes before converting them with the wrapped implementation.
+///
+/// This is useful for protocols omitting leading zero bytes in hex strings (e.g., `ff`
+/// for a 32-byte big-endian integer). If the deserialized bytes are longer than `N`,
+/// an error like "expected 0 to 32 bytes, got 33" is returned. Serialization is not
+/// affected, i.e., values are not padded or trimmed on output.
+///
+/// (De)serialization format is handled in the same way as for [`ExactLenHex`]: serialization
+/// is delegated to the wrapped implementation, and deserialization uses its format constants.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{Hex as _, HexForm, PadHex};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "PadHex::<HexForm<[u8; 4]>, 4>")]
+///     value: [u8; 4],
+/// }
+///
+/// let test: Test = serde_json::from_str(r#"{"value":"c0ffee"}"#)?;
+/// assert_eq!(test.value, [0, 0xc0, 0xff, 0xee]);
+/// assert_eq!(serde_json::to_string(&test)?, r#"{"value":"00c0ffee"}"#);
+/// let err = serde_json::from_str::<Test>(r#"{"value":"c0ffee0000"}"#).unwrap_err();
+/// assert!(err.to_string().starts_with("expected 0 to 4 bytes, got 5"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct PadHex<H, const N: usize>(PhantomData<H>);
+
+impl<T, H: Hex<T>, const N: usize> Hex<T> for PadHex<H, N> {
+    type Error = BoundedHexError<H::Error>;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+    const ALWAYS_HEX: bool = H::ALWAYS_HEX;
+    const ALWAYS_BINARY: bool = H::ALWAYS_BINARY;
+    const UPPER_CASE: bool = H::UPPER_CASE;
+    const PREFIX: Option<&'static str> = H::PREFIX;
+
+    fn create_bytes(value: &T) -> Cow<'_, [u8]> {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    // Bytes are padded only here, so that padding happens once per deserialized value;
+    // the wrapped validation is performed on the padded bytes.
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        let bytes = Self::pad(bytes)?;
+        H::validate_bytes(&bytes).map_err(BoundedHexError::Conversion)?;
+        H::from_bytes(&bytes).map_err(BoundedHexError::Conversion)
+    }
+
+    fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+        Self::check_len(bytes)
+    }
+
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(value, serializer)
+    }
+}
+
+impl<H, const N: usize> PadHex<H, N> {
+    fn check_len<E>(bytes: &[u8]) -> Result<(), BoundedHexError<E>> {
+        if bytes.len() > N {
+            Err(BoundedHexError::Length {
+                min: 0,
+                max: N,
+                actual: bytes.len(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn pad<E>(bytes: &[u8]) -> Result<Cow<'_, [u8]>, BoundedHexError<E>> {
+        Self::check_len(bytes)?;
+        if bytes.len() == N {
+            return Ok(Cow::Borrowed(bytes));
+        }
+
+        let mut padded = alloc::vec![0_u8; N - bytes.len()];
+        padded.extend_from_slice(bytes);
+        Ok(Cow::Owned(padded))
+    }
+}
+
+/// Error returned by [`BoundedHexForm`], [`ExactLenHex`] and [`PadHex`] when converting bytes
+/// to a value.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BoundedHexError<E> {
+    /// Byte length is out of bounds.
+    Length {
+        /// Minimum allowed byte length.
+        min: usize,
+        /// Maximum allowed byte length.
+        max: usize,
+        /// Actual byte length.
+        actual: usize,
+    },
+    /// Error converting bytes into a value.
+    Conversion(E),
+}
+
+impl<E: fmt::Display> fmt::Display for BoundedHexError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length { min, max, actual } if min == max => {
+                write!(formatter, "expected exactly {min} bytes, got {actual}")
+            }
+            Self::Length { min, max, actual } => {
+                write!(formatter, "expected {min} to {max} bytes, got {actual}")
+            }
+            Self::Conversion(err) => fmt::Display::fmt(err, formatter),
+        }
+    }
+}
+
+/// Validator of byte sequences used by [`ValidatedHex`].
+///
+/// The validator is a type rather than a function, so that it can be specified
+/// in `#[serde(with)]` attributes; usually, it is implemented for a zero-sized type.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub trait BytesValidator {
+    /// Validates the provided bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable error description conforming to `serde` conventions
+    /// (no upper-casing of the first letter, no punctuation at the end) if the bytes are invalid.
+    fn validate(bytes: &[u8]) -> Result<(), &'static str>;
+}
+
+/// Wrapper around a [`Hex`] implementation that checks deserialized bytes with
+/// a [`BytesValidator`] before converting them with the wrapped implementation.
+///
+/// This allows to reject certain byte patterns (e.g., all-zero public keys) without duplicating
+/// the check in each [`Hex::from_bytes()`] implementation. The validator runs exactly once
+/// per deserialized value. (De)serialization format is handled in the same way
+/// as for [`ExactLenHex`]: serialization is delegated to the wrapped implementation,
+/// and deserialization uses its format constants.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{BytesValidator, Hex as _, HexForm, ValidatedHex};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// struct NonZero(());
+///
+/// impl BytesValidator for NonZero {
+///     fn validate(bytes: &[u8]) -> Result<(), &'static str> {
+///         if bytes.iter().all(|&byte| byte == 0) {
+///             Err("all-zero key")
+///         } else {
+///             Ok(())
+///         }
+///     }
+/// }
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ValidatedHex::<HexForm<[u8; 4]>, NonZero>")]
+///     key: [u8; 4],
+/// }
+///
+/// let test: Test = serde_json::from_str(r#"{"key":"00000001"}"#)?;
+/// assert_eq!(test.key, [0, 0, 0, 1]);
+/// let err = serde_json::from_str::<Test>(r#"{"key":"00000000"}"#).unwrap_err();
+/// assert!(err.to_string().starts_with("all-zero key"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct ValidatedHex<H, V>(PhantomData<(H, V)>);
+
+impl<T, H: Hex<T>, V: BytesValidator> Hex<T> for ValidatedHex<H, V> {
+    type Error = ValidatedHexError<H::Error>;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+    const ALWAYS_HEX: bool = H::ALWAYS_HEX;
+    const ALWAYS_BINARY: bool = H::ALWAYS_BINARY;
+    const UPPER_CASE: bool = H::UPPER_CASE;
+    const PREFIX: Option<&'static str> = H::PREFIX;
+
+    fn create_bytes(value: &T) -> Cow<'_, [u8]> {
+        H::create_bytes(value)
+    }
+
+    // The validator is only invoked here (rather than in `validate_bytes()`), so that it runs
+    // once both on deserialization and on direct `from_bytes()` calls.
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        V::validate(bytes).map_err(ValidatedHexError::Validation)?;
+        H::from_bytes(bytes).map_err(ValidatedHexError::Conversion)
+    }
+
+    fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+        H::validate_bytes(bytes).map_err(ValidatedHexError::Conversion)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(value, serializer)
+    }
+}
+
+/// Error returned by [`ValidatedHex`] when converting bytes to a value.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ValidatedHexError<E> {
+    /// Bytes were rejected by the [`BytesValidator`].
+    Validation(&'static str),
+    /// Error converting bytes into a value.
+    Conversion(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ValidatedHexError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Validation(err) => formatter.write_str(err),
+            Self::Conversion(err) => fmt::Display::fmt(err, formatter),
+        }
+    }
+}
+
+/// Wrapper around a [`Hex`] implementation that always (de)serializes values as hex strings,
+/// regardless of whether the (de)serializer is [human-readable][hr].
+///
+/// This is useful for non-human-readable formats that nevertheless are expected
+/// to contain hex strings. The case and prefix of hex strings are taken from the wrapped
+/// implementation ([`Hex::UPPER_CASE`] and [`Hex::PREFIX`]); on deserialization, hex digits
+/// are case-insensitive. Since the wrapped implementation may not produce hex strings
+/// at all, its `serialize()` and `deserialize()` methods are not called; e.g., wrapping
+/// [`ColonHexForm`] produces hex strings without colons.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ForceHexStr, Hex as _, HexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ForceHexStr::<HexForm<Vec<u8>>>")]
+///     buffer: Vec<u8>,
+/// }
+///
+/// let test = Test { buffer: vec![0xc0, 0xff, 0xee] };
+/// let bytes = bincode::serialize(&test)?;
+/// // `bincode` serializes strings as their length followed by UTF-8 bytes.
+/// assert_eq!(bytes, bincode::serialize("c0ffee")?);
+/// let test_copy: Test = bincode::deserialize(&bytes)?;
+/// assert_eq!(test_copy, test);
+/// # Ok::<_, bincode::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct ForceHexStr<H>(PhantomData<H>);
+
+impl<T, H: Hex<T>> Hex<T> for ForceHexStr<H> {
+    type Error = H::Error;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+    const ALWAYS_HEX: bool = true;
+    const UPPER_CASE: bool = H::UPPER_CASE;
+    const PREFIX: Option<&'static str> = H::PREFIX;
+
+    fn create_bytes(value: &T) -> Cow<'_, [u8]> {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        H::from_bytes(bytes)
+    }
+
+    fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+        H::validate_bytes(bytes)
+    }
+}
+
+/// Wrapper around a [`Hex`] implementation that always (de)serializes values as byte arrays,
+/// regardless of whether the (de)serializer is [human-readable][hr].
+///
+/// This may be useful if binary data is encoded at a higher level, or if the format
+/// has a more efficient representation for byte arrays than hex strings. Note that formats
+/// without native byte array support (e.g., JSON) represent byte arrays as sequences
+/// of numbers; such sequences are accepted on deserialization. The wrapped implementation
+/// is only used to convert values to / from bytes; its `serialize()` and `deserialize()`
+/// methods are not called.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{ForceBinary, Hex as _, HexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "ForceBinary::<HexForm<Vec<u8>>>")]
+///     buffer: Vec<u8>,
+/// }
+///
+/// let test = Test { buffer: vec![0xc0, 0xff, 0xee] };
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"buffer":[192,255,238]}"#);
+/// let test_copy: Test = serde_json::from_str(&json)?;
+/// assert_eq!(test_copy, test);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct ForceBinary<H>(PhantomData<H>);
+
+impl<T, H: Hex<T>> Hex<T> for ForceBinary<H> {
+    type Error = H::Error;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+    const ALWAYS_BINARY: bool = true;
+
+    fn create_bytes(value: &T) -> Cow<'_, [u8]> {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        H::from_bytes(bytes)
+    }
+
+    fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+        H::validate_bytes(bytes)
+    }
+}
+
+/// Wrapper around a [`Hex`] implementation that accepts multiple input formats
+/// when deserializing from [human-readable][hr] formats. The formats are tried in this order:
+///
+/// 1. Strings accepted by `H` (e.g., hex strings for [`HexForm`]).
+/// 2. Base64-encoded strings with the standard alphabet and padding, if the `base64`
+///    crate feature is enabled. Base64 is only tried if `H` fails to deserialize the string;
+///    thus, strings valid both as hex and base64 (e.g., `c0ffee`) are decoded as hex.
+/// 3. Byte arrays.
+/// 4. Sequences of bytes (e.g., `[1, 2, 3]` in JSON).
+///
+/// Serialization and deserialization from binary formats are delegated to `H`.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{FlexHex, Hex as _, HexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "FlexHex::<HexForm<Vec<u8>>>")]
+///     buffer: Vec<u8>,
+/// }
+///
+/// let from_hex: Test = serde_json::from_str(r#"{ "buffer": "c0ffee" }"#)?;
+/// let from_seq: Test = serde_json::from_str(r#"{ "buffer": [192, 255, 238] }"#)?;
+/// assert_eq!(from_hex, from_seq);
+/// assert_eq!(serde_json::to_string(&from_seq)?, r#"{"buffer":"c0ffee"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct FlexHex<H>(PhantomData<H>);
+
+impl<T, H: Hex<T>> Hex<T> for FlexHex<H> {
+    type Error = H::Error;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+
+    fn create_bytes(value: &T) -> Cow<'_, [u8]> {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        H::from_bytes(bytes)
+    }
+
+    fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+        H::validate_bytes(bytes)
+    }
+
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(value, serializer)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(FlexHexVisitor::<T, H>(PhantomData))
+        } else {
+            H::deserialize(deserializer)
+        }
+    }
+}
+
+/// [`Visitor`] used by [`FlexHex`].
+struct FlexHexVisitor<T, H>(PhantomData<fn() -> (T, H)>);
+
+impl<'de, T, H: Hex<T>> Visitor<'de> for FlexHexVisitor<T, H> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)?;
+        formatter.write_str(flex::EXPECTING_SUFFIX)
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        match H::deserialize(StrDeserializer::<E>::new(value)) {
+            Ok(value) => Ok(value),
+            #[cfg(feature = "base64")]
+            Err(hex_err) => {
+                let bytes = flex::decode_base64(value, &hex_err)?;
+                from_validated_bytes::<T, H>(&bytes).map_err(E::custom)
+            }
+            #[cfg(not(feature = "base64"))]
+            Err(hex_err) => Err(hex_err),
+        }
+    }
+
+    fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
+        from_validated_bytes::<T, H>(value).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let bytes = collect_bytes(seq)?;
+        from_validated_bytes::<T, H>(&bytes).map_err(A::Error::custom)
+    }
+}
+
+/// Wrapper around a [`Hex`] implementation that only accepts lower-case hex strings without
+/// a prefix when deserializing from [human-readable][hr] formats. This is the opposite
+/// of the default case-insensitive deserialization, and may be useful for applications
+/// that need a canonical hex presentation (e.g., to prevent confusion in security-related data).
+///
+/// Strings containing upper-case hex digits are rejected with an error mentioning
+/// that strict hex requires lowercase characters; other non-hex chars (including prefixes
+/// such as `0x` and whitespace) are rejected as invalid hex chars. Byte sequences
+/// are not accepted either. Validated strings are passed to `H`; serialization
+/// and deserialization from binary formats are delegated to `H` as well.
+///
+/// [hr]: serde::Serializer::is_human_readable()
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{Hex as _, HexForm, StrictHex};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "StrictHex::<HexForm<Vec<u8>>>")]
+///     buffer: Vec<u8>,
+/// }
+///
+/// let test: Test = serde_json::from_str(r#"{ "buffer": "c0ffee" }"#)?;
+/// assert_eq!(test.buffer, [0xc0, 0xff, 0xee]);
+/// let err = serde_json::from_str::<Test>(r#"{ "buffer": "C0FFEE" }"#).unwrap_err();
+/// assert!(err.to_string().starts_with("strict hex requires lowercase characters only"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct StrictHex<H>(PhantomData<H>);
+
+impl<T, H: Hex<T>> Hex<T> for StrictHex<H> {
+    type Error = H::Error;
+    const TYPE_NAME: &'static str = H::TYPE_NAME;
+
+    fn create_bytes(value: &T) -> Cow<'_, [u8]> {
+        H::create_bytes(value)
+    }
+
+    fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        H::from_bytes(bytes)
+    }
+
+    fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+        H::validate_bytes(bytes)
+    }
+
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(value, serializer)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrictHexVisitor::<T, H>(PhantomData))
+        } else {
+            H::deserialize(deserializer)
+        }
+    }
+}
+
+/// [`Visitor`] used by [`StrictHex`].
+struct StrictHexVisitor<T, H>(PhantomData<fn() -> (T, H)>);
+
+impl<T, H: Hex<T>> Visitor<'_> for StrictHexVisitor<T, H> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        H::expecting(formatter)?;
+        formatter.write_str(" in lower case")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        check_strict_hex_chars(value)?;
+        H::deserialize(StrDeserializer::<E>::new(value))
+    }
+}
+
+/// Adapter allowing to serialize a value via a [`Hex`] implementation.
+pub(crate) struct HexSerialize<'a, T, H> {
+    value: &'a T,
+    _hex: PhantomData<H>,
+}
+
+impl<'a, T, H: Hex<T>> HexSerialize<'a, T, H> {
+    pub(crate) fn new(value: &'a T) -> Self {
+        Self {
+            value,
+            _hex: PhantomData,
+        }
+    }
+}
+
+impl<T, H: Hex<T>> Serialize for HexSerialize<'_, T, H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(self.value, serializer)
+    }
+}
+
+/// Container for use inside `#[serde(with)]` attribute for optional values, which provides
+/// (de)serialization via the [`Hex`] implementation `H` for the underlying type.
+///
+/// `None` is serialized as a missing value (e.g., `null` in JSON), and `Some(_)` is serialized
+/// in the same way as with `H`. Binary formats see the value via [`Serializer::serialize_none()`] /
+/// [`Serializer::serialize_some()`], so e.g. `bincode` encodes it as a tag byte followed
+/// by the bytes of the value. Combine with `#[serde(default)]` if the field may be absent
+/// from the serialized data altogether.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{HexForm, HexOption};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "HexOption::<HexForm<[u8; 4]>>")]
+///     buffer: Option<[u8; 4]>,
+/// }
+///
+/// let test = Test { buffer: Some([0xde, 0xad, 0xbe, 0xef]) };
+/// assert_eq!(serde_json::to_string(&test)?, r#"{"buffer":"deadbeef"}"#);
+/// let test = Test { buffer: None };
+/// assert_eq!(serde_json::to_string(&test)?, r#"{"buffer":null}"#);
+/// let test_copy: Test = serde_json::from_str(r#"{"buffer":null}"#)?;
+/// assert_eq!(test_copy, test);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct HexOption<H>(PhantomData<H>);
+
+impl<H> HexOption<H> {
+    /// Serializes an optional value.
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        H: Hex<T>,
+        S: Serializer,
+    {
+        match value {
+            Some(value) => serializer.serialize_some(&HexSerialize::<T, H>::new(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes an optional value.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        H: Hex<T>,
+        D: Deserializer<'de>,
+    {
+        struct OptionVisitor<T, H>(PhantomData<fn() -> (T, H)>);
+
+        impl<'de, T, H: Hex<T>> Visitor<'de> for OptionVisitor<T, H> {
+            type Value = Option<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("optional hex-encoded byte array")
+            }
+
+            fn visit_none<E: DeError>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_unit<E: DeError>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                H::deserialize(deserializer).map(Some)
+            }
+        }
+
+        deserializer.deserialize_option(OptionVisitor::<T, H>(PhantomData))
+    }
+}
+
+/// Wrapper around a [`Hex`] implementation for `T` that (de)serializes smart pointers to `T`:
+/// [`Box`]`<T>`, [`Rc`]`<T>` and [`Arc`]`<T>`.
+///
+/// Serialization and deserialization are delegated to `H`, so the presentation is the same
+/// as for `T`. A blanket `Hex<Box<T>>` implementation for all `H: Hex<T>` is not provided
+/// since it would conflict with wrapper implementations such as [`ForceHexStr`].
+///
+/// [`Rc`]: alloc::rc::Rc
+/// [`Arc`]: alloc::sync::Arc
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{Hex as _, HexForm, HexPtr};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "HexPtr::<HexForm<[u8; 4]>>")]
+///     key: Box<[u8; 4]>,
+/// }
+///
+/// let test = Test { key: Box::new([0xde, 0xad, 0xbe, 0xef]) };
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"key":"deadbeef"}"#);
+/// let test_copy: Test = serde_json::from_str(&json)?;
+/// assert_eq!(test_copy, test);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct HexPtr<H>(PhantomData<H>);
+
+macro_rules! impl_hex_ptr {
+    ($($(#[$attr:meta])* $ptr:ty => $new:path;)+) => {
+        $(
+        $(#[$attr])*
+        impl<T, H: Hex<T>> Hex<$ptr> for HexPtr<H> {
+            type Error = H::Error;
+            const TYPE_NAME: &'static str = H::TYPE_NAME;
+            const ALWAYS_HEX: bool = H::ALWAYS_HEX;
+            const ALWAYS_BINARY: bool = H::ALWAYS_BINARY;
+
+            fn create_bytes(value: &$ptr) -> Cow<'_, [u8]> {
+                H::create_bytes(value)
+            }
+
+            fn from_bytes(bytes: &[u8]) -> Result<$ptr, Self::Error> {
+                H::from_bytes(bytes).map($new)
+            }
+
+            fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+                H::validate_bytes(bytes)
+            }
+
+            fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                H::expecting(formatter)
+            }
+
+            fn serialize<S: Serializer>(value: &$ptr, serializer: S) -> Result<S::Ok, S::Error> {
+                H::serialize(value, serializer)
+            }
+
+            fn deserialize<'de, D>(deserializer: D) -> Result<$ptr, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                H::deserialize(deserializer).map($new)
+            }
+        }
+        )+
+    };
+}
+
+impl_hex_ptr!(
+    Box<T> => Box::new;
+    alloc::rc::Rc<T> => alloc::rc::Rc::new;
+    #[cfg(target_has_atomic = "ptr")]
+    alloc::sync::Arc<T> => alloc::sync::Arc::new;
+);
+
+/// Seed allowing to deserialize a sequence element via a [`Hex`] implementation.
+/// Errors are annotated with the element index.
+struct HexElementSeed<T, H> {
+    index: usize,
+    _hex: PhantomData<fn() -> (T, H)>,
+}
+
+impl<T, H: Hex<T>> HexElementSeed<T, H> {
+    fn new(index: usize) -> Self {
+        Self {
+            index,
+            _hex: PhantomData,
+        }
+    }
+}
+
+impl<'de, T, H: Hex<T>> DeserializeSeed<'de> for HexElementSeed<T, H> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        H::deserialize(deserializer)
+            .map_err(|err| D::Error::custom(format_args!("element {}: {err}", self.index)))
+    }
+}
+
+/// Container for use inside `#[serde(with)]` attribute for vectors, which provides
+/// (de)serialization via the [`Hex`] implementation `H` for the vector elements.
+///
+/// The vector is serialized as a sequence, each element of which is serialized in the same way
+/// as with `H`; i.e., as a hex string for human-readable formats, and as a byte array
+/// for binary ones. On deserialization, errors are annotated with the index
+/// of the failing element.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{HexForm, HexVec};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     #[serde(with = "HexVec::<HexForm<[u8; 2]>>")]
+///     buffers: Vec<[u8; 2]>,
+/// }
+///
+/// let test = Test { buffers: vec![[0xc0, 0xff], [0xee, 0x00]] };
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"buffers":["c0ff","ee00"]}"#);
+///
+/// let err = serde_json::from_str::<Test>(r#"{"buffers":["c0ff","ee"]}"#).unwrap_err();
+/// assert!(err.to_string().starts_with("element 1: could not convert slice to array"));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct HexVec<H>(PhantomData<H>);
+
+impl<H> HexVec<H> {
+    /// Serializes a slice of values.
+    pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        H: Hex<T>,
+        S: Serializer,
+    {
+        serializer.collect_seq(values.iter().map(HexSerialize::<T, H>::new))
+    }
+
+    /// Deserializes a vector of values.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        H: Hex<T>,
+        D: Deserializer<'de>,
+    {
+        struct VecVisitor<T, H>(PhantomData<fn() -> (T, H)>);
+
+        impl<'de, T, H: Hex<T>> Visitor<'de> for VecVisitor<T, H> {
+            type Value = Vec<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("sequence of hex-encoded byte arrays")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut values = Vec::with_capacity(cautious_capacity::<T>(seq.size_hint()));
+                while let Some(value) =
+                    seq.next_element_seed(HexElementSeed::<T, H>::new(values.len()))?
+                {
+                    values.push(value);
+                }
+                Ok(values)
+            }
+        }
+
+        deserializer.deserialize_seq(VecVisitor::<T, H>(PhantomData))
+    }
+}
+
+/// Container for use inside `#[serde(with)]` attribute for pairs `(T1, T2)`, which provides
+/// (de)serialization via the [`Hex`] implementations `H1` and `H2` for the pair elements.
+///
+/// The pair is serialized as a 2-element tuple, each element of which is serialized in the same
+/// way as with the corresponding `Hex` implementation. On deserialization, errors are annotated
+/// with the index of the failing element.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{HexForm, HexPair};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Encrypted {
+///     #[serde(with = "HexPair::<HexForm<[u8; 2]>, HexForm<Vec<u8>>>")]
+///     nonce_and_ciphertext: ([u8; 2], Vec<u8>),
+/// }
+///
+/// let value = Encrypted { nonce_and_ciphertext: ([1, 2], vec![3, 4, 5, 6]) };
+/// let json = serde_json::to_string(&value)?;
+/// assert_eq!(json, r#"{"nonce_and_ciphertext":["0102","03040506"]}"#);
+/// let value_copy: Encrypted = serde_json::from_str(&json)?;
+/// assert_eq!(value_copy, value);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct HexPair<H1, H2>(PhantomData<(H1, H2)>);
+
+impl<H1, H2> HexPair<H1, H2> {
+    /// Serializes a pair of values.
+    pub fn serialize<T1, T2, S>(value: &(T1, T2), serializer: S) -> Result<S::Ok, S::Error>
+    where
+        H1: Hex<T1>,
+        H2: Hex<T2>,
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&HexSerialize::<T1, H1>::new(&value.0))?;
+        tuple.serialize_element(&HexSerialize::<T2, H2>::new(&value.1))?;
+        tuple.end()
+    }
+
+    /// Deserializes a pair of values.
+    pub fn deserialize<'de, T1, T2, D>(deserializer: D) -> Result<(T1, T2), D::Error>
+    where
+        H1: Hex<T1>,
+        H2: Hex<T2>,
+        D: Deserializer<'de>,
+    {
+        struct PairVisitor<T1, T2, H1, H2> {
+            _values: PhantomData<fn() -> (T1, T2)>,
+            _hex: PhantomData<fn() -> (H1, H2)>,
+        }
+
+        impl<'de, T1, T2, H1, H2> Visitor<'de> for PairVisitor<T1, T2, H1, H2>
+        where
+            H1: Hex<T1>,
+            H2: Hex<T2>,
+        {
+            type Value = (T1, T2);
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("pair of hex-encoded byte arrays")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let first = seq
+                    .next_element_seed(HexElementSeed::<T1, H1>::new(0))?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let second = seq
+                    .next_element_seed(HexElementSeed::<T2, H2>::new(1))?
+                    .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+                Ok((first, second))
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            2,
+            PairVisitor::<T1, T2, H1, H2> {
+                _values: PhantomData,
+                _hex: PhantomData,
+            },
+        )
+    }
+}
+
+/// Wrapper around a value that (de)serializes it using a [`Hex`] implementation.
+///
+/// Unlike using `#[serde(with)]` attributes, this wrapper implements [`Serialize`] and
+/// [`Deserialize`](serde::Deserialize) directly, so it can be used as a field type without
+/// per-field annotations, or as an element type in collections. The wrapper dereferences
+/// to the wrapped value; `PartialEq`, `Eq` and `Hash` implementations delegate to it as well.
+///
+/// Since an `Into<T>` implementation is not allowed by Rust coherence rules,
+/// use [`Self::into_inner()`] to unwrap the value.
+///
+/// Outside `serde`, the wrapper can be converted to a hex string via [`Display`](fmt::Display)
+/// and parsed from one via [`FromStr`] (e.g., `"c0ffee".parse::<HexBuf<Vec<u8>>>()`).
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{HexBuf, HexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+/// # use std::collections::HashMap;
+///
+/// type HexBytes = HexBuf<Vec<u8>>;
+/// // ^ `HexForm` is used by default; this is equivalent to `HexBuf<Vec<u8>, HexForm<Vec<u8>>>`
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Test {
+///     buffer: HexBytes,
+///     tagged: HashMap<String, HexBytes>,
+/// }
+///
+/// let mut test = Test {
+///     buffer: vec![0xc0, 0xff, 0xee].into(),
+///     tagged: HashMap::new(),
+/// };
+/// test.tagged.insert("dead".to_owned(), vec![0xde, 0xad].into());
+/// assert_eq!(test.buffer.len(), 3); // uses `Deref`
+///
+/// let json = serde_json::to_string(&test)?;
+/// assert_eq!(json, r#"{"buffer":"c0ffee","tagged":{"dead":"dead"}}"#);
+/// let test_copy: Test = serde_json::from_str(&json)?;
+/// assert_eq!(test_copy, test);
+/// assert_eq!(test_copy.buffer.into_inner(), [0xc0, 0xff, 0xee]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct HexBuf<T, H = HexForm<T>> {
+    value: T,
+    _hex: PhantomData<fn() -> H>,
+}
+
+impl<T, H> HexBuf<T, H> {
+    /// Wraps the provided value.
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            _hex: PhantomData,
+        }
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, H: Hex<T>> HexBuf<T, H> {
+    /// Creates a value from raw bytes using [`Hex::validate_bytes()`] and [`Hex::from_bytes()`].
+    /// Unlike parsing via [`FromStr`], `bytes` are not hex-decoded.
+    ///
+    /// `TryFrom<&[u8]>` / `TryFrom<&str>` implementations are not provided since they would
+    /// conflict with the blanket `TryFrom` implementation derived from `From<T>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes cannot be converted to a value.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, H::Error> {
+        from_validated_bytes::<T, H>(bytes).map(Self::new)
+    }
+}
+
+impl<T: fmt::Debug, H> fmt::Debug for HexBuf<T, H> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_tuple("HexBuf").field(&self.value).finish()
+    }
+}
+
+/// Formats the value as a lower-case hex string.
+impl<T, H: Hex<T>> fmt::Display for HexBuf<T, H> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, formatter)
+    }
+}
+
+impl<T, H: Hex<T>> fmt::LowerHex for HexBuf<T, H> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.pad(&backend::encode(H::create_bytes(&self.value)))
+    }
+}
+
+impl<T, H: Hex<T>> fmt::UpperHex for HexBuf<T, H> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.pad(&backend::encode_upper(H::create_bytes(&self.value)))
+    }
+}
+
+/// Parses the value from a hex string. Both lower-case and upper-case hex digits are accepted;
+/// prefixes and whitespace are not, regardless of `H`.
+impl<T, H: Hex<T>> FromStr for HexBuf<T, H> {
+    type Err = ParseHexError<H::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = codec::decode(s).map_err(ParseHexError::Hex)?;
+        from_validated_bytes::<T, H>(&bytes)
+            .map(Self::new)
+            .map_err(ParseHexError::Conversion)
+    }
+}
+
+impl<T: Clone, H> Clone for HexBuf<T, H> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: PartialEq, H> PartialEq for HexBuf<T, H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, H> Eq for HexBuf<T, H> {}
+
+impl<T: Copy, H> Copy for HexBuf<T, H> {}
+
+impl<T: Default, H> Default for HexBuf<T, H> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Hash, H> Hash for HexBuf<T, H> {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        self.value.hash(state);
+    }
+}
+
+impl<T, H> From<T> for HexBuf<T, H> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, H> Deref for HexBuf<T, H> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, H> DerefMut for HexBuf<T, H> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T, H: Hex<T>> Serialize for HexBuf<T, H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        H::serialize(&self.value, serializer)
+    }
+}
+
+impl<'de, T, H: Hex<T>> Deserialize<'de> for HexBuf<T, H> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        H::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// [`Hex`] implementation for [`Bytes`](bytes::Bytes).
+///
+/// [`HexForm`] cannot be used for `Bytes` since the latter does not implement
+/// `TryFrom<&[u8]>`. (`BytesMut` does, so `HexForm<BytesMut>` works as expected.)
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Bytes;
+/// use hex_buffer_serde::{BytesHex, Hex as _};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Packet {
+///     #[serde(with = "BytesHex")]
+///     payload: Bytes,
+/// }
+///
+/// let packet = Packet { payload: Bytes::from_static(b"\xc0\xff\xee") };
+/// assert_eq!(serde_json::to_string(&packet)?, r#"{"payload":"c0ffee"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+#[derive(Debug)]
+pub struct BytesHex(());
+
+#[cfg(feature = "bytes")]
+impl Hex<bytes::Bytes> for BytesHex {
+    type Error = core::convert::Infallible;
+
+    fn create_bytes(buffer: &bytes::Bytes) -> Cow<'_, [u8]> {
+        Cow::Borrowed(buffer.as_ref())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<bytes::Bytes, Self::Error> {
+        Ok(bytes::Bytes::copy_from_slice(bytes))
+    }
+}
+
+/// A dummy container for use inside `#[serde(with)]` attribute for [`Uint8Array`]s
+/// from the [`js-sys`](https://crates.io/crates/js-sys) crate.
+///
+/// Arrays are (de)serialized in the same way as with [`HexForm`]. Note that serialization
+/// copies the array contents from JS memory, and deserialization creates a new array.
+///
+/// [`Uint8Array`]: js_sys::Uint8Array
+///
+/// # Examples
+///
+/// ```no_run
+/// use hex_buffer_serde::{Hex as _, Uint8ArrayHex};
+/// use js_sys::Uint8Array;
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Packet {
+///     #[serde(with = "Uint8ArrayHex")]
+///     payload: Uint8Array,
+/// }
+///
+/// // JS functions are only available in WASM.
+/// let packet = Packet { payload: Uint8Array::from(&b"\xc0\xff\xee"[..]) };
+/// assert_eq!(serde_json::to_string(&packet)?, r#"{"payload":"c0ffee"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "wasm-bindgen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm-bindgen")))]
+#[derive(Debug)]
+pub struct Uint8ArrayHex(());
+
+#[cfg(feature = "wasm-bindgen")]
+impl Hex<js_sys::Uint8Array> for Uint8ArrayHex {
+    type Error = core::convert::Infallible;
+
+    fn create_bytes(buffer: &js_sys::Uint8Array) -> Cow<'_, [u8]> {
+        Cow::Owned(buffer.to_vec())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<js_sys::Uint8Array, Self::Error> {
+        Ok(js_sys::Uint8Array::from(bytes))
+    }
+}
+
+/// A dummy container for use inside `#[serde(with)]` attribute for [`String`]s
+/// serialized as hex-encoded UTF-8 bytes.
+///
+/// Strings are (de)serialized in the same way as byte buffers with [`HexForm`].
+/// Deserialization fails with [`Utf8HexError`] if the decoded bytes are not valid UTF-8.
+/// (`HexForm<String>` cannot be used since `String` does not implement `TryFrom<&[u8]>`.)
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{Hex as _, Utf8HexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(with = "Utf8HexForm")]
+///     text: String,
+/// }
+///
+/// let message = Message { text: "hello".to_owned() };
+/// let json = serde_json::to_string(&message)?;
+/// assert_eq!(json, r#"{"text":"68656c6c6f"}"#);
+/// let message_copy: Message = serde_json::from_str(&json)?;
+/// assert_eq!(message_copy, message);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct Utf8HexForm(());
+
+impl Hex<String> for Utf8HexForm {
+    type Error = Utf8HexError;
+    const TYPE_NAME: &'static str = "UTF-8 string";
+
+    fn create_bytes(buffer: &String) -> Cow<'_, [u8]> {
+        Cow::Borrowed(buffer.as_bytes())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<String, Self::Error> {
+        core::str::from_utf8(bytes)
+            .map(String::from)
+            .map_err(Utf8HexError)
+    }
+}
+
+/// Error converting decoded bytes into a string in [`Utf8HexForm`].
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Utf8HexError(core::str::Utf8Error);
+
+impl Utf8HexError {
+    /// Returns the underlying UTF-8 decoding error.
+    pub fn utf8_error(&self) -> core::str::Utf8Error {
+        self.0
+    }
+}
+
+impl fmt::Display for Utf8HexError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "decoded bytes are not valid UTF-8: {}", self.0)
+    }
+}
+
+impl core::error::Error for Utf8HexError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// Analogue of [`HexForm`] that zeroizes intermediate decoded bytes on deserialization.
+///
+/// This is useful for secret values, such as private keys. The decoded bytes are zeroized
+/// after [`Hex::from_bytes()`] is called, regardless of whether it succeeds or fails.
+/// Note that this is a best-effort measure: the (de)serializer may keep copies of the data
+/// on its own (e.g., the original hex string), and serialization is not affected.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::{Hex as _, ZeroizeHexForm};
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Keypair {
+///     #[serde(with = "ZeroizeHexForm")]
+///     secret: [u8; 4],
+/// }
+///
+/// let keypair: Keypair = serde_json::from_str(r#"{"secret":"c0ffee00"}"#)?;
+/// assert_eq!(keypair.secret, [0xc0, 0xff, 0xee, 0]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "zeroize", feature = "alloc"))))]
+#[derive(Debug)]
+pub struct ZeroizeHexForm<T>(PhantomData<T>);
+
+#[cfg(feature = "zeroize")]
+impl<T, E> Hex<T> for ZeroizeHexForm<T>
+where
+    T: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = E>,
+    E: fmt::Display,
+{
+    type Error = E;
+
+    fn create_bytes(buffer: &T) -> Cow<'_, [u8]> {
+        HexForm::create_bytes(buffer)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<T, Self::Error> {
+        HexForm::from_bytes(bytes)
+    }
+
+    fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut bytes = if deserializer.is_human_readable() {
+            // We use `deserialize_any()` since the input may be a sequence of bytes
+            // rather than a string; see `HexVisitor::visit_seq()`.
+            deserializer.deserialize_any(ZeroizingVisitor(HexVisitor::new()))?
+        } else {
+            deserializer.deserialize_byte_buf(ZeroizingVisitor(BytesVisitor::new()))?
+        };
+        from_bytes_zeroizing::<T, Self>(&mut bytes).map_err(D::Error::custom)
+    }
+}
+
+/// Wrapper around a byte [`Visitor`] that collects byte sequences without leaving
+/// non-zeroized copies of the intermediate buffer in memory. Other inputs are handled
+/// by the wrapped visitor.
+#[cfg(feature = "zeroize")]
+struct ZeroizingVisitor<V>(V);
+
+#[cfg(feature = "zeroize")]
+impl<'de, V: Visitor<'de, Value = Vec<u8>>> Visitor<'de> for ZeroizingVisitor<V> {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        self.0.visit_str(value)
+    }
+
+    fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
+        self.0.visit_bytes(value)
+    }
+
+    fn visit_byte_buf<E: DeError>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        self.0.visit_byte_buf(value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        collect_bytes_zeroizing(seq)
+    }
+}
+
+/// Analogue of `collect_bytes()` that zeroizes the buffer on reallocation and on errors.
+/// (Growing a `Vec` with `push()` would free old allocations without zeroizing them.)
+#[cfg(feature = "zeroize")]
+fn collect_bytes_zeroizing<'de, A: SeqAccess<'de>>(mut seq: A) -> Result<Vec<u8>, A::Error> {
+    const MIN_CAPACITY: usize = 32;
+
+    let capacity = cautious_capacity::<u8>(seq.size_hint());
+    let mut bytes = zeroize::Zeroizing::new(Vec::with_capacity(capacity));
+    while let Some(byte) = seq.next_element::<u8>()? {
+        if bytes.len() == bytes.capacity() {
+            let mut grown_bytes = Vec::with_capacity((2 * bytes.capacity()).max(MIN_CAPACITY));
+            grown_bytes.extend_from_slice(&bytes);
+            // The old buffer is zeroized when dropped.
+            bytes = zeroize::Zeroizing::new(grown_bytes);
+        }
+        bytes.push(byte);
+    }
+    Ok(mem::take(&mut *bytes))
+}
+
+/// Converts `bytes` into a value and zeroizes them, regardless of the conversion outcome.
+#[cfg(feature = "zeroize")]
+fn from_bytes_zeroizing<T, H: Hex<T>>(bytes: &mut [u8]) -> Result<T, H::Error> {
+    let result = from_validated_bytes::<T, H>(bytes);
+    zeroize::Zeroize::zeroize(bytes);
+    result
+}
+
+/// Free functions (de)serializing byte buffers as lower-case hex strings for use
+/// with `#[serde(with)]`, similar to the [`serde_bytes`](https://docs.rs/serde_bytes/) crate.
+///
+/// The functions behave in the same way as [`HexForm`], but do not require specifying
+/// the buffer type or importing the [`Hex`] trait. See [`upper_hex_bytes`] for
+/// the upper-case variant.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_derive::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(with = "hex_buffer_serde::hex_bytes")]
+///     payload: Vec<u8>,
+/// }
+///
+/// let message = Message { payload: vec![0xc0, 0xff, 0xee] };
+/// let json = serde_json::to_string(&message)?;
+/// assert_eq!(json, r#"{"payload":"c0ffee"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod hex_bytes {
+    use serde::{Deserializer, Serializer};
+
+    use alloc::vec::Vec;
+
+    use super::HexVisitor;
+
+    /// Serializes `bytes` as a lower-case hex string for human-readable serializers,
+    /// and as a byte array otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors returned by the serializer.
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_bytes(bytes, serializer, false, "")
+    }
+
+    /// Deserializes bytes from a hex string for human-readable deserializers,
+    /// and from a byte array otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a valid hex string / byte array.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        super::deserialize_bytes(deserializer, HexVisitor::new())
+    }
+}
+
+/// Analogue of [`hex_bytes`](mod@hex_bytes) that serializes byte buffers as upper-case hex strings.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_derive::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(with = "hex_buffer_serde::upper_hex_bytes")]
+///     payload: Vec<u8>,
+/// }
+///
+/// let message = Message { payload: vec![0xc0, 0xff, 0xee] };
+/// let json = serde_json::to_string(&message)?;
+/// assert_eq!(json, r#"{"payload":"C0FFEE"}"#);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod upper_hex_bytes {
+    use serde::{Deserializer, Serializer};
+
+    use alloc::vec::Vec;
+
+    /// Serializes `bytes` as an upper-case hex string for human-readable serializers,
+    /// and as a byte array otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors returned by the serializer.
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_bytes(bytes, serializer, true, "")
+    }
+
+    /// Deserializes bytes in the same way as [`hex_bytes::deserialize()`](super::hex_bytes::deserialize()).
+    /// Hex strings are case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a valid hex string / byte array.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        super::hex_bytes::deserialize(deserializer)
+    }
+}
+
+/// Serializes `value` via the [`Hex`] implementation `H`. This is a free function equivalent
+/// to `H::serialize()` for use with `#[serde(serialize_with)]`, which does not require
+/// the [`Hex`] trait to be in scope.
+///
+/// # Errors
+///
+/// Propagates errors returned by the serializer.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::HexForm;
+/// # use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(serialize_with = "hex_buffer_serde::serialize_hex::<_, HexForm<_>, _>")]
+///     payload: Vec<u8>,
+/// }
+///
+/// let message = Message { payload: vec![0xc0, 0xff, 0xee] };
+/// let json = serde_json::to_string(&message)?;
+/// assert_eq!(json, r#"{"payload":"c0ffee"}"#);
+/// // Deserialization is unaffected, i.e., uses a sequence of bytes.
+/// let message: Message = serde_json::from_str(r#"{"payload":[1,2]}"#)?;
+/// assert_eq!(message.payload, [1, 2]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn serialize_hex<T, H, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    H: Hex<T> + ?Sized,
+    S: Serializer,
+{
+    H::serialize(value, serializer)
+}
+
+/// Deserializes a value via the [`Hex`] implementation `H`. This is a free function equivalent
+/// to `H::deserialize()` for use with `#[serde(deserialize_with)]`, which does not require
+/// the [`Hex`] trait to be in scope.
+///
+/// # Errors
+///
+/// Returns an error if the input is not a valid hex string / byte array, or if it cannot
+/// be converted to `T`.
+///
+/// # Examples
+///
+/// ```
+/// use hex_buffer_serde::HexForm;
+/// # use serde_derive::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Message {
+///     #[serde(deserialize_with = "hex_buffer_serde::deserialize_hex::<_, HexForm<_>, _>")]
+///     payload: Vec<u8>,
+/// }
+///
+/// let message: Message = serde_json::from_str(r#"{"payload":"c0ffee"}"#)?;
+/// assert_eq!(message.payload, [0xc0, 0xff, 0xee]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn deserialize_hex<'de, T, H, D>(deserializer: D) -> Result<T, D::Error>
+where
+    H: Hex<T> + ?Sized,
+    D: Deserializer<'de>,
+{
+    H::deserialize(deserializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_derive::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use alloc::{
+        borrow::ToOwned,
+        boxed::Box,
+        format,
+        rc::Rc,
+        string::{String, ToString},
+        sync::Arc,
+        vec,
+    };
+    use core::array::TryFromSliceError;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Buffer([u8; 8]);
+
+    impl AsRef<[u8]> for Buffer {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    impl TryFrom<&[u8]> for Buffer {
+        type Error = TryFromSliceError;
+
+        fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+            <[u8; 8]>::try_from(slice).map(Buffer)
+        }
+    }
+
+    /// Form rejecting all-zero buffers in `validate_bytes()` only.
+    struct NonZeroHex(());
+
+    impl Hex<Buffer> for NonZeroHex {
+        type Error = &'static str;
+
+        fn create_bytes(value: &Buffer) -> Cow<'_, [u8]> {
+            Cow::Borrowed(&value.0)
+        }
+
+        fn from_bytes(bytes: &[u8]) -> Result<Buffer, Self::Error> {
+            Buffer::try_from(bytes).map_err(|_| "invalid buffer length")
+        }
+
+        fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+            if bytes.iter().all(|&byte| byte == 0) {
+                Err("all-zero buffer")
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Test {
+        #[serde(with = "HexForm::<Buffer>")]
+        buffer: Buffer,
+        other_field: String,
+    }
+
+    #[test]
+    fn internal_type() {
+        let json = json!({ "buffer": "0001020304050607", "other_field": "abc" });
+        let value: Test = serde_json::from_value(json.clone()).unwrap();
+        assert!(value
+            .buffer
+            .0
+            .iter()
+            .enumerate()
+            .all(|(i, &byte)| i == usize::from(byte)));
+
+        let json_copy = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json_copy);
+    }
+
+    #[test]
+    fn error_reporting() {
+        let bogus_jsons = [
+            (
+                json!({ "buffer": "c0ffe", "other_field": "test" }),
+                "hex string has odd length 5; expected even number of characters",
+            ),
+            (
+                json!({ "buffer": 42, "other_field": "test" }),
+                "invalid type: integer `42`, expected hex-encoded byte array",
+            ),
+        ];
+
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<Test>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.starts_with(expected_err), "{err}");
+        }
+    }
+
+    #[test]
+    fn lenient_deserialization() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Lenient {
+            #[serde(with = "LenientHexForm")]
+            bytes: Vec<u8>,
+        }
+
+        let valid_strings = [
+            ("", &[] as &[u8]),
+            ("  ", &[]),
+            ("c0ffee", &[0xc0, 0xff, 0xee]),
+            ("\tc0 ff ee\r\n", &[0xc0, 0xff, 0xee]),
+            ("c0ff ee", &[0xc0, 0xff, 0xee]),
+            ("C 0 F F E E", &[0xc0, 0xff, 0xee]),
+        ];
+        for (s, expected) in valid_strings {
+            let value: Lenient = serde_json::from_value(json!({ "bytes": s })).unwrap();
+            assert_eq!(value.bytes, expected, "{s:?}");
+        }
+
+        let err = serde_json::from_value::<Lenient>(json!({ "bytes": "c0 ff eg" })).unwrap_err();
+        assert_eq!(err.to_string(), "invalid hex character 'g' at position 7");
+        let err = serde_json::from_value::<Lenient>(json!({ "bytes": "c0 f" })).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "hex string has odd length 3; expected even number of characters"
+        );
+
+        let err = serde_json::from_value::<Test>(json!({
+            "buffer": "00010203 4050607",
+            "other_field": "test",
+        }))
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid hex character ' ' at position 8"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn configured_hex_forms() {
+        enum PrefixedLenientConfig {}
+
+        impl HexConfig for PrefixedLenientConfig {
+            const PREFIX: Option<&'static str> = Some("0x");
+            const STRIP_WHITESPACE: bool = true;
+        }
+
+        enum StrictConfig {}
+
+        impl HexConfig for StrictConfig {
+            const ACCEPT_SEQ: bool = false;
+        }
+
+        enum CustomConfig {}
+
+        impl HexConfig for CustomConfig {
+            const UPPER_CASE: bool = true;
+            const PREFIX: Option<&'static str> = Some("hex:");
+            const STRIP_WHITESPACE: bool = true;
+            const ACCEPT_SEQ: bool = false;
+        }
+
+        fn serialize<C: HexConfig>(bytes: &[u8]) -> serde_json::Value {
+            ConfiguredHexForm::<Vec<u8>, C>::serialize(
+                &bytes.to_vec(),
+                serde_json::value::Serializer,
+            )
+            .unwrap()
+        }
+
+        fn deserialize<C: HexConfig>(json: serde_json::Value) -> Result<Vec<u8>, String> {
+            ConfiguredHexForm::<Vec<u8>, C>::deserialize(json).map_err(|err| err.to_string())
+        }
+
+        let bytes = [0xc0, 0xff, 0xee];
+        assert_eq!(serialize::<DefaultHexConfig>(&bytes), "c0ffee");
+        assert_eq!(serialize::<UpperHexConfig>(&bytes), "C0FFEE");
+        assert_eq!(serialize::<PrefixedHexConfig>(&bytes), "0xc0ffee");
+        assert_eq!(serialize::<PrefixedUpperHexConfig>(&bytes), "0xC0FFEE");
+        assert_eq!(serialize::<LenientHexConfig>(&bytes), "c0ffee");
+        assert_eq!(serialize::<CustomConfig>(&bytes), "hex:C0FFEE");
+
+        // Case is ignored on deserialization.
+        assert_eq!(
+            deserialize::<DefaultHexConfig>(json!("C0ffEE")).unwrap(),
+            bytes
+        );
+        assert_eq!(
+            deserialize::<UpperHexConfig>(json!("c0ffee")).unwrap(),
+            bytes
+        );
+
+        // Prefix
+        assert_eq!(
+            deserialize::<PrefixedHexConfig>(json!("0xc0ffee")).unwrap(),
+            bytes
+        );
+        let err = deserialize::<PrefixedHexConfig>(json!("c0ffee")).unwrap_err();
+        assert!(err.contains("expected `0x`-prefixed hex string"), "{err}");
+
+        // Whitespace stripping
+        let err = deserialize::<DefaultHexConfig>(json!("c0 ffee ")).unwrap_err();
+        assert_eq!(err, "invalid hex character ' ' at position 2");
+        assert_eq!(
+            deserialize::<LenientHexConfig>(json!("c0 ff ee")).unwrap(),
+            bytes
+        );
+        let err = deserialize::<PrefixedLenientConfig>(json!("c0 ff ee")).unwrap_err();
+        assert!(err.contains("expected `0x`-prefixed hex string"), "{err}");
+        let value = deserialize::<PrefixedLenientConfig>(json!("0x c0 ff ee")).unwrap();
+        assert_eq!(value, bytes);
+
+        // Byte sequences
+        assert_eq!(
+            deserialize::<DefaultHexConfig>(json!([192, 255, 238])).unwrap(),
+            bytes
+        );
+        let err = deserialize::<StrictConfig>(json!([192, 255, 238])).unwrap_err();
+        assert!(err.starts_with("invalid type: sequence"), "{err}");
+        assert_eq!(deserialize::<StrictConfig>(json!("c0ffee")).unwrap(), bytes);
+
+        // All options combined
+        let value = deserialize::<CustomConfig>(json!("hex:C0 FF ee")).unwrap();
+        assert_eq!(value, bytes);
+        assert!(deserialize::<CustomConfig>(json!("C0FFEE")).is_err());
+        assert!(deserialize::<CustomConfig>(json!([192, 255, 238])).is_err());
+
+        // Binary serialization is not affected.
+        let encoded = bincode::serialize(&HexBuf::<_, ConfiguredHexForm<_, CustomConfig>>::new(
+            bytes.to_vec(),
+        ))
+        .unwrap();
+        assert_eq!(encoded[8..], bytes);
+    }
+
+    #[test]
+    fn error_reporting_for_odd_length() {
+        let err = serde_json::from_value::<HexBuf<Vec<u8>>>(json!("abc")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "hex string has odd length 3; expected even number of characters"
+        );
+
+        let err =
+            serde_json::from_value::<HexBuf<Vec<u8>, PrefixedHexForm<Vec<u8>>>>(json!("0xabc"))
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "hex string has odd length 3; expected even number of characters"
+        );
+    }
+
+    #[test]
+    fn error_reporting_for_invalid_chars() {
+        let bogus_buffers = [
+            ("b0gus0", "'g'", 2),
+            ("c0ffeg", "'g'", 5),
+            ("0001020304 50607", "' '", 10),
+            ("00010203040506\u{fc}", "(byte 0xc3)", 14),
+        ];
+
+        for (buffer, found, position) in bogus_buffers {
+            let bogus_json = json!({ "buffer": buffer, "other_field": "test" });
+            let err = serde_json::from_value::<Test>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            let expected_err = format!("invalid hex character {found} at position {position}");
+            assert_eq!(err, expected_err);
+        }
+    }
+
+    #[test]
+    fn internal_type_with_derived_serde_code() {
+        // ...and here, we may use original `serde` code.
+        #[derive(Serialize, Deserialize)]
+        struct OriginalTest {
+            buffer: Buffer,
+            other_field: String,
+        }
+
+        let test = Test {
+            buffer: Buffer([1; 8]),
+            other_field: "a".to_owned(),
+        };
+        assert_eq!(
+            serde_json::to_value(test).unwrap(),
+            json!({
+                "buffer": "0101010101010101",
+                "other_field": "a",
+            })
+        );
+
+        let test = OriginalTest {
+            buffer: Buffer([1; 8]),
+            other_field: "a".to_owned(),
+        };
+        assert_eq!(
+            serde_json::to_value(test).unwrap(),
+            json!({
+                "buffer": [1, 1, 1, 1, 1, 1, 1, 1],
+                "other_field": "a",
+            })
+        );
+    }
+
+    #[test]
+    fn always_hex_and_binary_flags() {
+        struct ModeHex<const HEX: bool, const BINARY: bool>(());
+
+        impl<const HEX: bool, const BINARY: bool> Hex<Vec<u8>> for ModeHex<HEX, BINARY> {
+            type Error = core::convert::Infallible;
+            const ALWAYS_HEX: bool = HEX;
+            const ALWAYS_BINARY: bool = BINARY;
+
+            fn create_bytes(value: &Vec<u8>) -> Cow<'_, [u8]> {
+                Cow::Borrowed(value)
+            }
+
+            fn from_bytes(bytes: &[u8]) -> Result<Vec<u8>, Self::Error> {
+                Ok(bytes.to_vec())
+            }
+        }
+
+        fn assert_roundtrip<H: Hex<Vec<u8>>>(
+            expected_json: &serde_json::Value,
+            expected_bincode: &[u8],
+        ) {
+            let value = vec![0xc0, 0xff, 0xee];
+            let json = H::serialize(&value, serde_json::value::Serializer).unwrap();
+            assert_eq!(json, *expected_json);
+            assert_eq!(H::deserialize(json).unwrap(), value);
+
+            let mut bytes = vec![];
+            let mut serializer = bincode::Serializer::new(&mut bytes, bincode::options());
+            H::serialize(&value, &mut serializer).unwrap();
+            assert_eq!(bytes, expected_bincode);
+            let mut deserializer = bincode::Deserializer::from_slice(&bytes, bincode::options());
+            assert_eq!(H::deserialize(&mut deserializer).unwrap(), value);
+        }
+
+        let hex_json = json!("c0ffee");
+        let hex_bincode = [6, b'c', b'0', b'f', b'f', b'e', b'e'];
+        let binary_json = json!([0xc0, 0xff, 0xee]);
+        let binary_bincode = [3, 0xc0, 0xff, 0xee];
+
+        assert_roundtrip::<ModeHex<false, false>>(&hex_json, &binary_bincode);
+        assert_roundtrip::<ModeHex<true, false>>(&hex_json, &hex_bincode);
+        assert_roundtrip::<ModeHex<false, true>>(&binary_json, &binary_bincode);
+        // `ALWAYS_HEX` takes precedence.
+        assert_roundtrip::<ModeHex<true, true>>(&hex_json, &hex_bincode);
+    }
+
+    #[test]
+    fn custom_type_name() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct KeyHolder {
+            #[serde(with = "PublicKeyHex")]
+            key: Vec<u8>,
+        }
+
+        struct PublicKeyHex(());
+
+        impl Hex<Vec<u8>> for PublicKeyHex {
+            type Error = &'static str;
+            const TYPE_NAME: &'static str = "PublicKey";
+
+            fn create_bytes(value: &Vec<u8>) -> Cow<'_, [u8]> {
+                Cow::Borrowed(value)
+            }
+
+            fn from_bytes(bytes: &[u8]) -> Result<Vec<u8>, Self::Error> {
+                Ok(bytes.to_vec())
+            }
+        }
+
+        let json = json!({ "key": 1 });
+        let err = serde_json::from_value::<KeyHolder>(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid type: integer `1`, expected hex-encoded PublicKey"
+        );
+
+        // Check that the default type name is used by other implementations.
+        let json = json!({ "buffer": 1, "other_field": "abc" });
+        let err = serde_json::from_value::<Test>(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid type: integer `1`, expected hex-encoded byte array"
+        );
+    }
+
+    #[test]
+    fn custom_expecting() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct KeyHolder {
+            #[serde(with = "ForceHexStr::<PublicKeyHex>")]
+            key: Vec<u8>,
+        }
+
+        struct PublicKeyHex(());
+
+        impl Hex<Vec<u8>> for PublicKeyHex {
+            type Error = &'static str;
+
+            fn create_bytes(value: &Vec<u8>) -> Cow<'_, [u8]> {
+                Cow::Borrowed(value)
+            }
+
+            fn from_bytes(bytes: &[u8]) -> Result<Vec<u8>, Self::Error> {
+                Ok(bytes.to_vec())
+            }
+
+            fn expecting(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("32-byte Ed25519 public key in hex encoding")
+            }
+        }
+
+        let json = json!({ "key": 1 });
+        let err = serde_json::from_value::<KeyHolder>(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid type: integer `1`, expected 32-byte Ed25519 public key in hex encoding"
+        );
+    }
+
+    #[test]
+    fn validated_hex() {
+        struct NonZero(());
+
+        impl BytesValidator for NonZero {
+            fn validate(bytes: &[u8]) -> Result<(), &'static str> {
+                if bytes.iter().all(|&byte| byte == 0) {
+                    Err("all-zero buffer")
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        struct AnyBytes(());
+
+        impl BytesValidator for AnyBytes {
+            fn validate(_: &[u8]) -> Result<(), &'static str> {
+                Ok(())
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "ValidatedHex::<HexForm<Buffer>, NonZero>")]
+            buffer: Buffer,
+            #[serde(with = "ValidatedHex::<HexForm<Buffer>, AnyBytes>")]
+            any_buffer: Buffer,
+        }
+
+        type Validated = ValidatedHex<HexForm<Buffer>, NonZero>;
+
+        let value = Test {
+            buffer: Buffer([0, 0, 0, 0, 0, 0, 0, 1]),
+            any_buffer: Buffer([0; 8]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            json!({ "buffer": "0000000000000001", "any_buffer": "0000000000000000" })
+        );
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_jsons = [
+            (
+                json!({ "buffer": "0000000000000000", "any_buffer": "0000000000000000" }),
+                "all-zero buffer",
+            ),
+            (
+                json!({ "buffer": "0001", "any_buffer": "0000000000000000" }),
+                "could not convert slice to array",
+            ),
+            (
+                json!({ "buffer": "0000000000000001", "any_buffer": "00" }),
+                "could not convert slice to array",
+            ),
+        ];
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<Test>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+
+        let bogus_value = Test {
+            buffer: Buffer([0; 8]),
+            any_buffer: Buffer([0; 8]),
+        };
+        let bytes = bincode::serialize(&bogus_value).unwrap();
+        let err = bincode::deserialize::<Test>(&bytes).unwrap_err();
+        assert_eq!(err.to_string(), "all-zero buffer");
+
+        // The validator is applied outside of `serde` as well.
+        let err = Validated::from_bytes(&[0; 8]).unwrap_err();
+        assert_eq!(err.to_string(), "all-zero buffer");
+        let err = HexBuf::<Buffer, Validated>::from_bytes(&[0; 8]).unwrap_err();
+        assert_eq!(err.to_string(), "all-zero buffer");
+        let err = "0000000000000000"
+            .parse::<HexBuf<Buffer, Validated>>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "all-zero buffer");
+        let buffer: HexBuf<Buffer, Validated> = "0000000000000001".parse().unwrap();
+        assert_eq!(buffer.into_inner(), Buffer([0, 0, 0, 0, 0, 0, 0, 1]));
+    }
+
+    #[test]
+    fn length_check_middleware() {
+        struct Len4(());
+
+        impl BytesValidator for Len4 {
+            fn validate(bytes: &[u8]) -> Result<(), &'static str> {
+                if bytes.len() == 4 {
+                    Ok(())
+                } else {
+                    Err("expected 4 bytes")
+                }
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "ValidatedHex::<HexForm<Vec<u8>>, Len4>")]
+            bytes: Vec<u8>,
+        }
+
+        let value = Test {
+            bytes: vec![0xde, 0xad, 0xbe, 0xef],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "bytes": "deadbeef" }));
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(
+            bytes,
+            bincode::serialize(&[0xde_u8, 0xad, 0xbe, 0xef][..]).unwrap()
+        );
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        for bogus_hex in ["", "deadbe", "deadbeef00"] {
+            let err = serde_json::from_value::<Test>(json!({ "bytes": bogus_hex })).unwrap_err();
+            assert_eq!(err.to_string(), "expected 4 bytes");
+        }
+        let bytes = bincode::serialize(&[0_u8; 5][..]).unwrap();
+        let err = bincode::deserialize::<Test>(&bytes).unwrap_err();
+        assert_eq!(err.to_string(), "expected 4 bytes");
+    }
+
+    #[test]
+    fn validated_hex_retains_format_and_validates_once() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALL_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        struct CountingValidator(());
+
+        impl BytesValidator for CountingValidator {
+            fn validate(_: &[u8]) -> Result<(), &'static str> {
+                CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "ValidatedHex::<PrefixedUpperHexForm<Vec<u8>>, CountingValidator>")]
+            bytes: Vec<u8>,
+        }
+
+        let value = Test {
+            bytes: vec![0xc0, 0xff, 0xee],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "bytes": "0xC0FFEE" }));
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+        assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 1);
+
+        let err = serde_json::from_value::<Test>(json!({ "bytes": "c0ffee" }))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected `0x`-prefixed hex string"), "{err}");
+        assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn form_trait_impls() {
+        fn clone_via_trait<T: Clone>(value: &T) -> T {
+            value.clone()
+        }
+
+        // `Vec<u8>` is not `Copy`, but the form should be.
+        let form = HexForm::<Vec<u8>>::default();
+        let form_copy = form;
+        assert_eq!(form, form_copy);
+        assert_eq!(clone_via_trait(&form), form);
+
+        let buf = HexBuf::<[u8; 4]>::default();
+        assert_eq!(*buf, [0; 4]);
+        let buf_copy = buf;
+        assert_eq!(buf_copy, buf);
+    }
+
+    #[test]
+    fn external_type() {
+        #[derive(Debug, PartialEq, Eq)]
+        pub struct Buffer([u8; 8]);
+
+        struct BufferHex(());
+
+        impl Hex<Buffer> for BufferHex {
+            type Error = &'static str;
+
+            fn create_bytes(buffer: &Buffer) -> Cow<'_, [u8]> {
+                Cow::Borrowed(&buffer.0)
+            }
+
+            fn from_bytes(bytes: &[u8]) -> Result<Buffer, Self::Error> {
+                if bytes.len() == 8 {
+                    let mut inner = [0; 8];
+                    inner.copy_from_slice(bytes);
+                    Ok(Buffer(inner))
+                } else {
+                    Err("invalid buffer length")
+                }
+            }
+        }
+
+        #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "BufferHex")]
+            buffer: Buffer,
+            other_field: String,
+        }
+
+        let json = json!({ "buffer": "0001020304050607", "other_field": "abc" });
+        let value: Test = serde_json::from_value(json.clone()).unwrap();
+        assert!(value
+            .buffer
+            .0
+            .iter()
+            .enumerate()
+            .all(|(i, &byte)| i == usize::from(byte)));
+
+        let json_copy = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json_copy);
+
+        // Test binary / non-human readable format.
+        let buffer = bincode::serialize(&value).unwrap();
+        // Conversion to hex is needed to be able to search for a pattern.
+        let buffer_hex = hex::encode(&buffer);
+        // Check that the buffer is stored in the serialization compactly,
+        // as original bytes.
+        let needle = "0001020304050607";
+        assert!(buffer_hex.contains(needle));
+
+        let value_copy: Test = bincode::deserialize(&buffer).unwrap();
+        assert_eq!(value_copy, value);
+    }
+
+    #[test]
+    fn deserializing_flattened_field() {
+        // The fields in the flattened structure are somehow read with
+        // a human-readable `Deserializer`, even if the original `Deserializer`
+        // is not human-readable.
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Inner {
+            #[serde(with = "HexForm")]
+            x: Vec<u8>,
+            #[serde(with = "HexForm")]
+            y: [u8; 16],
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Outer {
+            #[serde(flatten)]
+            inner: Inner,
+            z: String,
+        }
+
+        let value = Outer {
+            inner: Inner {
+                x: vec![1; 8],
+                y: [0; 16],
+            },
+            z: "test".to_owned(),
+        };
+
+        let mut bytes = vec![];
+        ciborium::into_writer(&value, &mut bytes).unwrap();
+        let bytes_hex = hex::encode(&bytes);
+        // Check that byte buffers are stored in the binary form.
+        assert!(bytes_hex.contains(&"01".repeat(8)));
+        assert!(bytes_hex.contains(&"00".repeat(16)));
+        let value_copy = ciborium::from_reader(&bytes[..]).unwrap();
+        assert_eq!(value, value_copy);
+    }
+
+    #[test]
+    fn upper_case_serialization() {
+        struct UpperStrHex(());
+
+        impl Hex<Vec<u8>> for UpperStrHex {
+            type Error = core::convert::Infallible;
+            const ALWAYS_HEX: bool = true;
+            const UPPER_CASE: bool = true;
+
+            fn create_bytes(value: &Vec<u8>) -> Cow<'_, [u8]> {
+                Cow::Borrowed(value)
+            }
+
+            fn from_bytes(bytes: &[u8]) -> Result<Vec<u8>, Self::Error> {
+                Ok(bytes.to_vec())
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct UpperTest {
+            #[serde(with = "UpperHexForm::<Buffer>")]
+            buffer: Buffer,
+            #[serde(with = "UpperHexForm")]
+            bytes: Vec<u8>,
+        }
+
+        let value = UpperTest {
+            buffer: Buffer([0xab; 8]),
+            bytes: vec![0xc0, 0xff, 0xee],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            json!({ "buffer": "ABABABABABABABAB", "bytes": "C0FFEE" })
+        );
+        let value_copy: UpperTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        // Lower-case and mixed-case inputs should be accepted as well.
+        let json = json!({ "buffer": "abababababababab", "bytes": "c0FfEe" });
+        let value_copy: UpperTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+        let json = serde_json::to_value(&value_copy).unwrap();
+        assert_eq!(json["bytes"], "C0FFEE");
+
+        // Upper-case output should be readable with `HexForm`.
+        let test: Test =
+            serde_json::from_value(json!({ "buffer": "ABABABABABABABAB", "other_field": "" }))
+                .unwrap();
+        assert_eq!(test.buffer.0, [0xab; 8]);
+
+        // Binary serialization should be unaffected.
+        let bytes = bincode::serialize(&value).unwrap();
+        assert!(hex::encode(&bytes).contains("c0ffee"));
+        let value_copy: UpperTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        // `UPPER_CASE` should combine with other format constants.
+        let mut bytes = vec![];
+        let mut serializer = bincode::Serializer::new(&mut bytes, bincode::options());
+        UpperStrHex::serialize(&value.bytes, &mut serializer).unwrap();
+        assert_eq!(bytes, [6, b'C', b'0', b'F', b'F', b'E', b'E']);
+        let mut deserializer = bincode::Deserializer::from_slice(&bytes, bincode::options());
+        assert_eq!(
+            UpperStrHex::deserialize(&mut deserializer).unwrap(),
+            value.bytes
+        );
+    }
+
+    #[test]
+    fn prefixed_serialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct PrefixedTest {
+            #[serde(with = "PrefixedHexForm::<Buffer>")]
+            buffer: Buffer,
+            #[serde(with = "PrefixedUpperHexForm")]
+            bytes: Vec<u8>,
+            #[serde(with = "PrefixedHexForm")]
+            empty: Vec<u8>,
+        }
+
+        let value = PrefixedTest {
+            buffer: Buffer([0xab; 8]),
+            bytes: vec![0xc0, 0xff, 0xee],
+            empty: vec![],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            json!({ "buffer": "0xabababababababab", "bytes": "0xC0FFEE", "empty": "0x" })
+        );
+        let value_copy: PrefixedTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let bytes_hex = hex::encode(&bytes);
+        assert!(bytes_hex.contains("abababababababab"));
+        assert!(bytes_hex.contains("c0ffee"));
+        let value_copy: PrefixedTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+    }
+
+    #[test]
+    fn prefixed_deserialization_errors() {
+        #[derive(Debug, Deserialize)]
+        struct PrefixedTest {
+            #[serde(with = "PrefixedHexForm::<Buffer>", rename = "buffer")]
+            _buffer: Buffer,
+            #[serde(with = "PrefixedUpperHexForm", rename = "bytes")]
+            _bytes: Vec<u8>,
+            #[serde(with = "PrefixedHexForm", rename = "empty")]
+            _empty: Vec<u8>,
+        }
+
+        let bogus_jsons = [
+            json!({ "buffer": "abababababababab", "bytes": "0x", "empty": "0x" }),
+            json!({ "buffer": "0xabababababababab", "bytes": "C0FFEE", "empty": "0x" }),
+            json!({ "buffer": "0Xabababababababab", "bytes": "0x", "empty": "0x" }),
+        ];
+
+        for bogus_json in bogus_jsons {
+            let err = serde_json::from_value::<PrefixedTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("invalid value"), "{err}");
+            assert!(err.contains("expected `0x`-prefixed hex string"), "{err}");
+        }
+
+        let bogus_json = json!({ "buffer": "0xabab", "bytes": "0x", "empty": "0x" });
+        let err = serde_json::from_value::<PrefixedTest>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("could not convert slice to array"), "{err}");
+
+        let bogus_json = json!({ "buffer": "0x0102zzabababab", "bytes": "0x", "empty": "0x" });
+        let err = serde_json::from_value::<PrefixedTest>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "invalid hex character 'z' at position 6");
+    }
+
+    #[test]
+    fn custom_prefix() {
+        struct TaggedHex(());
+
+        impl Hex<Vec<u8>> for TaggedHex {
+            type Error = core::convert::Infallible;
+            const UPPER_CASE: bool = true;
+            const PREFIX: Option<&'static str> = Some("hex:");
+
+            fn create_bytes(value: &Vec<u8>) -> Cow<'_, [u8]> {
+                Cow::Borrowed(value)
+            }
+
+            fn from_bytes(bytes: &[u8]) -> Result<Vec<u8>, Self::Error> {
+                Ok(bytes.to_vec())
+            }
+        }
+
+        let bytes = vec![0xc0, 0xff, 0xee];
+        let json = TaggedHex::serialize(&bytes, serde_json::value::Serializer).unwrap();
+        assert_eq!(json, "hex:C0FFEE");
+        assert_eq!(TaggedHex::deserialize(json).unwrap(), bytes);
+        assert_eq!(TaggedHex::deserialize(json!("hex:c0ffee")).unwrap(), bytes);
+
+        let err = TaggedHex::deserialize(json!("c0ffee"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected `hex:`-prefixed hex string"), "{err}");
+    }
+
+    #[test]
+    fn deserializing_from_byte_sequence() {
+        #[derive(Debug, Deserialize)]
+        struct VecTest {
+            #[serde(with = "HexForm")]
+            bytes: Vec<u8>,
+        }
+
+        let json = json!({ "buffer": [0, 1, 2, 3, 4, 5, 6, 7], "other_field": "abc" });
+        let value: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value.buffer.0, [0, 1, 2, 3, 4, 5, 6, 7]);
+        // Serialization should still produce a hex string.
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json["buffer"], "0001020304050607");
+
+        let json_str = r#"{ "buffer": [0, 1, 2, 3, 4, 5, 6, 7], "other_field": "abc" }"#;
+        let value: Test = serde_json::from_str(json_str).unwrap();
+        assert_eq!(value.buffer.0, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+        let value: VecTest = serde_json::from_str(r#"{ "bytes": [] }"#).unwrap();
+        assert!(value.bytes.is_empty());
+        let value: VecTest = serde_json::from_str(r#"{ "bytes": [192, 255, 238] }"#).unwrap();
+        assert_eq!(value.bytes, [0xc0, 0xff, 0xee]);
+        let value: VecTest = serde_json::from_str(r#"{ "bytes": "c0ffee" }"#).unwrap();
+        assert_eq!(value.bytes, [0xc0, 0xff, 0xee]);
+    }
+
+    #[test]
+    fn error_reporting_for_byte_sequence() {
+        let bogus_jsons = [
+            (
+                json!([0, 1, 2, 3, 4, 5, 6, 256]),
+                "invalid value: integer `256`",
+            ),
+            (
+                json!([0, 1, 2, -3, 4, 5, 6, 7]),
+                "invalid value: integer `-3`",
+            ),
+            (
+                json!([0, 1, 2, "3", 4, 5, 6, 7]),
+                "invalid type: string \"3\"",
+            ),
+            (json!([0, 1, 2]), "could not convert slice to array"),
+            (json!(true), "expected hex-encoded byte array"),
+        ];
+
+        for (buffer, expected_err) in bogus_jsons {
+            let bogus_json = json!({ "buffer": buffer, "other_field": "abc" });
+            let err = serde_json::from_value::<Test>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+    }
+
+    #[test]
+    fn default_values() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct DefaultTest {
+            #[serde(with = "HexForm", default)]
+            bytes: Vec<u8>,
+            tag: u8,
+        }
+
+        let value: DefaultTest = serde_json::from_value(json!({ "tag": 1 })).unwrap();
+        assert_eq!(
+            value,
+            DefaultTest {
+                bytes: vec![],
+                tag: 1
+            }
+        );
+
+        let value: DefaultTest =
+            serde_json::from_value(json!({ "bytes": "c0ffee", "tag": 1 })).unwrap();
+        assert_eq!(value.bytes, [0xc0, 0xff, 0xee]);
+    }
+
+    #[test]
+    fn utf8_strings() {
+        use crate::testing::{assert_binary_roundtrip, assert_decode_error, assert_hex_roundtrip};
+
+        assert_hex_roundtrip::<_, Utf8HexForm>(&"hello".to_owned(), "68656c6c6f");
+        assert_hex_roundtrip::<_, Utf8HexForm>(&String::new(), "");
+        assert_hex_roundtrip::<_, Utf8HexForm>(&"\u{1f980}".to_owned(), "f09fa680");
+        assert_binary_roundtrip::<_, Utf8HexForm>(&"hello".to_owned());
+
+        assert_decode_error::<String, Utf8HexForm>(
+            "68ff6c",
+            "decoded bytes are not valid UTF-8: invalid utf-8 sequence of 1 bytes from index 1",
+        );
+        assert_decode_error::<String, Utf8HexForm>("f09fa6", "not valid UTF-8");
+        assert_decode_error::<String, Utf8HexForm>("6865z", "odd length");
+    }
+
+    #[test]
+    fn optional_values() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct OptionalTest {
+            #[serde(with = "HexOption::<HexForm<Buffer>>")]
+            buffer: Option<Buffer>,
+            #[serde(with = "HexOption::<UpperHexForm<Vec<u8>>>", default)]
+            bytes: Option<Vec<u8>>,
+        }
+
+        let values = [
+            OptionalTest {
+                buffer: Some(Buffer([1; 8])),
+                bytes: Some(vec![0xc0, 0xff, 0xee]),
+            },
+            OptionalTest {
+                buffer: None,
+                bytes: Some(vec![]),
+            },
+            OptionalTest {
+                buffer: Some(Buffer([0; 8])),
+                bytes: None,
+            },
+        ];
+        let expected_jsons = [
+            json!({ "buffer": "0101010101010101", "bytes": "C0FFEE" }),
+            json!({ "buffer": null, "bytes": "" }),
+            json!({ "buffer": "0000000000000000", "bytes": null }),
+        ];
+
+        for (value, expected_json) in values.iter().zip(expected_jsons) {
+            let json = serde_json::to_value(value).unwrap();
+            assert_eq!(json, expected_json);
+            let value_copy: OptionalTest = serde_json::from_value(json).unwrap();
+            assert_eq!(value_copy, *value);
+
+            let bytes = bincode::serialize(value).unwrap();
+            assert_eq!(bytes[0], u8::from(value.buffer.is_some()));
+            let value_copy: OptionalTest = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(value_copy, *value);
+        }
+
+        let value: OptionalTest = serde_json::from_value(json!({ "buffer": null })).unwrap();
+        assert_eq!(value.bytes, None);
+
+        let bogus_json = json!({ "buffer": "010101010101010g", "bytes": null });
+        let err = serde_json::from_value::<OptionalTest>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "invalid hex character 'g' at position 15");
+        let bogus_json = json!({ "buffer": "01", "bytes": null });
+        let err = serde_json::from_value::<OptionalTest>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("could not convert slice to array"), "{err}");
+    }
+
+    #[test]
+    fn vectors() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct VecTest {
+            #[serde(with = "HexVec::<HexForm<Buffer>>")]
+            buffers: Vec<Buffer>,
+            #[serde(with = "HexVec::<UpperHexForm<Vec<u8>>>")]
+            byte_vecs: Vec<Vec<u8>>,
+        }
+
+        let values = [
+            VecTest {
+                buffers: vec![],
+                byte_vecs: vec![],
+            },
+            VecTest {
+                buffers: vec![Buffer([1; 8]), Buffer([0xab; 8])],
+                byte_vecs: vec![vec![], vec![0xc0, 0xff, 0xee]],
+            },
+        ];
+        let expected_jsons = [
+            json!({ "buffers": [], "byte_vecs": [] }),
+            json!({
+                "buffers": ["0101010101010101", "abababababababab"],
+                "byte_vecs": ["", "C0FFEE"],
+            }),
+        ];
+
+        for (value, expected_json) in values.iter().zip(expected_jsons) {
+            let json = serde_json::to_value(value).unwrap();
+            assert_eq!(json, expected_json);
+            let value_copy: VecTest = serde_json::from_value(json).unwrap();
+            assert_eq!(value_copy, *value);
+
+            let bytes = bincode::serialize(value).unwrap();
+            let value_copy: VecTest = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(value_copy, *value);
+        }
+
+        let bytes = bincode::serialize(&values[1]).unwrap();
+        let bytes_hex = hex::encode(bytes);
+        assert!(bytes_hex.contains("0101010101010101"));
+        assert!(bytes_hex.contains("abababababababab"));
+        assert!(bytes_hex.contains("c0ffee"));
+    }
+
+    #[test]
+    fn error_reporting_for_vectors() {
+        #[derive(Debug, Deserialize)]
+        struct VecTest {
+            #[serde(with = "HexVec::<HexForm<Buffer>>")]
+            _buffers: Vec<Buffer>,
+        }
+
+        let bogus_jsons = [
+            (
+                json!({ "_buffers": ["0101010101010101", "01"] }),
+                "element 1: could not convert slice to array",
+            ),
+            (
+                json!({ "_buffers": ["010101010101010g"] }),
+                "element 0: invalid hex character 'g' at position 15",
+            ),
+            (
+                json!({ "_buffers": ["0101010101010101", "0101010101010101", true] }),
+                "element 2: invalid type: boolean `true`, expected hex-encoded byte array",
+            ),
+            (
+                json!({ "_buffers": "0101010101010101" }),
+                "expected sequence of hex-encoded byte arrays",
+            ),
+        ];
+
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<VecTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+    }
+
+    #[test]
+    fn forced_hex_strings() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ForceTest {
+            #[serde(with = "ForceHexStr::<HexForm<Buffer>>")]
+            buffer: Buffer,
+            #[serde(with = "HexVec::<ForceHexStr<UpperHexForm<Vec<u8>>>>")]
+            byte_vecs: Vec<Vec<u8>>,
+        }
+
+        #[derive(Serialize)]
+        struct StrTest<'a> {
+            buffer: &'a str,
+            byte_vecs: &'a [&'a str],
+        }
+
+        let value = ForceTest {
+            buffer: Buffer([0xab; 8]),
+            byte_vecs: vec![vec![], vec![0xc0, 0xff, 0xee]],
+        };
+        let bytes = bincode::serialize(&value).unwrap();
+        let expected_bytes = bincode::serialize(&StrTest {
+            buffer: "abababababababab",
+            byte_vecs: &["", "C0FFEE"],
+        });
+        assert_eq!(bytes, expected_bytes.unwrap());
+        let value_copy: ForceTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            json!({ "buffer": "abababababababab", "byte_vecs": ["", "C0FFEE"] })
+        );
+        let value_copy: ForceTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        // Byte sequences are not accepted.
+        let bogus_json = json!({ "buffer": [1, 2, 3, 4, 5, 6, 7, 8], "byte_vecs": [] });
+        let err = serde_json::from_value::<ForceTest>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected hex-encoded byte array"), "{err}");
+
+        // The prefix of the wrapped form should be retained.
+        let mut bytes = vec![];
+        let mut serializer = bincode::Serializer::new(&mut bytes, bincode::options());
+        ForceHexStr::<PrefixedHexForm<Vec<u8>>>::serialize(&vec![1, 2], &mut serializer).unwrap();
+        assert_eq!(bytes, [6, b'0', b'x', b'0', b'1', b'0', b'2']);
+        let mut deserializer = bincode::Deserializer::from_slice(&bytes, bincode::options());
+        let value = ForceHexStr::<PrefixedHexForm<Vec<u8>>>::deserialize(&mut deserializer);
+        assert_eq!(value.unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn wrappers_forward_wire_format() {
+        struct NonEmpty(());
+
+        impl BytesValidator for NonEmpty {
+            fn validate(bytes: &[u8]) -> Result<(), &'static str> {
+                if bytes.is_empty() {
+                    Err("empty buffer")
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct WrappedTest {
+            #[serde(with = "ExactLenHex::<ForceHexStr<HexForm<Vec<u8>>>, 2>")]
+            exact: Vec<u8>,
+            #[serde(with = "ValidatedHex::<ForceHexStr<HexForm<Vec<u8>>>, NonEmpty>")]
+            validated: Vec<u8>,
+        }
+
+        #[derive(Serialize)]
+        struct StrTest<'a> {
+            exact: &'a str,
+            validated: &'a str,
+        }
+
+        let value = WrappedTest {
+            exact: vec![0xc0, 0xff],
+            validated: vec![0xee],
+        };
+        let bytes = bincode::serialize(&value).unwrap();
+        let expected_bytes = bincode::serialize(&StrTest {
+            exact: "c0ff",
+            validated: "ee",
+        });
+        assert_eq!(bytes, expected_bytes.unwrap());
+        let value_copy: WrappedTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let err = ExactLenHex::<Utf8HexForm, 2>::deserialize(json!(42)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid type: integer `42`, expected hex-encoded UTF-8 string"
+        );
+        let err = ValidatedHex::<Utf8HexForm, NonEmpty>::deserialize(json!(42)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid type: integer `42`, expected hex-encoded UTF-8 string"
+        );
+    }
+
+    #[test]
+    fn forced_binary_serialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ForceTest {
+            #[serde(with = "ForceBinary::<HexForm<Vec<u8>>>")]
+            bytes: Vec<u8>,
+            #[serde(with = "HexOption::<ForceBinary<HexForm<Buffer>>>")]
+            buffer: Option<Buffer>,
+        }
+
+        let value = ForceTest {
+            bytes: vec![0xc0, 0xff, 0xee],
+            buffer: Some(Buffer([1; 8])),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            json!({ "bytes": [0xc0, 0xff, 0xee], "buffer": [1, 1, 1, 1, 1, 1, 1, 1] })
+        );
+        let value_copy: ForceTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: ForceTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        // Hex strings are not accepted.
+        let bogus_json = json!({ "bytes": [], "buffer": "0101010101010101" });
+        let err = serde_json::from_value::<ForceTest>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected byte array"), "{err}");
+    }
+
+    #[test]
+    fn colon_separated_serialization() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ColonTest {
+            #[serde(with = "ColonHexForm")]
+            mac: [u8; 6],
+            #[serde(with = "ColonHexForm")]
+            bytes: Vec<u8>,
+        }
+
+        let value = ColonTest {
+            mac: [0x01, 0x23, 0x45, 0x67, 0x89, 0xab],
+            bytes: vec![],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "mac": "01:23:45:67:89:AB", "bytes": "" }));
+        let value_copy: ColonTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = json!({ "mac": "0123456789ab", "bytes": "" });
+        let value_copy: ColonTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert!(hex::encode(&bytes).contains("0123456789ab"));
+        let value_copy: ColonTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_macs = [
+            ("01:23:45:67:89:", "expected colon-separated hex string"),
+            ("0123:45:67:89:ab", "expected colon-separated hex string"),
+            ("01::23:45:67:89:ab", "expected colon-separated hex string"),
+            (
+                "01:23:45:67:89:ag",
+                "invalid hex character 'g' at position 16",
+            ),
+            ("01:23:45:67:89", "could not convert slice to array"),
+        ];
+        for (bogus_mac, expected_err) in bogus_macs {
+            let bogus_json = json!({ "mac": bogus_mac, "bytes": "" });
+            let err = serde_json::from_value::<ColonTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+    }
+
+    #[test]
+    fn validating_bytes() {
+        /// Rejects empty and all-zero buffers.
+        struct NonZeroHex(());
+
+        impl Hex<Vec<u8>> for NonZeroHex {
+            type Error = &'static str;
+
+            fn create_bytes(buffer: &Vec<u8>) -> Cow<'_, [u8]> {
+                Cow::Borrowed(buffer)
+            }
+
+            fn from_bytes(bytes: &[u8]) -> Result<Vec<u8>, Self::Error> {
+                Ok(bytes.to_vec())
+            }
+
+            fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+                if bytes.iter().all(|&byte| byte == 0) {
+                    Err("buffer is all zeros")
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ValidatedTest {
+            #[serde(with = "NonZeroHex")]
+            buffer: Vec<u8>,
+            #[serde(with = "HexVec::<ForceHexStr<NonZeroHex>>")]
+            buffers: Vec<Vec<u8>>,
+        }
+
+        let value = ValidatedTest {
+            buffer: vec![0, 1],
+            buffers: vec![vec![2]],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "buffer": "0001", "buffers": ["02"] }));
+        let value_copy: ValidatedTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_jsons = [
+            (
+                json!({ "buffer": "0000", "buffers": [] }),
+                "buffer is all zeros",
+            ),
+            (
+                json!({ "buffer": "", "buffers": [] }),
+                "buffer is all zeros",
+            ),
+            (
+                json!({ "buffer": "01", "buffers": ["01", "00"] }),
+                "element 1: buffer is all zeros",
+            ),
+        ];
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<ValidatedTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+
+        let bogus_value = ValidatedTest {
+            buffer: vec![0; 4],
+            buffers: vec![],
+        };
+        let bytes = bincode::serialize(&bogus_value).unwrap();
+        let err = bincode::deserialize::<ValidatedTest>(&bytes).unwrap_err();
+        assert_eq!(err.to_string(), "buffer is all zeros");
+    }
+
+    #[test]
+    fn hex_buf_wrapper() {
+        type BufferHex = HexBuf<Buffer, UpperHexForm<Buffer>>;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct HexBufTest {
+            buffer: BufferHex,
+            bytes: Option<HexBuf<Vec<u8>>>,
+            vec: Vec<HexBuf<Vec<u8>, PrefixedHexForm<Vec<u8>>>>,
+        }
+
+        let mut value = HexBufTest {
+            buffer: Buffer([0xab; 8]).into(),
+            bytes: Some(HexBuf::new(vec![0xc0, 0xff, 0xee])),
+            vec: vec![vec![].into(), vec![1, 2].into()],
+        };
+        value.buffer.0[0] = 0xcd; // uses `DerefMut`
+
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "buffer": "CDABABABABABABAB",
+                "bytes": "c0ffee",
+                "vec": ["0x", "0x0102"],
+            })
+        );
+        let value_copy: HexBufTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert!(hex::encode(&bytes).contains("cdababababababab"));
+        let value_copy: HexBufTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+        assert_eq!(
+            value_copy.buffer.clone().into_inner(),
+            Buffer([0xcd, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab])
+        );
+        assert_eq!(
+            format!("{:?}", value_copy.bytes.unwrap()),
+            "HexBuf([192, 255, 238])"
+        );
+
+        let err = serde_json::from_value::<BufferHex>(json!("abab")).unwrap_err();
+        assert_eq!(err.to_string(), "could not convert slice to array");
+    }
+
+    #[test]
+    fn formatting_hex_buf() {
+        let buffer = HexBuf::<Buffer>::new(Buffer([0xc0, 0xff, 0xee, 0, 1, 2, 3, 0xab]));
+        let expected = hex::encode(buffer.0);
+        assert_eq!(buffer.to_string(), expected);
+        assert_eq!(format!("{buffer:x}"), expected);
+        assert_eq!(format!("{buffer:X}"), hex::encode_upper(buffer.0));
+        assert_eq!(format!("{buffer:>20}"), format!("    {expected}"));
+
+        let empty = HexBuf::<Vec<u8>>::new(vec![]);
+        assert_eq!(empty.to_string(), "");
+    }
+
+    #[test]
+    fn parsing_hex_buf() {
+        let buffer: HexBuf<Vec<u8>, HexForm<Vec<u8>>> = "c0FFee".parse().unwrap();
+        assert_eq!(*buffer, [0xc0, 0xff, 0xee]);
+        assert_eq!(buffer.to_string(), hex::encode(&*buffer));
+
+        let empty: HexBuf<Vec<u8>> = "".parse().unwrap();
+        assert!(empty.is_empty());
+
+        let err = "c0ffeg".parse::<HexBuf<Vec<u8>>>().unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ParseHexError::Hex(HexDecodeError::InvalidChar {
+                    position: 5,
+                    byte: b'g'
+                })
+            ),
+            "{err:?}"
+        );
+        let err = "c0ffe".parse::<HexBuf<Vec<u8>>>().unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ParseHexError::Hex(HexDecodeError::OddLength { len: 5 })
+            ),
+            "{err:?}"
+        );
+
+        let err = "c0ffee".parse::<HexBuf<Buffer>>().unwrap_err();
+        assert!(matches!(err, ParseHexError::Conversion(_)), "{err:?}");
+        assert_eq!(err.to_string(), "could not convert slice to array");
+
+        // Bytes are validated in the same way as on deserialization.
+        let err = "0000000000000000"
+            .parse::<HexBuf<Buffer, NonZeroHex>>()
+            .unwrap_err();
+        assert!(matches!(err, ParseHexError::Conversion(_)), "{err:?}");
+        assert_eq!(err.to_string(), "all-zero buffer");
+        let buffer: HexBuf<Buffer, NonZeroHex> = "0000000000000001".parse().unwrap();
+        assert_eq!(buffer.0, [0, 0, 0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn creating_hex_buf_from_bytes() {
+        let buffer = HexBuf::<Vec<u8>>::from_bytes(b"c0ffee").unwrap();
+        // Bytes are not hex-decoded.
+        assert_eq!(*buffer, b"c0ffee");
+        assert_eq!(buffer.to_string(), "633066666565");
+
+        let parsed: HexBuf<Vec<u8>> = "c0ffee".parse().unwrap();
+        assert_eq!(*parsed, [0xc0, 0xff, 0xee]);
+
+        let buffer = HexBuf::<Buffer>::from_bytes(&[1; 8]).unwrap();
+        assert_eq!(buffer.0, [1; 8]);
+        let err = HexBuf::<Buffer>::from_bytes(&[1; 3]).unwrap_err();
+        assert_eq!(err.to_string(), "could not convert slice to array");
+
+        let err = HexBuf::<Buffer, NonZeroHex>::from_bytes(&[0; 8]).unwrap_err();
+        assert_eq!(err, "all-zero buffer");
+        let buffer = HexBuf::<Buffer, NonZeroHex>::from_bytes(&[1; 8]).unwrap();
+        assert_eq!(buffer.0, [1; 8]);
+    }
+
+    #[test]
+    fn bounded_byte_length() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct BoundedTest {
+            #[serde(with = "BoundedHexForm::<_, 16, 32>")]
+            bytes: Vec<u8>,
+            #[serde(with = "HexOption::<BoundedHexForm<Buffer, 8, 8>>")]
+            buffer: Option<Buffer>,
+        }
+
+        for len in [16, 17, 31, 32] {
+            let value = BoundedTest {
+                bytes: vec![0xab; len],
+                buffer: Some(Buffer([1; 8])),
+            };
+            let json = serde_json::to_value(&value).unwrap();
+            assert_eq!(json["bytes"], "ab".repeat(len));
+            let value_copy: BoundedTest = serde_json::from_value(json).unwrap();
+            assert_eq!(value_copy, value);
+
+            let bytes = bincode::serialize(&value).unwrap();
+            let value_copy: BoundedTest = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(value_copy, value);
+        }
+
+        for len in [0, 12, 15, 33] {
+            let bogus_value = BoundedTest {
+                bytes: vec![0xab; len],
+                buffer: None,
+            };
+            let expected_err = format!("expected 16 to 32 bytes, got {len}");
+
+            let json = serde_json::to_value(&bogus_value).unwrap();
+            let err = serde_json::from_value::<BoundedTest>(json).unwrap_err();
+            assert_eq!(err.to_string(), expected_err);
+
+            let bytes = bincode::serialize(&bogus_value).unwrap();
+            let err = bincode::deserialize::<BoundedTest>(&bytes).unwrap_err();
+            assert_eq!(err.to_string(), expected_err);
+        }
+
+        let bogus_json = json!({ "bytes": "ab".repeat(16), "buffer": "01" });
+        let err = serde_json::from_value::<BoundedTest>(bogus_json).unwrap_err();
+        assert_eq!(err.to_string(), "expected exactly 8 bytes, got 1");
+    }
+
+    #[test]
+    fn exact_byte_length() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ExactLenTest {
+            #[serde(with = "ExactLenHex::<HexForm<Vec<u8>>, 4>")]
+            bytes: Vec<u8>,
+            #[serde(with = "HexVec::<ExactLenHex<UpperHexForm<Vec<u8>>, 2>>")]
+            byte_vecs: Vec<Vec<u8>>,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct WrappedFormatTest {
+            #[serde(with = "ExactLenHex::<PrefixedHexForm<Vec<u8>>, 2>")]
+            prefixed: Vec<u8>,
+            #[serde(with = "ExactLenHex::<UpperHexForm<Vec<u8>>, 2>")]
+            upper: Vec<u8>,
+        }
+
+        let value = ExactLenTest {
+            bytes: vec![0xde, 0xad, 0xbe, 0xef],
+            byte_vecs: vec![vec![0xc0, 0xff]],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "bytes": "deadbeef", "byte_vecs": ["C0FF"] }));
+        let value_copy: ExactLenTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: ExactLenTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_jsons = [
+            (
+                json!({ "bytes": "deadbe", "byte_vecs": [] }),
+                "expected exactly 4 bytes, got 3",
+            ),
+            (
+                json!({ "bytes": "deadbeef00", "byte_vecs": [] }),
+                "expected exactly 4 bytes, got 5",
+            ),
+            (
+                json!({ "bytes": "deadbeef", "byte_vecs": ["c0ff", ""] }),
+                "element 1: expected exactly 2 bytes, got 0",
+            ),
+        ];
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<ExactLenTest>(bogus_json).unwrap_err();
+            assert_eq!(err.to_string(), expected_err);
+        }
+
+        let err = <ExactLenHex<HexForm<Buffer>, 8>>::from_bytes(&[0; 7]).unwrap_err();
+        assert_eq!(err.to_string(), "expected exactly 8 bytes, got 7");
+
+        let err = HexForm::<[u8; 4]>::deserialize(json!("c0ffee")).unwrap_err();
+        assert_eq!(err.to_string(), "could not convert slice to array");
+        let err = ExactLenHex::<HexForm<[u8; 4]>, 4>::deserialize(json!("c0ffee")).unwrap_err();
+        assert_eq!(err.to_string(), "expected exactly 4 bytes, got 3");
+
+        // The format of the wrapped implementation should be retained.
+        let value = WrappedFormatTest {
+            prefixed: vec![1, 2],
+            upper: vec![0xc0, 0xff],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "prefixed": "0x0102", "upper": "C0FF" }));
+        let value_copy: WrappedFormatTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: WrappedFormatTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_json = json!({ "prefixed": "0x010203", "upper": "c0ff" });
+        let err = serde_json::from_value::<WrappedFormatTest>(bogus_json).unwrap_err();
+        assert_eq!(err.to_string(), "expected exactly 2 bytes, got 3");
+        let bogus_json = json!({ "prefixed": "0102", "upper": "c0ff" });
+        let err = serde_json::from_value::<WrappedFormatTest>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected `0x`-prefixed hex string"), "{err}");
+    }
+
+    #[test]
+    fn zero_padding() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct PadTest {
+            #[serde(with = "PadHex::<HexForm<[u8; 32]>, 32>")]
+            word: [u8; 32],
+            #[serde(with = "PadHex::<HexForm<Vec<u8>>, 4>")]
+            bytes: Vec<u8>,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ForcedPadTest {
+            #[serde(with = "PadHex::<ForceHexStr<HexForm<Vec<u8>>>, 2>")]
+            bytes: Vec<u8>,
+        }
 
-        let test = Test {
-            buffer: Buffer([1; 8]),
-            other_field: "a".to_owned(),
-        };
-        assert_eq!(
-            serde_json::to_value(test).unwrap(),
-            json!({
-                "buffer": "0101010101010101",
-                "other_field": "a",
-            })
-        );
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct PrefixedPadTest {
+            #[serde(with = "PadHex::<PrefixedUpperHexForm<Vec<u8>>, 2>")]
+            bytes: Vec<u8>,
+        }
 
-        let test = OriginalTest {
-            buffer: Buffer([1; 8]),
-            other_field: "a".to_owned(),
+        let value: PadTest = serde_json::from_value(json!({ "word": "ff", "bytes": "" })).unwrap();
+        let mut expected_word = [0; 32];
+        expected_word[31] = 0xff;
+        assert_eq!(value.word, expected_word);
+        assert_eq!(value.bytes, [0; 4]);
+
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json["word"], format!("{}ff", "00".repeat(31)));
+        assert_eq!(json["bytes"], "00000000");
+
+        let value = PadTest {
+            word: expected_word,
+            bytes: vec![1],
         };
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: PadTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy.bytes, [0, 0, 0, 1]);
+
+        let bogus_json = json!({ "word": "00".repeat(33), "bytes": "" });
+        let err = serde_json::from_value::<PadTest>(bogus_json).unwrap_err();
+        assert_eq!(err.to_string(), "expected 0 to 32 bytes, got 33");
+
+        let padded = <PadHex<HexForm<Vec<u8>>, 3>>::from_bytes(&[1, 2, 3]).unwrap();
+        assert_eq!(padded, [1, 2, 3]);
+
+        // The wire format and error messages of the wrapped form are retained.
+        let bytes = bincode::serialize(&ForcedPadTest {
+            bytes: vec![0xc0, 0xff],
+        });
+        assert_eq!(bytes.unwrap(), bincode::serialize("c0ff").unwrap());
+        let bytes = bincode::serialize("ff").unwrap();
+        let value: ForcedPadTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value.bytes, [0, 0xff]);
+
+        let err = PadHex::<Utf8HexForm, 2>::deserialize(json!(42)).unwrap_err();
         assert_eq!(
-            serde_json::to_value(test).unwrap(),
-            json!({
-                "buffer": [1, 1, 1, 1, 1, 1, 1, 1],
-                "other_field": "a",
-            })
+            err.to_string(),
+            "invalid type: integer `42`, expected hex-encoded UTF-8 string"
         );
+
+        let value: PrefixedPadTest = serde_json::from_value(json!({ "bytes": "0xff" })).unwrap();
+        assert_eq!(value.bytes, [0, 0xff]);
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "bytes": "0x00FF" }));
+        let err = serde_json::from_value::<PrefixedPadTest>(json!({ "bytes": "ff" }))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected `0x`-prefixed hex string"), "{err}");
     }
 
     #[test]
-    fn external_type() {
-        #[derive(Debug, PartialEq, Eq)]
-        pub struct Buffer([u8; 8]);
+    fn zero_padding_validates_padded_bytes_once() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
 
-        struct BufferHex(());
+        static CALL_COUNT: AtomicUsize = AtomicUsize::new(0);
 
-        impl Hex<Buffer> for BufferHex {
+        struct PaddedHex(());
+
+        impl Hex<Vec<u8>> for PaddedHex {
             type Error = &'static str;
 
-            fn create_bytes(buffer: &Buffer) -> Cow<'_, [u8]> {
-                Cow::Borrowed(&buffer.0)
+            fn create_bytes(value: &Vec<u8>) -> Cow<'_, [u8]> {
+                Cow::Borrowed(value)
             }
 
-            fn from_bytes(bytes: &[u8]) -> Result<Buffer, Self::Error> {
-                if bytes.len() == 8 {
-                    let mut inner = [0; 8];
-                    inner.copy_from_slice(bytes);
-                    Ok(Buffer(inner))
+            fn from_bytes(bytes: &[u8]) -> Result<Vec<u8>, Self::Error> {
+                Ok(bytes.to_vec())
+            }
+
+            fn validate_bytes(bytes: &[u8]) -> Result<(), Self::Error> {
+                CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+                if bytes.len() == 4 {
+                    Ok(())
                 } else {
-                    Err("invalid buffer length")
+                    Err("bytes are not padded")
                 }
             }
         }
 
-        #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
-        struct Test {
-            #[serde(with = "BufferHex")]
-            buffer: Buffer,
-            other_field: String,
+        let value = PadHex::<PaddedHex, 4>::deserialize(json!("c0ffee")).unwrap();
+        assert_eq!(value, [0, 0xc0, 0xff, 0xee]);
+        assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 1);
+        let value = PadHex::<PaddedHex, 4>::from_bytes(&[1]).unwrap();
+        assert_eq!(value, [0, 0, 0, 1]);
+        assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn boxed_slices() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct BoxTest {
+            #[serde(with = "HexForm")]
+            buffer: Box<[u8]>,
+            #[serde(with = "HexForm")]
+            empty_buffer: Box<[u8]>,
         }
 
-        let json = json!({ "buffer": "0001020304050607", "other_field": "abc" });
-        let value: Test = serde_json::from_value(json.clone()).unwrap();
-        assert!(value
-            .buffer
-            .0
-            .iter()
-            .enumerate()
-            .all(|(i, &byte)| i == usize::from(byte)));
+        let value = BoxTest {
+            buffer: vec![0xc0, 0xff, 0xee].into_boxed_slice(),
+            empty_buffer: Box::default(),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "buffer": "c0ffee", "empty_buffer": "" }));
+        let value_copy: BoxTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
 
-        let json_copy = serde_json::to_value(&value).unwrap();
-        assert_eq!(json, json_copy);
+        let bytes = bincode::serialize(&value).unwrap();
+        assert!(hex::encode(&bytes).contains("c0ffee"));
+        let value_copy: BoxTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+    }
 
-        // Test binary / non-human readable format.
-        let buffer = bincode::serialize(&value).unwrap();
-        // Conversion to hex is needed to be able to search for a pattern.
-        let buffer_hex = hex::encode(&buffer);
-        // Check that the buffer is stored in the serialization compactly,
-        // as original bytes.
-        let needle = "0001020304050607";
-        assert!(buffer_hex.contains(needle));
+    #[test]
+    fn reference_counted_slices() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ArcTest {
+            #[serde(with = "HexForm")]
+            buffer: Arc<[u8]>,
+            #[serde(with = "HexForm")]
+            rc_buffer: Rc<[u8]>,
+        }
 
-        let value_copy: Test = bincode::deserialize(&buffer).unwrap();
+        let buffer: Arc<[u8]> = Arc::from(&[0xc0, 0xff, 0xee][..]);
+        let value = ArcTest {
+            buffer: Arc::clone(&buffer),
+            rc_buffer: Rc::from(&[0xde, 0xad][..]),
+        };
+        assert_eq!(Arc::strong_count(&buffer), 2);
+
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "buffer": "c0ffee", "rc_buffer": "dead" }));
+        let value_copy: ArcTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert!(hex::encode(&bytes).contains("c0ffee"));
+        let value_copy: ArcTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        // Deserialized values should not share the allocation with the original one.
+        assert_eq!(Arc::strong_count(&value_copy.buffer), 1);
+        assert_eq!(Arc::strong_count(&buffer), 2);
+        drop(value);
+        assert_eq!(Arc::strong_count(&buffer), 1);
+    }
+
+    /// `Hex` is intentionally not imported in this module.
+    mod inherent_methods {
+        use super::Buffer;
+        use crate::{HexDecodeError, HexForm, ParseHexError};
+
+        use alloc::vec::Vec;
+
+        #[test]
+        fn encoding_and_decoding_without_trait_import() {
+            let buffer = Buffer([0xc0, 0xff, 0xee, 0, 1, 2, 3, 0xab]);
+            let hex_str = HexForm::encode(&buffer);
+            assert_eq!(hex_str, "c0ffee00010203ab");
+            assert_eq!(HexForm::<Buffer>::decode(&hex_str).unwrap(), buffer);
+            assert_eq!(
+                HexForm::<Buffer>::decode("C0FFEE00010203AB").unwrap(),
+                buffer
+            );
+
+            let err = HexForm::<Buffer>::decode("c0ffee").unwrap_err();
+            assert!(matches!(err, ParseHexError::Conversion(_)), "{err:?}");
+            let err = HexForm::<Vec<u8>>::decode("c0ffe").unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    ParseHexError::Hex(HexDecodeError::OddLength { len: 5 })
+                ),
+                "{err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn smart_pointers_to_hex_types() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct PointerTest {
+            #[serde(with = "HexPtr::<HexForm<Buffer>>")]
+            boxed: Box<Buffer>,
+            #[serde(with = "HexPtr::<UpperHexForm<Buffer>>")]
+            rc: Rc<Buffer>,
+            #[serde(with = "HexOption::<HexPtr<PrefixedHexForm<Buffer>>>")]
+            arc: Option<Arc<Buffer>>,
+        }
+
+        let value = PointerTest {
+            boxed: Box::new(Buffer([1; 8])),
+            rc: Rc::new(Buffer([0xab; 8])),
+            arc: Some(Arc::new(Buffer([0xcd; 8]))),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "boxed": "0101010101010101",
+                "rc": "ABABABABABABABAB",
+                "arc": "0xcdcdcdcdcdcdcdcd",
+            })
+        );
+        let value_copy: PointerTest = serde_json::from_value(json).unwrap();
         assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[8..16], [1; 8]);
+        let value_copy: PointerTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let err = serde_json::from_value::<PointerTest>(json!({
+            "arc": null,
+            "boxed": "01",
+            "rc": "abababababababab",
+        }))
+        .unwrap_err();
+        assert_eq!(err.to_string(), "could not convert slice to array");
     }
 
     #[test]
-    fn deserializing_flattened_field() {
-        // The fields in the flattened structure are somehow read with
-        // a human-readable `Deserializer`, even if the original `Deserializer`
-        // is not human-readable.
+    fn strict_deserialization() {
+        type Strict = StrictHex<HexForm<Vec<u8>>>;
+
+        let bytes = Strict::deserialize(json!("aabb")).unwrap();
+        assert_eq!(bytes, [0xaa, 0xbb]);
+        let bytes = HexForm::<Vec<u8>>::deserialize(json!("aabb")).unwrap();
+        assert_eq!(bytes, [0xaa, 0xbb]);
+        let bytes = HexForm::<Vec<u8>>::deserialize(json!("AABB")).unwrap();
+        assert_eq!(bytes, [0xaa, 0xbb]);
+
+        let bogus_inputs = [
+            (
+                json!("AABB"),
+                "strict hex requires lowercase characters only; got 'A' at position 0",
+            ),
+            (
+                json!("aaBb"),
+                "strict hex requires lowercase characters only; got 'B' at position 2",
+            ),
+            (json!("0xaabb"), "invalid hex character 'x' at position 1"),
+            (json!("aa bb"), "invalid hex character ' ' at position 2"),
+            (json!("aab"), "odd length"),
+            (json!([1, 2]), "invalid type: sequence"),
+        ];
+        for (input, expected_err) in bogus_inputs {
+            let err = Strict::deserialize(input).unwrap_err().to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+
+        let bytes = bincode::serialize(&HexBuf::<_, Strict>::new(vec![0xaa, 0xbb])).unwrap();
+        let buffer: HexBuf<Vec<u8>, Strict> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(*buffer, [0xaa, 0xbb]);
+    }
+
+    #[test]
+    fn flexible_deserialization() {
         #[derive(Debug, PartialEq, Serialize, Deserialize)]
-        struct Inner {
+        struct FlexTest {
+            #[serde(with = "FlexHex::<HexForm<Vec<u8>>>")]
+            buffer: Vec<u8>,
+            #[serde(with = "FlexHex::<PrefixedHexForm<Buffer>>")]
+            prefixed: Buffer,
+        }
+
+        let value = FlexTest {
+            buffer: vec![0xc0, 0xff, 0xee, 0],
+            prefixed: Buffer([1; 8]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(
+            json,
+            json!({ "buffer": "c0ffee00", "prefixed": "0x0101010101010101" })
+        );
+        let value_copy: FlexTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = json!({ "buffer": [0xc0, 0xff, 0xee, 0], "prefixed": vec![1_u8; 8] });
+        let value_copy: FlexTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[8..12], [0xc0, 0xff, 0xee, 0]);
+        let value_copy: FlexTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let err = serde_json::from_value::<FlexTest>(json!({ "buffer": true, "prefixed": "" }))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("byte array or sequence of bytes"), "{err}");
+    }
+
+    #[test]
+    fn flexible_deserialization_from_bytes() {
+        use serde::de::value::{BorrowedBytesDeserializer, Error};
+
+        let deserializer = BorrowedBytesDeserializer::<Error>::new(&[1; 8]);
+        let buffer: Buffer = FlexHex::<HexForm<Buffer>>::deserialize(deserializer).unwrap();
+        assert_eq!(buffer, Buffer([1; 8]));
+
+        let deserializer = BorrowedBytesDeserializer::<Error>::new(&[1; 4]);
+        let err = FlexHex::<HexForm<Buffer>>::deserialize(deserializer).unwrap_err();
+        assert_eq!(err.to_string(), "could not convert slice to array");
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn flexible_deserialization_with_base64() {
+        type Form = FlexHex<HexForm<Vec<u8>>>;
+
+        let bytes = Form::deserialize(json!("AQID")).unwrap();
+        assert_eq!(bytes, [1, 2, 3]);
+        let bytes = Form::deserialize(json!("3q2+7w==")).unwrap();
+        assert_eq!(bytes, [0xde, 0xad, 0xbe, 0xef]);
+
+        // Valid both as hex and base64; hex takes priority.
+        let bytes = Form::deserialize(json!("c0ffee00")).unwrap();
+        assert_eq!(bytes, [0xc0, 0xff, 0xee, 0]);
+
+        let err = Form::deserialize(json!("c0ff!!")).unwrap_err().to_string();
+        assert!(
+            err.contains("invalid hex character '!' at position 4"),
+            "{err}"
+        );
+        assert!(err.contains("not valid base64 either"), "{err}");
+
+        let err = Form::deserialize(json!(true)).unwrap_err().to_string();
+        assert!(
+            err.ends_with(
+                "expected hex-encoded byte array, base64-encoded string, byte array \
+                 or sequence of bytes"
+            ),
+            "{err}"
+        );
+
+        let err = FlexHex::<HexForm<Buffer>>::deserialize(json!("AQID"))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "could not convert slice to array");
+    }
+
+    #[test]
+    fn pairs() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct PairTest {
+            #[serde(with = "HexPair::<HexForm<Vec<u8>>, UpperHexForm<Vec<u8>>>")]
+            pair: (Vec<u8>, Vec<u8>),
+        }
+
+        let value = PairTest {
+            pair: (vec![1, 2], vec![3, 4, 5, 0xab]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "pair": ["0102", "030405AB"] }));
+        let value_copy: PairTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        // Tuples are encoded by `bincode` without a length prefix.
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[..8], 2_u64.to_le_bytes());
+        assert_eq!(bytes[8..10], [1, 2]);
+        assert_eq!(bytes[10..18], 4_u64.to_le_bytes());
+        assert_eq!(bytes[18..], [3, 4, 5, 0xab]);
+        let value_copy: PairTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bogus_jsons = [
+            (
+                json!({ "pair": ["0102"] }),
+                "invalid length 1, expected pair",
+            ),
+            (json!({ "pair": ["0102", "03", "04"] }), "invalid length 3"),
+            (json!({ "pair": ["0102", "xx"] }), "element 1: "),
+            (
+                json!({ "pair": "0102" }),
+                "expected pair of hex-encoded byte arrays",
+            ),
+        ];
+        for (bogus_json, expected_err) in bogus_jsons {
+            let err = serde_json::from_value::<PairTest>(bogus_json)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn bytes_integration() {
+        use bytes::{Bytes, BytesMut};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct BytesTest {
+            #[serde(with = "BytesHex")]
+            bytes: Bytes,
             #[serde(with = "HexForm")]
-            x: Vec<u8>,
+            bytes_mut: BytesMut,
+        }
+
+        let value = BytesTest {
+            bytes: Bytes::from_static(&[0xc0, 0xff, 0xee]),
+            bytes_mut: BytesMut::from(&[0xde, 0xad][..]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "bytes": "c0ffee", "bytes_mut": "dead" }));
+        let value_copy: BytesTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert!(hex::encode(&bytes).contains("c0ffee"));
+        let value_copy: BytesTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+    }
+
+    #[test]
+    fn arrayvec_integration() {
+        use arrayvec::ArrayVec;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ArrayVecTest {
             #[serde(with = "HexForm")]
-            y: [u8; 16],
+            buffer: ArrayVec<u8, 4>,
         }
 
+        let value = ArrayVecTest {
+            buffer: ArrayVec::from([0xde, 0xad, 0xbe, 0xef]),
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "buffer": "deadbeef" }));
+        let value_copy: ArrayVecTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = json!({ "buffer": "c0ffee" });
+        let value: ArrayVecTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value.buffer.as_slice(), [0xc0, 0xff, 0xee]);
+
+        let json = json!({ "buffer": "deadbeef00" });
+        let err = serde_json::from_value::<ArrayVecTest>(json).unwrap_err();
+        assert_eq!(err.to_string(), "insufficient capacity");
+    }
+
+    #[test]
+    fn smallvec_integration() {
+        use smallvec::SmallVec;
+
         #[derive(Debug, PartialEq, Serialize, Deserialize)]
-        struct Outer {
-            #[serde(flatten)]
-            inner: Inner,
-            z: String,
+        struct SmallVecTest {
+            #[serde(with = "HexForm")]
+            buffer: SmallVec<[u8; 4]>,
         }
 
-        let value = Outer {
-            inner: Inner {
-                x: vec![1; 8],
-                y: [0; 16],
-            },
-            z: "test".to_owned(),
+        let json = json!({ "buffer": "c0ffee" });
+        let value: SmallVecTest = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(value.buffer.as_slice(), [0xc0, 0xff, 0xee]);
+        assert!(!value.buffer.spilled());
+        assert_eq!(serde_json::to_value(&value).unwrap(), json);
+
+        let json = json!({ "buffer": "deadbeef00" });
+        let value: SmallVecTest = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(value.buffer.as_slice(), [0xde, 0xad, 0xbe, 0xef, 0]);
+        assert!(value.buffer.spilled());
+        assert_eq!(serde_json::to_value(&value).unwrap(), json);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: SmallVecTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroizing_decoded_bytes() {
+        use zeroize::Zeroizing;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct ZeroizeTest {
+            #[serde(with = "ZeroizeHexForm")]
+            secret: Buffer,
+        }
+
+        let mut bytes = Zeroizing::new(vec![0xab; 8]);
+        let buffer = from_bytes_zeroizing::<_, ZeroizeHexForm<Buffer>>(&mut bytes).unwrap();
+        assert_eq!(buffer, Buffer([0xab; 8]));
+        assert_eq!(*bytes, [0; 8]);
+
+        let mut bytes = Zeroizing::new(vec![0xab; 5]);
+        let err = from_bytes_zeroizing::<Buffer, ZeroizeHexForm<Buffer>>(&mut bytes).unwrap_err();
+        assert_eq!(err.to_string(), "could not convert slice to array");
+        assert_eq!(*bytes, [0; 5]);
+
+        let value = ZeroizeTest {
+            secret: Buffer([0xc0; 8]),
         };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "secret": "c0c0c0c0c0c0c0c0" }));
+        let value_copy: ZeroizeTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
 
-        let mut bytes = vec![];
-        ciborium::into_writer(&value, &mut bytes).unwrap();
-        let bytes_hex = hex::encode(&bytes);
-        // Check that byte buffers are stored in the binary form.
-        assert!(bytes_hex.contains(&"01".repeat(8)));
-        assert!(bytes_hex.contains(&"00".repeat(16)));
-        let value_copy = ciborium::from_reader(&bytes[..]).unwrap();
-        assert_eq!(value, value_copy);
+        let bytes = bincode::serialize(&value).unwrap();
+        let value_copy: ZeroizeTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        // Byte sequences are accepted as well.
+        let json = json!({ "secret": vec![0xc0_u8; 8] });
+        let value_copy: ZeroizeTest = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+        let err = serde_json::from_value::<ZeroizeTest>(json!({ "secret": vec![0xc0_u8; 5] }))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "could not convert slice to array");
+
+        // Check sequences that require growing the buffer (JSON arrays have no size hint).
+        let long_bytes: Vec<u8> = (0_u8..=255).cycle().take(1_000).collect();
+        let long_json = serde_json::to_string(&long_bytes).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&long_json);
+        let restored: Vec<u8> = ZeroizeHexForm::deserialize(&mut deserializer).unwrap();
+        assert_eq!(restored, long_bytes);
+    }
+
+    #[test]
+    fn hex_bytes_functions() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Test {
+            #[serde(with = "crate::hex_bytes")]
+            lower: Vec<u8>,
+            #[serde(with = "crate::upper_hex_bytes")]
+            upper: Vec<u8>,
+        }
+
+        let value = Test {
+            lower: vec![0xc0, 0xff, 0xee],
+            upper: vec![0xde, 0xad],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "lower": "c0ffee", "upper": "DEAD" }));
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = json!({ "lower": "C0FFEE", "upper": "dead" });
+        let value_copy: Test = serde_json::from_value(json).unwrap();
+        assert_eq!(value_copy, value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[..8], 3_u64.to_le_bytes());
+        assert_eq!(bytes[8..11], [0xc0, 0xff, 0xee]);
+        let value_copy: Test = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, value);
+
+        let json = json!({ "lower": "c0ffe", "upper": "dead" });
+        let err = serde_json::from_value::<Test>(json).unwrap_err();
+        assert!(err.to_string().contains("odd length"), "{err}");
+    }
+
+    #[test]
+    fn separate_serialize_with_and_deserialize_with() {
+        #[derive(Debug, PartialEq, Serialize)]
+        struct SerializeTest {
+            #[serde(serialize_with = "crate::serialize_hex::<_, UpperHexForm<_>, _>")]
+            key: Vec<u8>,
+            plain: Vec<u8>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct DeserializeTest {
+            #[serde(deserialize_with = "crate::deserialize_hex::<_, HexForm<_>, _>")]
+            key: Vec<u8>,
+            plain: Vec<u8>,
+        }
+
+        let value = SerializeTest {
+            key: vec![0xc0, 0xff, 0xee],
+            plain: vec![1, 2],
+        };
+        let json = serde_json::to_value(&value).unwrap();
+        assert_eq!(json, json!({ "key": "C0FFEE", "plain": [1, 2] }));
+        let restored: DeserializeTest = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.key, value.key);
+        assert_eq!(restored.plain, value.plain);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        let restored: DeserializeTest = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(restored.key, value.key);
+
+        let err = serde_json::from_value::<DeserializeTest>(json!({ "key": "0g", "plain": [] }))
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid hex character"), "{err}");
+    }
+
+    #[test]
+    fn upper_hex_bytes_matches_hex_encode_upper() {
+        let bytes: Vec<u8> = (0_u8..=255).collect();
+        let hex_str =
+            crate::upper_hex_bytes::serialize(&bytes, serde_json::value::Serializer).unwrap();
+        assert_eq!(hex_str, hex::encode_upper(&bytes));
+
+        for hex_str in [hex::encode_upper(&bytes), hex::encode(&bytes)] {
+            let deserializer = serde_json::Value::from(hex_str);
+            let restored = crate::upper_hex_bytes::deserialize(deserializer).unwrap();
+            assert_eq!(restored, bytes);
+        }
     }
 }
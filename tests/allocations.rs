@@ -0,0 +1,77 @@
+//! Tests that serialization of const-length and borrowed types does not allocate.
+
+#![cfg(feature = "const_len")]
+
+use hex_buffer_serde::{ConstHexBuf, ConstHexBytes, ConstHexForm, HexBytes};
+use serde_derive::Serialize;
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Allocator counting allocations. The test binary contains a single test,
+/// so allocations from concurrently running tests are not an issue.
+struct CountingAllocator;
+
+static ALLOCATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATION_COUNT.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+type HexArray = ConstHexBuf<[u8; 32], ConstHexForm<[u8; 32]>, 32>;
+
+#[derive(Serialize)]
+struct Test {
+    first: HexArray,
+    second: Option<HexArray>,
+}
+
+#[derive(Serialize)]
+struct BorrowedTest<'a> {
+    slice: HexBytes<'a>,
+    array: ConstHexBytes<'a, 4>,
+}
+
+#[test]
+fn serialization_does_not_allocate() {
+    let value = Test {
+        first: [0xab; 32].into(),
+        second: Some([0xcd; 32].into()),
+    };
+    let mut buffer = [0_u8; 128];
+
+    let count_before = ALLOCATION_COUNT.load(Ordering::SeqCst);
+    bincode::serialize_into(&mut buffer[..], &value).unwrap();
+    let count_after = ALLOCATION_COUNT.load(Ordering::SeqCst);
+    assert_eq!(count_after, count_before);
+
+    assert_eq!(buffer[..8], 32_u64.to_le_bytes());
+    assert_eq!(buffer[8..40], [0xab; 32]);
+    assert_eq!(buffer[40], 1); // `Some` tag
+
+    let slice = [0xc0; 40];
+    let array = [0xde, 0xad, 0xbe, 0xef];
+    let value = BorrowedTest {
+        slice: HexBytes(&slice),
+        array: ConstHexBytes(&array),
+    };
+    let mut buffer = [0_u8; 128];
+
+    let count_before = ALLOCATION_COUNT.load(Ordering::SeqCst);
+    serde_json::to_writer(&mut buffer[..], &value).unwrap();
+    bincode::serialize_into(&mut buffer[..], &value).unwrap();
+    let count_after = ALLOCATION_COUNT.load(Ordering::SeqCst);
+    assert_eq!(count_after, count_before);
+}
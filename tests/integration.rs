@@ -0,0 +1,103 @@
+//! Integration tests for `ConstHex` implementations used where a `Hex` one is expected
+//! via `ConstHexBridge`.
+
+#![cfg(all(feature = "alloc", feature = "const_len"))]
+
+use hex_buffer_serde::{ConstHex, ConstHexBridge, HexVec};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PublicKey([u8; 32]);
+
+struct KeyHex(());
+
+impl ConstHex<PublicKey, 32> for KeyHex {
+    type Error = &'static str;
+
+    fn create_bytes(value: &PublicKey) -> [u8; 32] {
+        value.0
+    }
+
+    fn from_bytes(bytes: [u8; 32]) -> Result<PublicKey, Self::Error> {
+        if bytes == [0; 32] {
+            Err("all-zero public key")
+        } else {
+            Ok(PublicKey(bytes))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct KeyList {
+    #[serde(with = "HexVec::<ConstHexBridge<KeyHex, 32>>")]
+    keys: Vec<PublicKey>,
+}
+
+fn sample_keys() -> KeyList {
+    KeyList {
+        keys: vec![PublicKey([0xab; 32]), PublicKey([0x01; 32])],
+    }
+}
+
+#[test]
+fn bridged_list_in_json() {
+    let value = sample_keys();
+    let json = serde_json::to_value(&value).unwrap();
+    assert_eq!(json, json!({ "keys": ["ab".repeat(32), "01".repeat(32)] }));
+    let value_copy: KeyList = serde_json::from_value(json).unwrap();
+    assert_eq!(value_copy, value);
+
+    let empty = KeyList { keys: vec![] };
+    let json = serde_json::to_value(&empty).unwrap();
+    assert_eq!(json, json!({ "keys": [] }));
+}
+
+#[test]
+fn bridged_list_in_binary_formats() {
+    let value = sample_keys();
+    let bytes = bincode::serialize(&value).unwrap();
+    // Vector length, then each key as a length-prefixed byte array.
+    let mut expected_bytes = 2_u64.to_le_bytes().to_vec();
+    for key in &value.keys {
+        expected_bytes.extend_from_slice(&32_u64.to_le_bytes());
+        expected_bytes.extend_from_slice(&key.0);
+    }
+    assert_eq!(bytes, expected_bytes);
+    let value_copy: KeyList = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(value_copy, value);
+
+    let bytes = postcard::to_allocvec(&value).unwrap();
+    let mut expected_bytes = vec![2];
+    for key in &value.keys {
+        expected_bytes.push(32);
+        expected_bytes.extend_from_slice(&key.0);
+    }
+    assert_eq!(bytes, expected_bytes);
+    let value_copy: KeyList = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(value_copy, value);
+}
+
+#[test]
+fn bridged_list_errors() {
+    let bogus_jsons = [
+        (
+            json!({ "keys": ["ab".repeat(32), "01".repeat(31)] }),
+            "element 1: expected hex string of 64 characters (for 32 bytes), got 62 characters",
+        ),
+        (
+            json!({ "keys": ["00".repeat(32)] }),
+            "element 0: all-zero public key",
+        ),
+        (
+            json!({ "keys": ["ab".repeat(32), "0g".repeat(32)] }),
+            "element 1: invalid hex character 'g' at position 1",
+        ),
+    ];
+    for (bogus_json, expected_err) in bogus_jsons {
+        let err = serde_json::from_value::<KeyList>(bogus_json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with(expected_err), "{err}");
+    }
+}
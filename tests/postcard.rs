@@ -0,0 +1,78 @@
+//! Tests for the `postcard` binary format.
+
+#![cfg(feature = "alloc")]
+
+use hex_buffer_serde::{Hex as _, HexForm};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Test {
+    #[serde(with = "HexForm")]
+    buffer: Vec<u8>,
+    #[serde(with = "HexForm")]
+    array: [u8; 16],
+    #[serde(with = "HexForm")]
+    key: [u8; 8],
+}
+
+#[test]
+fn postcard_roundtrip() {
+    let value = Test {
+        buffer: vec![0xc0, 0xff, 0xee],
+        array: [0xab; 16],
+        key: [1, 2, 3, 4, 5, 6, 7, 8],
+    };
+    let bytes = postcard::to_allocvec(&value).unwrap();
+
+    // Byte buffers are stored as raw bytes prefixed by their varint-encoded length.
+    let mut expected = vec![3, 0xc0, 0xff, 0xee, 16];
+    expected.extend_from_slice(&[0xab; 16]);
+    expected.extend_from_slice(&[8, 1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(bytes, expected);
+
+    let value_copy: Test = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(value_copy, value);
+}
+
+#[test]
+fn postcard_errors() {
+    let mut bytes = postcard::to_allocvec(&Test {
+        buffer: vec![],
+        array: [0; 16],
+        key: [0; 8],
+    })
+    .unwrap();
+    // Truncate the key.
+    let key_len_pos = bytes.len() - 9;
+    bytes[key_len_pos] = 7;
+    bytes.pop();
+
+    // `postcard` does not retain custom error messages.
+    let err = postcard::from_bytes::<Test>(&bytes).unwrap_err();
+    assert_eq!(err, postcard::Error::SerdeDeCustom);
+}
+
+#[test]
+fn flattened_fields_are_not_supported() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Inner {
+        #[serde(with = "HexForm")]
+        x: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Outer {
+        #[serde(flatten)]
+        inner: Inner,
+        z: String,
+    }
+
+    // Unlike `ciborium`, `postcard` is not self-describing, so it cannot serialize maps
+    // of unknown length produced by `#[serde(flatten)]`.
+    let value = Outer {
+        inner: Inner { x: vec![1; 8] },
+        z: "test".to_owned(),
+    };
+    let err = postcard::to_allocvec(&value).unwrap_err();
+    assert_eq!(err, postcard::Error::SerializeSeqLengthUnknown);
+}
@@ -0,0 +1,121 @@
+//! Property-based round-trip tests for `HexForm` and `ConstHexForm`.
+
+#![cfg(all(feature = "alloc", feature = "const_len"))]
+
+use hex_buffer_serde::{ConstHex as _, ConstHexForm, Hex as _, HexForm};
+use proptest::prelude::*;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Buffer {
+    #[serde(with = "HexForm")]
+    bytes: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Arrays {
+    #[serde(with = "ConstHexForm")]
+    small: [u8; 1],
+    #[serde(with = "ConstHexForm")]
+    medium: [u8; 16],
+    #[serde(with = "ConstHexForm")]
+    large: [u8; 33],
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Flattened {
+    #[serde(flatten)]
+    inner: Buffer,
+    tag: u8,
+}
+
+fn arrays() -> impl Strategy<Value = Arrays> {
+    (
+        any::<[u8; 1]>(),
+        any::<[u8; 16]>(),
+        any::<[u8; 32]>(),
+        any::<u8>(),
+    )
+        .prop_map(|(small, medium, large_head, large_tail)| {
+            let mut large = [0; 33];
+            large[..32].copy_from_slice(&large_head);
+            large[32] = large_tail;
+            Arrays {
+                small,
+                medium,
+                large,
+            }
+        })
+}
+
+/// Hex strings containing at least one char that is not a hex digit.
+fn invalid_hex_strings() -> impl Strategy<Value = String> {
+    ("[0-9a-fA-F]{0,16}", "[^0-9a-fA-F]", "[0-9a-fA-F]{0,16}")
+        .prop_map(|(head, invalid, tail)| format!("{head}{invalid}{tail}"))
+}
+
+proptest! {
+    #[test]
+    fn vec_roundtrip(bytes in proptest::collection::vec(any::<u8>(), 0..64)) {
+        let buffer = Buffer { bytes };
+
+        let json = serde_json::to_string(&buffer).unwrap();
+        prop_assert_eq!(&json, &format!(r#"{{"bytes":"{}"}}"#, hex::encode(&buffer.bytes)));
+        let restored: Buffer = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(&restored, &buffer);
+
+        let encoded = bincode::serialize(&buffer).unwrap();
+        let restored: Buffer = bincode::deserialize(&encoded).unwrap();
+        prop_assert_eq!(&restored, &buffer);
+    }
+
+    #[test]
+    fn flattened_vec_roundtrip(bytes in proptest::collection::vec(any::<u8>(), 0..64)) {
+        let value = Flattened { inner: Buffer { bytes }, tag: 42 };
+        let json = serde_json::to_string(&value).unwrap();
+        let restored: Flattened = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(restored, value);
+    }
+
+    #[test]
+    fn array_roundtrip(arrays in arrays()) {
+        let json = serde_json::to_string(&arrays).unwrap();
+        let restored: Arrays = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(&restored, &arrays);
+
+        let encoded = bincode::serialize(&arrays).unwrap();
+        let restored: Arrays = bincode::deserialize(&encoded).unwrap();
+        prop_assert_eq!(&restored, &arrays);
+    }
+
+    #[test]
+    fn odd_length_hex_is_rejected(hex_str in "([0-9a-f]{2}){0,16}[0-9a-f]") {
+        let json = serde_json::json!({ "bytes": hex_str });
+        let err = serde_json::from_value::<Buffer>(json).unwrap_err();
+        prop_assert!(err.to_string().contains("odd length"), "{}", err);
+    }
+
+    #[test]
+    fn invalid_hex_is_rejected(hex_str in invalid_hex_strings()) {
+        let json = serde_json::json!({ "bytes": &hex_str });
+        let err = serde_json::from_value::<Buffer>(json).unwrap_err();
+        let err = err.to_string();
+        prop_assert!(
+            err.contains("invalid hex character") || err.contains("odd length"),
+            "{}",
+            err
+        );
+
+        let json = serde_json::json!(&hex_str);
+        let result = ConstHexForm::<[u8; 16]>::deserialize(json);
+        prop_assert!(result.is_err());
+    }
+
+    #[test]
+    fn wrong_length_hex_is_rejected(bytes in proptest::collection::vec(any::<u8>(), 0..64)) {
+        prop_assume!(bytes.len() != 16);
+        let json = serde_json::json!(hex::encode(&bytes));
+        let err = ConstHexForm::<[u8; 16]>::deserialize(json).unwrap_err();
+        prop_assert!(err.to_string().contains("expected hex string of 32 characters"), "{}", err);
+    }
+}
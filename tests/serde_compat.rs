@@ -0,0 +1,189 @@
+//! Tests for hex fields in enums with non-default `serde` representations. Such enums
+//! are deserialized via buffered content, which may hit visitor methods not used otherwise.
+//!
+//! `bincode` is not self-describing, so it cannot deserialize internally tagged or untagged enums
+//! (they require `Deserializer::deserialize_any()`). CBOR via `ciborium` is used as a binary
+//! format instead; `bincode` is only checked for externally tagged enums.
+
+#![cfg(feature = "alloc")]
+
+use hex_buffer_serde::{Hex as _, HexForm};
+use serde::de::DeserializeOwned;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+
+use std::fmt;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Tagged {
+    Key {
+        #[serde(with = "HexForm")]
+        key: Vec<u8>,
+        comment: String,
+    },
+    Signature {
+        #[serde(with = "HexForm")]
+        r: [u8; 4],
+        #[serde(with = "HexForm")]
+        s: [u8; 4],
+    },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Untagged {
+    Signature {
+        #[serde(with = "HexForm")]
+        signature: Vec<u8>,
+    },
+    Key {
+        #[serde(with = "HexForm")]
+        key: [u8; 4],
+    },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Metadata {
+    #[serde(with = "HexForm")]
+    hash: Vec<u8>,
+    height: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum TaggedWithFlatten {
+    Block {
+        #[serde(flatten)]
+        metadata: Metadata,
+        #[serde(with = "HexForm")]
+        payload: Vec<u8>,
+    },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum ExternallyTagged {
+    Key(#[serde(with = "HexForm")] Vec<u8>),
+    Pair {
+        #[serde(with = "HexForm")]
+        first: [u8; 4],
+        #[serde(with = "HexForm")]
+        second: Vec<u8>,
+    },
+}
+
+fn assert_json_roundtrip<T>(value: &T, expected_json: &serde_json::Value)
+where
+    T: fmt::Debug + PartialEq + serde::Serialize + DeserializeOwned,
+{
+    let json = serde_json::to_value(value).unwrap();
+    assert_eq!(json, *expected_json);
+    let value_copy: T = serde_json::from_value(json).unwrap();
+    assert_eq!(value_copy, *value);
+}
+
+fn assert_cbor_roundtrip<T>(value: &T, raw_bytes: &[u8])
+where
+    T: fmt::Debug + PartialEq + serde::Serialize + DeserializeOwned,
+{
+    let mut bytes = vec![];
+    ciborium::into_writer(value, &mut bytes).unwrap();
+    // Check that byte buffers are stored in the binary form.
+    assert!(
+        bytes
+            .windows(raw_bytes.len())
+            .any(|window| window == raw_bytes),
+        "{bytes:?}"
+    );
+    let value_copy: T = ciborium::from_reader(&bytes[..]).unwrap();
+    assert_eq!(value_copy, *value);
+}
+
+#[test]
+fn internally_tagged_enum() {
+    let key = Tagged::Key {
+        key: vec![0xc0, 0xff, 0xee],
+        comment: "test".to_owned(),
+    };
+    let expected_json = json!({ "type": "key", "key": "c0ffee", "comment": "test" });
+    assert_json_roundtrip(&key, &expected_json);
+    assert_cbor_roundtrip(&key, &[0xc0, 0xff, 0xee]);
+
+    let signature = Tagged::Signature {
+        r: [1, 2, 3, 4],
+        s: [5, 6, 7, 8],
+    };
+    let expected_json = json!({ "type": "signature", "r": "01020304", "s": "05060708" });
+    assert_json_roundtrip(&signature, &expected_json);
+    assert_cbor_roundtrip(&signature, &[5, 6, 7, 8]);
+
+    let err =
+        serde_json::from_value::<Tagged>(json!({ "type": "signature", "r": "0102", "s": "" }))
+            .unwrap_err();
+    assert!(err.to_string().contains("array"), "{err}");
+}
+
+#[test]
+fn untagged_enum() {
+    let signature = Untagged::Signature {
+        signature: vec![0xab; 8],
+    };
+    let expected_json = json!({ "signature": "abababababababab" });
+    assert_json_roundtrip(&signature, &expected_json);
+    assert_cbor_roundtrip(&signature, &[0xab; 8]);
+
+    let key = Untagged::Key { key: [1, 2, 3, 4] };
+    let expected_json = json!({ "key": "01020304" });
+    assert_json_roundtrip(&key, &expected_json);
+    assert_cbor_roundtrip(&key, &[1, 2, 3, 4]);
+
+    // Invalid hex strings should not match any variant.
+    let err = serde_json::from_value::<Untagged>(json!({ "key": "0102030g" })).unwrap_err();
+    assert!(
+        err.to_string().contains("did not match any variant"),
+        "{err}"
+    );
+}
+
+#[test]
+fn flattened_field_in_tagged_enum() {
+    let block = TaggedWithFlatten::Block {
+        metadata: Metadata {
+            hash: vec![0x11; 8],
+            height: 42,
+        },
+        payload: vec![0xc0, 0xff, 0xee],
+    };
+    let expected_json = json!({
+        "type": "block",
+        "hash": "1111111111111111",
+        "height": 42,
+        "payload": "c0ffee",
+    });
+    assert_json_roundtrip(&block, &expected_json);
+    assert_cbor_roundtrip(&block, &[0x11; 8]);
+}
+
+#[test]
+fn externally_tagged_enum() {
+    let values = [
+        ExternallyTagged::Key(vec![0xc0, 0xff, 0xee]),
+        ExternallyTagged::Pair {
+            first: [1, 2, 3, 4],
+            second: vec![],
+        },
+    ];
+    let expected_jsons = [
+        json!({ "Key": "c0ffee" }),
+        json!({ "Pair": { "first": "01020304", "second": "" } }),
+    ];
+
+    for (value, expected_json) in values.iter().zip(&expected_jsons) {
+        assert_json_roundtrip(value, expected_json);
+
+        let bytes = bincode::serialize(value).unwrap();
+        let value_copy: ExternallyTagged = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(value_copy, *value);
+    }
+    assert_cbor_roundtrip(&values[0], &[0xc0, 0xff, 0xee]);
+}
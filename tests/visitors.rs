@@ -0,0 +1,74 @@
+//! Tests embedding public visitors into custom deserializers.
+
+#![cfg(feature = "alloc")]
+
+use hex_buffer_serde::{BytesVisitor, HexVisitor};
+use serde::{Deserialize, Deserializer};
+
+/// Buffer deserialized from a `0x`-prefixed hex string or from raw bytes.
+#[derive(Debug, PartialEq)]
+struct Buffer(Vec<u8>);
+
+impl<'de> Deserialize<'de> for Buffer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor::with_prefix("0x"))?
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor::new())?
+        };
+        Ok(Self(bytes))
+    }
+}
+
+#[test]
+fn deserializing_with_hex_visitor() {
+    let buffer: Buffer = serde_json::from_str(r#""0xc0FFee""#).unwrap();
+    assert_eq!(buffer, Buffer(vec![0xc0, 0xff, 0xee]));
+
+    let err = serde_json::from_str::<Buffer>(r#""c0ffee""#).unwrap_err();
+    assert!(
+        err.to_string().contains("`0x`-prefixed hex string"),
+        "{err}"
+    );
+    let err = serde_json::from_str::<Buffer>(r#""0xc0ffeg""#).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("invalid hex character 'g' at position 7"),
+        "{err}"
+    );
+}
+
+#[test]
+fn deserializing_with_bytes_visitor() {
+    let bytes = bincode::serialize(&[1_u8, 2, 3] as &[u8]).unwrap();
+    let buffer: Buffer = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(buffer, Buffer(vec![1, 2, 3]));
+}
+
+#[cfg(feature = "const_len")]
+#[test]
+fn deserializing_with_const_visitors() {
+    use hex_buffer_serde::{ConstBytesVisitor, ConstHexVisitor};
+    use serde::de::value::{BytesDeserializer, Error as ValueError, StrDeserializer};
+
+    let deserializer = StrDeserializer::<ValueError>::new("c0ffee");
+    let bytes: [u8; 3] = deserializer
+        .deserialize_str(ConstHexVisitor::new())
+        .unwrap();
+    assert_eq!(bytes, [0xc0, 0xff, 0xee]);
+
+    let deserializer = StrDeserializer::<ValueError>::new("c0ffee");
+    let err = deserializer
+        .deserialize_str(ConstHexVisitor::<4>::new())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected hex string of 8 characters (for 4 bytes), got 6 characters"
+    );
+
+    let deserializer = BytesDeserializer::<ValueError>::new(&[1, 2, 3, 4]);
+    let bytes: [u8; 4] = deserializer
+        .deserialize_bytes(ConstBytesVisitor::new())
+        .unwrap();
+    assert_eq!(bytes, [1, 2, 3, 4]);
+}
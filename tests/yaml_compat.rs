@@ -0,0 +1,83 @@
+//! Tests for YAML as a human-readable format.
+
+#![cfg(feature = "alloc")]
+
+use hex_buffer_serde::{Hex as _, HexForm};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Test {
+    #[serde(with = "HexForm")]
+    buffer: Vec<u8>,
+    #[serde(with = "HexForm")]
+    key: [u8; 4],
+}
+
+#[test]
+fn yaml_roundtrip() {
+    let value = Test {
+        buffer: vec![0xc0, 0xff, 0xee],
+        key: [0x01, 0x02, 0x03, 0x04],
+    };
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    // Hex strings that could be mistaken for numbers are quoted. No `!!binary` tags are used.
+    assert_eq!(yaml, "buffer: c0ffee\nkey: '01020304'\n");
+    assert!(!yaml.contains("!!binary"));
+
+    let value_copy: Test = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(value_copy, value);
+
+    let yaml = "buffer: \"c0ffee\"\nkey: '01020304'\n";
+    let value_copy: Test = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(value_copy, value);
+
+    // Unquoted scalars resembling numbers are still read as strings, with leading zeros retained.
+    let yaml = "buffer: c0ffee\nkey: 01020304\n";
+    let value_copy: Test = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(value_copy, value);
+}
+
+#[test]
+fn yaml_errors() {
+    let yaml = "buffer: c0ffeg\nkey: '01020304'\n";
+    let err = serde_yaml::from_str::<Test>(yaml).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("invalid hex character 'g' at position 5"),
+        "{err}"
+    );
+
+    let yaml = "buffer: c0ffee\nkey: '010203'\n";
+    let err = serde_yaml::from_str::<Test>(yaml).unwrap_err();
+    assert!(
+        err.to_string().contains("could not convert slice to array"),
+        "{err}"
+    );
+}
+
+#[cfg(feature = "const_len")]
+#[test]
+fn yaml_roundtrip_for_const_len_form() {
+    use hex_buffer_serde::{ConstHex, ConstHexForm};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ConstTest {
+        #[serde(with = "ConstHexForm")]
+        array: [u8; 4],
+    }
+
+    let value = ConstTest {
+        array: [0xde, 0xad, 0xbe, 0xef],
+    };
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    assert_eq!(yaml, "array: deadbeef\n");
+    let value_copy: ConstTest = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(value_copy, value);
+
+    let err = serde_yaml::from_str::<ConstTest>("array: dead\n").unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("expected hex string of 8 characters (for 4 bytes), got 4 characters"),
+        "{err}"
+    );
+}
@@ -2,7 +2,8 @@
 
 extern crate alloc;
 
-use hex_buffer_serde::{Hex as _, HexForm};
+use hex_buffer_serde::{Hex as _, HexForm, Uint8ArrayHex};
+use js_sys::Uint8Array;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
@@ -47,3 +48,19 @@ pub fn reverse(value: JsValue) -> Result<JsValue, JsValue> {
     parsed.reverse();
     serde_wasm_bindgen::to_value(&parsed).map_err(|err| to_js_error(&err))
 }
+
+#[derive(Serialize, Deserialize)]
+struct JsArrayData {
+    #[serde(with = "Uint8ArrayHex")]
+    array: Uint8Array,
+}
+
+#[wasm_bindgen(js_name = reverseJsArray)]
+pub fn reverse_js_array(value: JsValue) -> Result<JsValue, JsValue> {
+    let parsed: JsArrayData =
+        serde_wasm_bindgen::from_value(value).map_err(|err| to_js_error(&err))?;
+    let mut bytes = parsed.array.to_vec();
+    bytes.reverse();
+    parsed.array.copy_from(&bytes);
+    serde_wasm_bindgen::to_value(&parsed).map_err(|err| to_js_error(&err))
+}
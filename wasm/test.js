@@ -1,7 +1,7 @@
 #!/usr/bin/env node
 
 const { strict: assert } = require('assert');
-const { reverse } = require('./pkg');
+const { reverse, reverseJsArray } = require('./pkg');
 
 const value = {
   buffer: 'c0ffee',
@@ -24,3 +24,10 @@ assert.throws(() => reverse(invalidValue), {
   name: 'Error',
   message: /could not convert slice to array/i,
 });
+
+const arrayValue = { array: 'c0ffee' };
+assert.deepEqual(reverseJsArray(arrayValue), { array: 'eeffc0' });
+assert.throws(() => reverseJsArray({ array: 'c0ffe' }), {
+  name: 'Error',
+  message: /odd length/i,
+});